@@ -0,0 +1,150 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkvm_circuit_types::{environment::prelude::*, integers::Integer, Boolean, Field};
+
+/// A trait for values that can be encoded as the input bits of a hash function.
+///
+/// The encoding is canonical, and matches `ToBits::to_bits_le`:
+///   - a boolean is encoded as a single bit,
+///   - a field element is encoded as its `Field::size_in_bits()` little-endian bits,
+///   - an integer is encoded as its `I::BITS` little-endian bits, and
+///   - an array or a tuple is encoded as the concatenation of the encodings of its elements, in order.
+///
+/// As each element has a fixed bit length, the encoding of an array or tuple type is injective.
+/// Note: Structured values typically exceed the input size of `Pedersen::hash`; use `Pedersen::hash_long` instead.
+pub trait HashInput<E: Environment> {
+    /// Returns the bits of `self` to be hashed.
+    fn to_hash_bits(&self) -> Vec<Boolean<E>>;
+}
+
+impl<E: Environment> HashInput<E> for Boolean<E> {
+    /// Returns the bits of `self` to be hashed.
+    fn to_hash_bits(&self) -> Vec<Boolean<E>> {
+        vec![self.clone()]
+    }
+}
+
+impl<E: Environment> HashInput<E> for Field<E> {
+    /// Returns the bits of `self` to be hashed.
+    fn to_hash_bits(&self) -> Vec<Boolean<E>> {
+        self.to_bits_le()
+    }
+}
+
+impl<E: Environment, I: IntegerType> HashInput<E> for Integer<E, I> {
+    /// Returns the bits of `self` to be hashed.
+    fn to_hash_bits(&self) -> Vec<Boolean<E>> {
+        self.to_bits_le()
+    }
+}
+
+impl<E: Environment, C: HashInput<E>, const N: usize> HashInput<E> for [C; N] {
+    /// Returns the concatenation of the bits of each element of `self`, in order.
+    fn to_hash_bits(&self) -> Vec<Boolean<E>> {
+        self.iter().flat_map(|element| element.to_hash_bits()).collect()
+    }
+}
+
+/// A helper macro to implement `HashInput` for a tuple of `HashInput` circuits.
+macro_rules! hash_input_tuple {
+    (($t0:ident, 0), $(($ty:ident, $idx:tt)),+) => {
+        impl<E: Environment, $t0: HashInput<E>, $($ty: HashInput<E>),+> HashInput<E> for ($t0, $($ty),+) {
+            /// Returns the concatenation of the bits of each element of `self`, in order.
+            fn to_hash_bits(&self) -> Vec<Boolean<E>> {
+                let mut bits = self.0.to_hash_bits();
+                $(bits.extend(self.$idx.to_hash_bits());)+
+                bits
+            }
+        }
+    }
+}
+
+hash_input_tuple!((C0, 0), (C1, 1));
+hash_input_tuple!((C0, 0), (C1, 1), (C2, 2));
+hash_input_tuple!((C0, 0), (C1, 1), (C2, 2), (C3, 3));
+hash_input_tuple!((C0, 0), (C1, 1), (C2, 2), (C3, 3), (C4, 4));
+hash_input_tuple!((C0, 0), (C1, 1), (C2, 2), (C3, 3), (C4, 4), (C5, 5));
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use crate::{HashLong, Pedersen128};
+    use snarkvm_circuit_types::{environment::Circuit, U32, U8};
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    const ITERATIONS: u64 = 10;
+    const MESSAGE: &str = "PedersenCircuit0";
+
+    fn check_hash_input(mode: Mode, rng: &mut TestRng) {
+        let circuit = Pedersen128::<Circuit>::constant(console::Pedersen128::setup(MESSAGE));
+
+        for i in 0..ITERATIONS {
+            let integer = U32::<Circuit>::new(mode, Uniform::rand(rng));
+            let field = Field::<Circuit>::new(mode, Uniform::rand(rng));
+            let boolean = Boolean::<Circuit>::new(mode, bool::rand(rng));
+
+            Circuit::scope(format!("HashInput {mode} {i}"), || {
+                // Concatenate the bits manually.
+                let mut expected = integer.to_bits_le();
+                expected.extend(field.to_bits_le());
+                expected.push(boolean.clone());
+
+                // Ensure the tuple encoding matches the manual concatenation.
+                let tuple = (integer.clone(), field.clone(), boolean.clone());
+                let candidate = tuple.to_hash_bits();
+                assert_eq!(32 + <Circuit as Environment>::BaseField::size_in_bits() + 1, candidate.len());
+                assert_eq!(expected.eject_value(), candidate.eject_value());
+
+                // Ensure the digests match.
+                assert_eq!(circuit.hash_long(&expected).eject_value(), circuit.hash_long(&candidate).eject_value());
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_hash_input_constant() {
+        let mut rng = TestRng::default();
+        check_hash_input(Mode::Constant, &mut rng);
+    }
+
+    #[test]
+    fn test_hash_input_public() {
+        let mut rng = TestRng::default();
+        check_hash_input(Mode::Public, &mut rng);
+    }
+
+    #[test]
+    fn test_hash_input_private() {
+        let mut rng = TestRng::default();
+        check_hash_input(Mode::Private, &mut rng);
+    }
+
+    #[test]
+    fn test_hash_input_order_matters() {
+        let circuit = Pedersen128::<Circuit>::constant(console::Pedersen128::setup(MESSAGE));
+
+        let a = U8::<Circuit>::new(Mode::Private, console::U8::new(1));
+        let b = U8::<Circuit>::new(Mode::Private, console::U8::new(2));
+
+        // Ensure swapping the elements of a tuple changes the digest.
+        let first = circuit.hash_long(&(a.clone(), b.clone()).to_hash_bits());
+        let second = circuit.hash_long(&(b, a).to_hash_bits());
+        assert_ne!(first.eject_value(), second.eject_value());
+        Circuit::reset();
+    }
+}
@@ -23,6 +23,9 @@ pub use bhp::*;
 pub mod elligator2;
 pub use elligator2::Elligator2;
 
+pub mod hash_input;
+pub use hash_input::HashInput;
+
 pub mod pedersen;
 pub use pedersen::*;
 
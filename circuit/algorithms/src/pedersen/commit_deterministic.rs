@@ -0,0 +1,110 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, const NUM_BITS: u8> Pedersen<E, NUM_BITS> {
+    /// Returns the Pedersen commitment of the given input as a field element,
+    /// using a randomizer that is deterministically derived from the given nonce.
+    ///
+    /// The randomizer is the Pedersen hash of `nonce`, truncated (up to data bits) to project onto the scalar field,
+    /// so the nonce must fit within `NUM_BITS` bits. Committing to the same input with the same nonce
+    /// always reproduces the same commitment.
+    /// Note: The commitment is only hiding if the nonce is secret, and is never reused across inputs.
+    pub fn commit_deterministic(&self, input: &[Boolean<E>], nonce: &[Boolean<E>]) -> Field<E> {
+        // Hash the nonce to the base field.
+        let output = self.hash(nonce);
+
+        // Truncate the output to the size in data bits (1 bit less than the MODULUS) of the scalar.
+        // Slicing here is safe as the base field is larger than the scalar field.
+        let randomizer = Scalar::from_bits_le(&output.to_bits_le()[..E::ScalarField::size_in_data_bits()]);
+
+        // Commit to the input with the derived randomizer.
+        self.commit(input, &randomizer)
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    const ITERATIONS: u64 = 10;
+    const MESSAGE: &str = "PedersenCircuit0";
+    const NUM_BITS: u8 = 64;
+    const NUM_NONCE_BITS: usize = 32;
+
+    fn check_commit_deterministic(mode: Mode, rng: &mut TestRng) {
+        use console::{Commit as C, Hash as H};
+
+        // Initialize Pedersen.
+        let native = console::Pedersen::<<Circuit as Environment>::Network, NUM_BITS>::setup(MESSAGE);
+        let circuit = Pedersen::<Circuit, NUM_BITS>::constant(native.clone());
+
+        for i in 0..ITERATIONS {
+            // Sample a random input.
+            let input = (0..NUM_BITS).map(|_| bool::rand(rng)).collect::<Vec<bool>>();
+            // Sample two distinct nonces.
+            let first_nonce = (0..NUM_NONCE_BITS).map(|_| bool::rand(rng)).collect::<Vec<bool>>();
+            let mut second_nonce = first_nonce.clone();
+            second_nonce[0] = !second_nonce[0];
+
+            // Compute the expected commitment, by deriving the randomizer natively.
+            let hash = native.hash(&first_nonce).expect("Failed to hash native nonce");
+            let randomizer = console::Scalar::from_bits_le(
+                &hash.to_bits_le()[..<Circuit as Environment>::ScalarField::size_in_data_bits()],
+            )
+            .expect("Failed to derive native randomizer");
+            let expected = native.commit(&input, &randomizer).expect("Failed to commit native input");
+
+            // Prepare the circuit inputs.
+            let circuit_input: Vec<Boolean<_>> = Inject::new(mode, input);
+            let circuit_first_nonce: Vec<Boolean<_>> = Inject::new(mode, first_nonce);
+            let circuit_second_nonce: Vec<Boolean<_>> = Inject::new(mode, second_nonce);
+
+            Circuit::scope(format!("Pedersen {mode} {i}"), || {
+                // Perform the commit operation twice with the same nonce, and once with a different nonce.
+                let first = circuit.commit_deterministic(&circuit_input, &circuit_first_nonce);
+                let repeated = circuit.commit_deterministic(&circuit_input, &circuit_first_nonce);
+                let second = circuit.commit_deterministic(&circuit_input, &circuit_second_nonce);
+                assert_eq!(expected, first.eject_value());
+                assert_eq!(first.eject_value(), repeated.eject_value());
+                assert_ne!(first.eject_value(), second.eject_value());
+                assert!(Circuit::is_satisfied_in_scope());
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_commit_deterministic_constant() {
+        let mut rng = TestRng::default();
+        check_commit_deterministic(Mode::Constant, &mut rng);
+    }
+
+    #[test]
+    fn test_commit_deterministic_public() {
+        let mut rng = TestRng::default();
+        check_commit_deterministic(Mode::Public, &mut rng);
+    }
+
+    #[test]
+    fn test_commit_deterministic_private() {
+        let mut rng = TestRng::default();
+        check_commit_deterministic(Mode::Private, &mut rng);
+    }
+}
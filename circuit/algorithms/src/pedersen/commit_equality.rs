@@ -0,0 +1,164 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, const NUM_BITS: u8> Pedersen<E, NUM_BITS> {
+    /// Returns `true` if the two given Pedersen commitments are equal, by comparing their x-coordinates.
+    ///
+    /// This comparison does not require the openings of either commitment. Note that, by the binding
+    /// property, two commitments are equal only if they open to the same input *and* the same randomizer;
+    /// commitments to the same input under different randomizers compare as not equal.
+    pub fn commitments_equal(&self, a: &Field<E>, b: &Field<E>) -> Boolean<E> {
+        a.is_equal(b)
+    }
+
+    /// Commits to the given input twice, under `randomizer_a` and `randomizer_b`, and returns both commitments.
+    ///
+    /// Both commitments open to the same input by construction, so no equality is enforced between them.
+    /// Unlike `commitments_equal`, which compares two given commitments and is `true` only if they share both
+    /// the input and the randomizer, the commitments returned here differ unless the two randomizers are equal.
+    pub fn prove_same_value(
+        &self,
+        input: &[Boolean<E>],
+        randomizer_a: &Scalar<E>,
+        randomizer_b: &Scalar<E>,
+    ) -> (Field<E>, Field<E>) {
+        (self.commit(input, randomizer_a), self.commit(input, randomizer_b))
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    const ITERATIONS: u64 = 10;
+    const MESSAGE: &str = "PedersenCircuit0";
+    const NUM_BITS: u8 = 64;
+
+    fn check_commitments_equal(mode: Mode, rng: &mut TestRng) {
+        use console::Commit as C;
+
+        // Initialize Pedersen.
+        let native = console::Pedersen::<<Circuit as Environment>::Network, NUM_BITS>::setup(MESSAGE);
+        let circuit = Pedersen::<Circuit, NUM_BITS>::constant(native.clone());
+
+        for i in 0..ITERATIONS {
+            // Sample two random inputs and randomizers.
+            let input_a = (0..NUM_BITS).map(|_| bool::rand(rng)).collect::<Vec<bool>>();
+            let input_b = (0..NUM_BITS).map(|_| bool::rand(rng)).collect::<Vec<bool>>();
+            let randomizer_a = Uniform::rand(rng);
+            let randomizer_b = Uniform::rand(rng);
+
+            // Compute the native commitments.
+            let commitment = native.commit(&input_a, &randomizer_a).expect("Failed to commit native input");
+            let other_input = native.commit(&input_b, &randomizer_a).expect("Failed to commit native input");
+            let other_randomizer = native.commit(&input_a, &randomizer_b).expect("Failed to commit native input");
+
+            let a = Field::<Circuit>::new(mode, commitment);
+            let same = Field::<Circuit>::new(mode, commitment);
+            let differing_input = Field::<Circuit>::new(mode, other_input);
+            let differing_randomizer = Field::<Circuit>::new(mode, other_randomizer);
+
+            Circuit::scope(format!("Pedersen {mode} {i}"), || {
+                assert!(circuit.commitments_equal(&a, &same).eject_value());
+                assert!(!circuit.commitments_equal(&a, &differing_input).eject_value());
+                assert!(!circuit.commitments_equal(&a, &differing_randomizer).eject_value());
+                assert!(Circuit::is_satisfied_in_scope());
+            });
+            Circuit::reset();
+        }
+    }
+
+    fn check_prove_same_value(mode: Mode, rng: &mut TestRng) {
+        use console::Commit as C;
+
+        // Initialize Pedersen.
+        let native = console::Pedersen::<<Circuit as Environment>::Network, NUM_BITS>::setup(MESSAGE);
+        let circuit = Pedersen::<Circuit, NUM_BITS>::constant(native.clone());
+
+        for i in 0..ITERATIONS {
+            // Sample a random input and two random randomizers.
+            let input = (0..NUM_BITS).map(|_| bool::rand(rng)).collect::<Vec<bool>>();
+            let randomizer_a = Uniform::rand(rng);
+            let randomizer_b = Uniform::rand(rng);
+
+            // Compute the native commitments.
+            let expected_a = native.commit(&input, &randomizer_a).expect("Failed to commit native input");
+            let expected_b = native.commit(&input, &randomizer_b).expect("Failed to commit native input");
+
+            let circuit_input: Vec<Boolean<_>> = Inject::new(mode, input);
+            let randomizer_a = Scalar::<Circuit>::new(mode, randomizer_a);
+            let randomizer_b = Scalar::<Circuit>::new(mode, randomizer_b);
+
+            // Ensure differing randomizers yield differing commitments to the same input.
+            Circuit::scope(format!("Pedersen {mode} {i}"), || {
+                let (commitment_a, commitment_b) =
+                    circuit.prove_same_value(&circuit_input, &randomizer_a, &randomizer_b);
+                assert_eq!(expected_a, commitment_a.eject_value());
+                assert_eq!(expected_b, commitment_b.eject_value());
+                assert!(!circuit.commitments_equal(&commitment_a, &commitment_b).eject_value());
+                assert!(Circuit::is_satisfied_in_scope());
+            });
+            Circuit::reset();
+
+            // Ensure matching randomizers yield equal commitments.
+            let (commitment_a, commitment_b) = circuit.prove_same_value(&circuit_input, &randomizer_a, &randomizer_a);
+            assert_eq!(expected_a, commitment_b.eject_value());
+            assert!(circuit.commitments_equal(&commitment_a, &commitment_b).eject_value());
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_commitments_equal_constant() {
+        let mut rng = TestRng::default();
+        check_commitments_equal(Mode::Constant, &mut rng);
+    }
+
+    #[test]
+    fn test_commitments_equal_public() {
+        let mut rng = TestRng::default();
+        check_commitments_equal(Mode::Public, &mut rng);
+    }
+
+    #[test]
+    fn test_commitments_equal_private() {
+        let mut rng = TestRng::default();
+        check_commitments_equal(Mode::Private, &mut rng);
+    }
+
+    #[test]
+    fn test_prove_same_value_constant() {
+        let mut rng = TestRng::default();
+        check_prove_same_value(Mode::Constant, &mut rng);
+    }
+
+    #[test]
+    fn test_prove_same_value_public() {
+        let mut rng = TestRng::default();
+        check_prove_same_value(Mode::Public, &mut rng);
+    }
+
+    #[test]
+    fn test_prove_same_value_private() {
+        let mut rng = TestRng::default();
+        check_prove_same_value(Mode::Private, &mut rng);
+    }
+}
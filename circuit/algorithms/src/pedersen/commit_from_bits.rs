@@ -0,0 +1,235 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use snarkvm_circuit_types::boolean::is_less_than_or_equal_constant;
+
+impl<E: Environment, const NUM_BITS: u8> CommitFromBits for Pedersen<E, NUM_BITS> {
+    type Input = Boolean<E>;
+    type Output = Field<E>;
+
+    /// Returns the Pedersen commitment of the given input and randomizer bits as a field element.
+    ///
+    /// The randomizer is given as its **little-endian** bits, which are used directly instead of
+    /// decomposing a scalar. If the bits may exceed the scalar field modulus, they are enforced to be
+    /// less than the modulus, so that the commitment matches `commit` with the equivalent scalar.
+    fn commit_from_randomizer_bits(&self, input: &[Self::Input], randomizer_bits: &[Self::Input]) -> Self::Output {
+        // Ensure the number of randomizer bits is within the scalar field size.
+        let size_in_bits = console::Scalar::<E::Network>::size_in_bits();
+        if randomizer_bits.len() > size_in_bits {
+            E::halt(format!("The Pedersen randomizer cannot exceed {size_in_bits} bits"))
+        }
+
+        // If the randomizer bits may exceed the scalar field modulus, ensure they are less than the modulus.
+        if randomizer_bits.len() > console::Scalar::<E::Network>::size_in_data_bits() {
+            // Compute `bits_le <= ScalarField::MODULUS - 1`, which is equivalent to `bits_le < ScalarField::MODULUS`.
+            let modulus_minus_one = (-console::Scalar::<E::Network>::one()).to_bits_le();
+            let is_less_than_modulus = is_less_than_or_equal_constant(randomizer_bits, &modulus_minus_one);
+            E::assert(is_less_than_modulus);
+        }
+
+        // Compute h^r, and add it to the hash of the input.
+        randomizer_bits
+            .iter()
+            .zip(&self.random_base)
+            .map(|(bit, power)| Group::ternary(bit, power, &Group::zero()))
+            .fold(self.hash_uncompressed(input), |acc, x| acc + x)
+            .to_x_coordinate()
+    }
+}
+
+impl<E: Environment, const NUM_BITS: u8> Metrics<dyn CommitFromBits<Input = Boolean<E>, Output = Field<E>>>
+    for Pedersen<E, NUM_BITS>
+{
+    type Case = (Vec<Mode>, Vec<Mode>);
+
+    fn count(case: &Self::Case) -> Count {
+        let (input_modes, randomizer_modes) = case;
+        let uncompressed_count =
+            count!(Pedersen<E, NUM_BITS>, HashUncompressed<Input = Boolean<E>, Output = Group<E>>, input_modes);
+        let uncompressed_mode =
+            output_mode!(Pedersen<E, NUM_BITS>, HashUncompressed<Input = Boolean<E>, Output = Group<E>>, input_modes);
+
+        // Compute the cost of ensuring the randomizer bits are less than the modulus.
+        let canonical_count = match randomizer_modes.len() > console::Scalar::<E::Network>::size_in_data_bits() {
+            false => Count::zero(),
+            true => {
+                // Each `AND` or `OR` is free until the accumulated result is a variable.
+                // Note: The first `OR` with a variable bit is free, as the accumulated result is the constant `false`.
+                let modulus_minus_one = (-console::Scalar::<E::Network>::one()).to_bits_le();
+                let (_, num_gates) = modulus_minus_one.iter().zip_eq(randomizer_modes).fold(
+                    (false, 0u64),
+                    |(is_variable, num_gates), (this, mode)| match (is_variable, mode.is_constant()) {
+                        (false, false) => (!*this, num_gates),
+                        (true, false) => (true, num_gates + 1),
+                        (is_variable, true) => (is_variable, num_gates),
+                    },
+                );
+                // Note: Constant bits may shortcut the gates, so a mix of modes is only bounded.
+                match (
+                    randomizer_modes.iter().all(|mode| mode.is_constant()),
+                    randomizer_modes.iter().any(|mode| mode.is_constant()),
+                ) {
+                    (true, _) => Count::zero(),
+                    (false, false) => Count::is(0, 0, num_gates, num_gates + 1),
+                    (false, true) => Count::less_than(0, 0, num_gates, num_gates + 1),
+                }
+            }
+        };
+
+        // Compute the cost of constructing the group elements.
+        let group_initialize_count = randomizer_modes
+            .iter()
+            .map(|mode| {
+                count!(
+                    Group<E>,
+                    Ternary<Boolean = Boolean<E>, Output = Group<E>>,
+                    &(*mode, Mode::Constant, Mode::Constant)
+                )
+            })
+            .fold(Count::zero(), |cumulative, count| cumulative + count);
+
+        // Calculate the cost of summing the group elements.
+        let (_, summation_count) =
+            randomizer_modes.iter().fold((uncompressed_mode, Count::zero()), |(prev_mode, cumulative), mode| {
+                // The `first` and `second` inputs to `Group::ternary` are always constant so we can directly determine the mode.
+                let curr_mode = match mode.is_constant() {
+                    true => Mode::Constant,
+                    false => Mode::Private,
+                };
+                let mode = output_mode!(Group<E>, Add<Group<E>, Output = Group<E>>, &(prev_mode, curr_mode));
+                let sum_count = count!(Group<E>, Add<Group<E>, Output = Group<E>>, &(prev_mode, curr_mode));
+                (mode, cumulative + sum_count)
+            });
+
+        uncompressed_count + canonical_count + group_initialize_count + summation_count
+    }
+}
+
+impl<E: Environment, const NUM_BITS: u8> OutputMode<dyn CommitFromBits<Input = Boolean<E>, Output = Field<E>>>
+    for Pedersen<E, NUM_BITS>
+{
+    type Case = (Vec<Mode>, Vec<Mode>);
+
+    fn output_mode(parameters: &Self::Case) -> Mode {
+        let (input_modes, randomizer_modes) = parameters;
+        match input_modes.iter().all(|m| *m == Mode::Constant) && randomizer_modes.iter().all(|m| *m == Mode::Constant)
+        {
+            true => Mode::Constant,
+            false => Mode::Private,
+        }
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    const ITERATIONS: u64 = 10;
+    const MESSAGE: &str = "PedersenCircuit0";
+    const NUM_BITS: u8 = 64;
+
+    fn check_commit_from_randomizer_bits(mode: Mode, rng: &mut TestRng) {
+        use console::{Commit as C, ToBits as T};
+
+        // Initialize Pedersen.
+        let native = console::Pedersen::<<Circuit as Environment>::Network, NUM_BITS>::setup(MESSAGE);
+        let circuit = Pedersen::<Circuit, NUM_BITS>::constant(native.clone());
+
+        for i in 0..ITERATIONS {
+            // Sample a random input and randomizer.
+            let input = (0..NUM_BITS).map(|_| bool::rand(rng)).collect::<Vec<bool>>();
+            let randomizer: console::Scalar<_> = Uniform::rand(rng);
+            // Compute the expected commitment.
+            let expected = native.commit(&input, &randomizer).expect("Failed to commit native input");
+            // Prepare the circuit input and randomizer bits.
+            let circuit_input: Vec<Boolean<_>> = Inject::new(mode, input);
+            let circuit_randomizer_bits: Vec<Boolean<_>> = Inject::new(mode, randomizer.to_bits_le());
+            let circuit_randomizer = Scalar::<Circuit>::new(mode, randomizer);
+
+            Circuit::scope(format!("Pedersen {mode} {i}"), || {
+                // Perform the commit operation.
+                let candidate = circuit.commit_from_randomizer_bits(&circuit_input, &circuit_randomizer_bits);
+                assert_eq!(expected, candidate.eject_value());
+
+                // Check constraint counts and output mode.
+                let input_modes = circuit_input.iter().map(|b| b.eject_mode()).collect::<Vec<_>>();
+                let randomizer_modes = circuit_randomizer_bits.iter().map(|b| b.eject_mode()).collect::<Vec<_>>();
+                assert_count!(
+                    Pedersen<Circuit, NUM_BITS>,
+                    CommitFromBits<Input = Boolean<Circuit>, Output = Field<Circuit>>,
+                    &(input_modes.clone(), randomizer_modes.clone())
+                );
+                assert_output_mode!(
+                    Pedersen<Circuit, NUM_BITS>,
+                    CommitFromBits<Input = Boolean<Circuit>, Output = Field<Circuit>>,
+                    &(input_modes, randomizer_modes),
+                    candidate
+                );
+            });
+
+            // Ensure the commitment matches `commit` with the equivalent scalar.
+            let candidate = circuit.commit_from_randomizer_bits(&circuit_input, &circuit_randomizer_bits);
+            assert_eq!(circuit.commit(&circuit_input, &circuit_randomizer).eject_value(), candidate.eject_value());
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_commit_from_randomizer_bits_constant() {
+        let mut rng = TestRng::default();
+        check_commit_from_randomizer_bits(Mode::Constant, &mut rng);
+    }
+
+    #[test]
+    fn test_commit_from_randomizer_bits_public() {
+        let mut rng = TestRng::default();
+        check_commit_from_randomizer_bits(Mode::Public, &mut rng);
+    }
+
+    #[test]
+    fn test_commit_from_randomizer_bits_private() {
+        let mut rng = TestRng::default();
+        check_commit_from_randomizer_bits(Mode::Private, &mut rng);
+    }
+
+    #[test]
+    fn test_commit_from_randomizer_bits_is_canonical() {
+        use console::ToBits as T;
+
+        let circuit = Pedersen::<Circuit, NUM_BITS>::constant(console::Pedersen::setup(MESSAGE));
+        let input: Vec<Boolean<_>> = Inject::new(Mode::Private, vec![true; NUM_BITS as usize]);
+
+        // Construct the bits of the scalar field modulus, which is not canonical.
+        let modulus_minus_one = (-console::Scalar::<<Circuit as Environment>::Network>::one()).to_bits_le();
+        let mut modulus = modulus_minus_one.clone();
+        for bit in modulus.iter_mut() {
+            *bit = !*bit;
+            if *bit {
+                break;
+            }
+        }
+        let randomizer_bits: Vec<Boolean<_>> = Inject::new(Mode::Private, modulus);
+
+        let _candidate = circuit.commit_from_randomizer_bits(&input, &randomizer_bits);
+        assert!(!Circuit::is_satisfied());
+        Circuit::reset();
+    }
+}
@@ -0,0 +1,224 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, const NUM_BITS: u8> CommitRefresh for Pedersen<E, NUM_BITS> {
+    type Output = Group<E>;
+    type Randomizer = Scalar<E>;
+
+    /// Returns the given Pedersen commitment, re-blinded from the old randomizer to the new randomizer,
+    /// by computing `commitment + (new_randomizer - old_randomizer) * h`.
+    ///
+    /// The difference is computed as `new_randomizer + (MODULUS - old_randomizer)`, which lies in `(0, 2 * MODULUS)`,
+    /// and is decomposed into one more bit than a scalar. As `h` is in the prime-order subgroup, the multiple
+    /// of the modulus vanishes, so only a single scalar multiplication is required.
+    ///
+    /// Note: The number of constraints is independent of the values of the randomizers.
+    fn refresh_blinding(
+        &self,
+        commitment: &Self::Output,
+        old_randomizer: &Self::Randomizer,
+        new_randomizer: &Self::Randomizer,
+    ) -> Self::Output {
+        // Initialize the scalar field modulus as a constant base field element.
+        // Note: This is safe as the scalar field modulus is less than the base field modulus.
+        let modulus = Field::constant(match console::FromBits::from_bits_le(&E::ScalarField::modulus().to_bits_le()) {
+            Ok(modulus) => modulus,
+            Err(error) => E::halt(format!("Failed to retrieve the scalar modulus as a field element: {error}")),
+        });
+
+        // Compute the bits of `new_randomizer + (MODULUS - old_randomizer)`.
+        let difference = new_randomizer.to_field() + (modulus - old_randomizer.to_field());
+        let difference_bits = difference.to_lower_bits_le(E::ScalarField::size_in_bits() + 1);
+
+        // Extend the random base with `h^(2^SCALAR_SIZE_IN_BITS)`, for the most significant bit of the difference.
+        let random_base = match self.random_base.last() {
+            Some(last) => self.random_base.iter().cloned().chain([last.double()]).collect::<Vec<_>>(),
+            None => E::halt("The Pedersen random base is empty"),
+        };
+
+        // Compute commitment + h^(r_new - r_old).
+        difference_bits
+            .iter()
+            .zip_eq(&random_base)
+            .map(|(bit, power)| Group::ternary(bit, power, &Group::zero()))
+            .fold(commitment.clone(), |acc, x| acc + x)
+    }
+}
+
+impl<E: Environment, const NUM_BITS: u8> Metrics<dyn CommitRefresh<Output = Group<E>, Randomizer = Scalar<E>>>
+    for Pedersen<E, NUM_BITS>
+{
+    type Case = (Mode, Mode, Mode);
+
+    fn count(case: &Self::Case) -> Count {
+        let (commitment_mode, old_randomizer_mode, new_randomizer_mode) = case;
+
+        // Determine the mode of the difference of the randomizers.
+        let difference_mode = match old_randomizer_mode.is_constant() && new_randomizer_mode.is_constant() {
+            true => Mode::Constant,
+            false => Mode::Private,
+        };
+        let num_bits = E::ScalarField::size_in_bits() + 1;
+
+        // Compute the cost of injecting the modulus, and decomposing the difference into bits.
+        let difference_count = Count::is(1, 0, 0, 0)
+            + count!(Field<E>, ToLowerBits<Boolean = Boolean<E>>, &(difference_mode, num_bits as u64));
+        let bit_mode = output_mode!(Field<E>, ToLowerBits<Boolean = Boolean<E>>, &difference_mode);
+
+        // Compute the cost of extending the random base.
+        let random_base_count = count!(Group<E>, Double<Output = Group<E>>, &Mode::Constant);
+
+        // Compute the cost of constructing the group elements.
+        let group_initialize_count = count!(
+            Group<E>,
+            Ternary<Boolean = Boolean<E>, Output = Group<E>>,
+            &(bit_mode, Mode::Constant, Mode::Constant)
+        ) * num_bits as u64;
+
+        // Determine the mode of each of the group elements.
+        // The `first` and `second` inputs to `Group::ternary` are always constant, so the mode follows the bit.
+        let group_mode = match bit_mode.is_constant() {
+            true => Mode::Constant,
+            false => Mode::Private,
+        };
+
+        // Calculate the cost of summing the group elements into the commitment.
+        let (_, summation_count) =
+            (0..num_bits).fold((*commitment_mode, Count::zero()), |(prev_mode, cumulative), _| {
+                let mode = output_mode!(Group<E>, Add<Group<E>, Output = Group<E>>, &(prev_mode, group_mode));
+                let sum_count = count!(Group<E>, Add<Group<E>, Output = Group<E>>, &(prev_mode, group_mode));
+                (mode, cumulative + sum_count)
+            });
+
+        difference_count + random_base_count + group_initialize_count + summation_count
+    }
+}
+
+impl<E: Environment, const NUM_BITS: u8> OutputMode<dyn CommitRefresh<Output = Group<E>, Randomizer = Scalar<E>>>
+    for Pedersen<E, NUM_BITS>
+{
+    type Case = (Mode, Mode, Mode);
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        let (commitment_mode, old_randomizer_mode, new_randomizer_mode) = case;
+        match commitment_mode.is_constant() && old_randomizer_mode.is_constant() && new_randomizer_mode.is_constant() {
+            true => Mode::Constant,
+            false => Mode::Private,
+        }
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    const ITERATIONS: u64 = 10;
+    const MESSAGE: &str = "PedersenCircuit0";
+    const NUM_BITS_MULTIPLIER: u8 = 8;
+
+    fn check_refresh_blinding<const NUM_BITS: u8>(mode: Mode, rng: &mut TestRng) {
+        use console::CommitUncompressed as C;
+
+        // Initialize Pedersen.
+        let native = console::Pedersen::<<Circuit as Environment>::Network, NUM_BITS>::setup(MESSAGE);
+        let circuit = Pedersen::<Circuit, NUM_BITS>::constant(native.clone());
+
+        for i in 0..ITERATIONS {
+            // Sample a random input.
+            let input = (0..NUM_BITS).map(|_| bool::rand(rng)).collect::<Vec<bool>>();
+            // Sample the old and new randomizers.
+            let old_randomizer = Uniform::rand(rng);
+            let new_randomizer = Uniform::rand(rng);
+            // Compute the original commitment.
+            let commitment =
+                native.commit_uncompressed(&input, &old_randomizer).expect("Failed to commit native input");
+            // Compute the expected commitment, which opens to the same input with the new randomizer.
+            let expected = native.commit_uncompressed(&input, &new_randomizer).expect("Failed to commit native input");
+            // Prepare the circuit commitment and randomizers.
+            let circuit_commitment = Group::<Circuit>::new(mode, commitment);
+            let circuit_old_randomizer = Scalar::<Circuit>::new(mode, old_randomizer);
+            let circuit_new_randomizer = Scalar::<Circuit>::new(mode, new_randomizer);
+
+            Circuit::scope(format!("Pedersen {mode} {i}"), || {
+                // Perform the refresh operation.
+                let candidate =
+                    circuit.refresh_blinding(&circuit_commitment, &circuit_old_randomizer, &circuit_new_randomizer);
+                assert_eq!(expected, candidate.eject_value());
+
+                // Check constraint counts and output mode.
+                assert_count!(
+                    Pedersen<Circuit, NUM_BITS>,
+                    CommitRefresh<Output = Group<Circuit>, Randomizer = Scalar<Circuit>>,
+                    &(mode, mode, mode)
+                );
+                assert_output_mode!(
+                    Pedersen<Circuit, NUM_BITS>,
+                    CommitRefresh<Output = Group<Circuit>, Randomizer = Scalar<Circuit>>,
+                    &(mode, mode, mode),
+                    candidate
+                );
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_refresh_blinding_constant() {
+        let mut rng = TestRng::default();
+        check_refresh_blinding::<NUM_BITS_MULTIPLIER>(Mode::Constant, &mut rng);
+        check_refresh_blinding::<{ 2 * NUM_BITS_MULTIPLIER }>(Mode::Constant, &mut rng);
+    }
+
+    #[test]
+    fn test_refresh_blinding_public() {
+        let mut rng = TestRng::default();
+        check_refresh_blinding::<NUM_BITS_MULTIPLIER>(Mode::Public, &mut rng);
+        check_refresh_blinding::<{ 2 * NUM_BITS_MULTIPLIER }>(Mode::Public, &mut rng);
+    }
+
+    #[test]
+    fn test_refresh_blinding_private() {
+        let mut rng = TestRng::default();
+        check_refresh_blinding::<NUM_BITS_MULTIPLIER>(Mode::Private, &mut rng);
+        check_refresh_blinding::<{ 2 * NUM_BITS_MULTIPLIER }>(Mode::Private, &mut rng);
+    }
+
+    #[test]
+    fn test_refresh_blinding_boundary() {
+        let mut rng = TestRng::default();
+
+        // Initialize Pedersen.
+        let circuit = Pedersen::<Circuit, NUM_BITS_MULTIPLIER>::constant(console::Pedersen::setup(MESSAGE));
+        let commitment = Uniform::rand(&mut rng);
+
+        // Ensure the commitment is unchanged when the randomizers are equal, including when both are zero or
+        // the maximum scalar, as the difference is then the modulus itself.
+        let zero = console::Scalar::zero();
+        let max = -console::Scalar::one();
+        for randomizer in [zero, max, Uniform::rand(&mut rng)] {
+            let circuit_commitment = Group::<Circuit>::new(Mode::Private, commitment);
+            let randomizer = Scalar::<Circuit>::new(Mode::Private, randomizer);
+            let candidate = circuit.refresh_blinding(&circuit_commitment, &randomizer, &randomizer);
+            assert_eq!(commitment, candidate.eject_value());
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+    }
+}
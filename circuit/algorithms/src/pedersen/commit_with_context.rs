@@ -0,0 +1,219 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// The number of bits used to encode the length of the context.
+const CONTEXT_LENGTH_BITS: usize = 8;
+
+impl<E: Environment, const NUM_BITS: u8> CommitWithContext for Pedersen<E, NUM_BITS> {
+    type Input = Boolean<E>;
+    type Output = Field<E>;
+    type Randomizer = Scalar<E>;
+
+    /// Returns the Pedersen commitment of the given input and randomizer, bound to the given context, as a field element.
+    ///
+    /// The preimage is `len(context) || context || input`, where the length is encoded in 8 bits, so that
+    /// no bits can be moved between the context and the input without changing the commitment.
+    /// The length, context, and input must fit within `NUM_BITS` bits.
+    /// Note: As Pedersen is additively homomorphic, commitments to the same input under two contexts
+    /// differ by a publicly-computable offset, so the context must not be treated as a secret.
+    fn commit_with_context(
+        &self,
+        context: &[Self::Input],
+        input: &[Self::Input],
+        randomizer: &Self::Randomizer,
+    ) -> Self::Output {
+        // Ensure the length of the context can be encoded.
+        if context.len() >= 1 << CONTEXT_LENGTH_BITS {
+            E::halt(format!("The context exceeds the maximum length of {} bits", (1 << CONTEXT_LENGTH_BITS) - 1))
+        }
+
+        // Construct the preimage: len(context) || context || input.
+        let mut preimage = Vec::with_capacity(CONTEXT_LENGTH_BITS + context.len() + input.len());
+        preimage.extend((0..CONTEXT_LENGTH_BITS).map(|i| Boolean::constant((context.len() >> i) & 1 == 1)));
+        preimage.extend_from_slice(context);
+        preimage.extend_from_slice(input);
+
+        // Commit to the preimage.
+        self.commit(&preimage, randomizer)
+    }
+}
+
+impl<E: Environment, const NUM_BITS: u8>
+    Metrics<dyn CommitWithContext<Input = Boolean<E>, Output = Field<E>, Randomizer = Scalar<E>>>
+    for Pedersen<E, NUM_BITS>
+{
+    type Case = (Vec<Mode>, Vec<Mode>, Vec<Mode>);
+
+    fn count(case: &Self::Case) -> Count {
+        let (context_modes, input_modes, randomizer_modes) = case;
+        let preimage_modes = preimage_modes(context_modes, input_modes);
+        count!(
+            Pedersen<E, NUM_BITS>,
+            Commit<Input = Boolean<E>, Output = Field<E>, Randomizer = Scalar<E>>,
+            &(preimage_modes, randomizer_modes.clone())
+        )
+    }
+}
+
+impl<E: Environment, const NUM_BITS: u8>
+    OutputMode<dyn CommitWithContext<Input = Boolean<E>, Output = Field<E>, Randomizer = Scalar<E>>>
+    for Pedersen<E, NUM_BITS>
+{
+    type Case = (Vec<Mode>, Vec<Mode>, Vec<Mode>);
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        let (context_modes, input_modes, randomizer_modes) = case;
+        let preimage_modes = preimage_modes(context_modes, input_modes);
+        output_mode!(
+            Pedersen<E, NUM_BITS>,
+            Commit<Input = Boolean<E>, Output = Field<E>, Randomizer = Scalar<E>>,
+            &(preimage_modes, randomizer_modes.clone())
+        )
+    }
+}
+
+/// Returns the modes of the preimage, given the modes of the context and input.
+fn preimage_modes(context_modes: &[Mode], input_modes: &[Mode]) -> Vec<Mode> {
+    [vec![Mode::Constant; CONTEXT_LENGTH_BITS].as_slice(), context_modes, input_modes].concat()
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    const ITERATIONS: u64 = 10;
+    const MESSAGE: &str = "PedersenCircuit0";
+    const NUM_BITS: u8 = 64;
+    const NUM_CONTEXT_BITS: usize = 16;
+
+    /// Returns the little-endian bits of the context length, followed by the context.
+    fn encode_context(context: &[bool]) -> Vec<bool> {
+        (0..CONTEXT_LENGTH_BITS).map(|i| (context.len() >> i) & 1 == 1).chain(context.iter().copied()).collect()
+    }
+
+    fn check_commit_with_context(mode: Mode, rng: &mut TestRng) {
+        use console::Commit as C;
+
+        // Initialize Pedersen.
+        let native = console::Pedersen::<<Circuit as Environment>::Network, NUM_BITS>::setup(MESSAGE);
+        let circuit = Pedersen::<Circuit, NUM_BITS>::constant(native.clone());
+
+        for i in 0..ITERATIONS {
+            // Sample two distinct contexts.
+            let first_context = (0..NUM_CONTEXT_BITS).map(|_| bool::rand(rng)).collect::<Vec<bool>>();
+            let mut second_context = first_context.clone();
+            second_context[0] = !second_context[0];
+            // Sample a random input.
+            let input = (0..NUM_BITS as usize - CONTEXT_LENGTH_BITS - NUM_CONTEXT_BITS)
+                .map(|_| bool::rand(rng))
+                .collect::<Vec<bool>>();
+            // Sample a randomizer.
+            let randomizer = Uniform::rand(rng);
+            // Compute the expected commitment.
+            let expected = native
+                .commit(&[encode_context(&first_context), input.clone()].concat(), &randomizer)
+                .expect("Failed to commit native input");
+
+            // Prepare the circuit inputs.
+            let circuit_first_context: Vec<Boolean<_>> = Inject::new(Mode::Constant, first_context);
+            let circuit_second_context: Vec<Boolean<_>> = Inject::new(Mode::Constant, second_context);
+            let circuit_input: Vec<Boolean<_>> = Inject::new(mode, input);
+            let circuit_randomizer: Scalar<_> = Inject::new(mode, randomizer);
+
+            Circuit::scope(format!("Pedersen {mode} {i}"), || {
+                // Perform the commit operation under the first context.
+                let first = circuit.commit_with_context(&circuit_first_context, &circuit_input, &circuit_randomizer);
+                assert_eq!(expected, first.eject_value());
+
+                // Check constraint counts and output mode.
+                let context_modes = circuit_first_context.iter().map(|b| b.eject_mode()).collect::<Vec<_>>();
+                let input_modes = circuit_input.iter().map(|b| b.eject_mode()).collect::<Vec<_>>();
+                let randomizer_modes =
+                    circuit_randomizer.to_bits_le().iter().map(|b| b.eject_mode()).collect::<Vec<_>>();
+                let case = (context_modes, input_modes, randomizer_modes);
+                assert_count!(
+                    Pedersen<Circuit, NUM_BITS>,
+                    CommitWithContext<Input = Boolean<Circuit>, Output = Field<Circuit>, Randomizer = Scalar<Circuit>>,
+                    &case
+                );
+                assert_output_mode!(
+                    Pedersen<Circuit, NUM_BITS>,
+                    CommitWithContext<Input = Boolean<Circuit>, Output = Field<Circuit>, Randomizer = Scalar<Circuit>>,
+                    &case,
+                    first
+                );
+
+                // Ensure the second context yields a different commitment.
+                let second = circuit.commit_with_context(&circuit_second_context, &circuit_input, &circuit_randomizer);
+                assert_ne!(first.eject_value(), second.eject_value());
+                assert!(Circuit::is_satisfied_in_scope());
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_commit_with_context_constant() {
+        let mut rng = TestRng::default();
+        check_commit_with_context(Mode::Constant, &mut rng);
+    }
+
+    #[test]
+    fn test_commit_with_context_public() {
+        let mut rng = TestRng::default();
+        check_commit_with_context(Mode::Public, &mut rng);
+    }
+
+    #[test]
+    fn test_commit_with_context_private() {
+        let mut rng = TestRng::default();
+        check_commit_with_context(Mode::Private, &mut rng);
+    }
+
+    #[test]
+    fn test_commit_with_context_boundary() {
+        let mut rng = TestRng::default();
+
+        // Initialize Pedersen.
+        let native = console::Pedersen::<<Circuit as Environment>::Network, NUM_BITS>::setup(MESSAGE);
+        let circuit = Pedersen::<Circuit, NUM_BITS>::constant(native);
+
+        // Sample the bits to split between the context and the input.
+        let bits = (0..16).map(|_| bool::rand(&mut rng)).collect::<Vec<bool>>();
+        let randomizer: Scalar<Circuit> = Inject::new(Mode::Private, Uniform::rand(&mut rng));
+
+        // Ensure moving a bit from the context to the input changes the commitment.
+        let (context, input) = bits.split_at(2);
+        let first = circuit.commit_with_context(
+            &<Vec<Boolean<_>> as Inject>::new(Mode::Constant, context.to_vec()),
+            &<Vec<Boolean<_>> as Inject>::new(Mode::Private, input.to_vec()),
+            &randomizer,
+        );
+        let (context, input) = bits.split_at(1);
+        let second = circuit.commit_with_context(
+            &<Vec<Boolean<_>> as Inject>::new(Mode::Constant, context.to_vec()),
+            &<Vec<Boolean<_>> as Inject>::new(Mode::Private, input.to_vec()),
+            &randomizer,
+        );
+        assert_ne!(first.eject_value(), second.eject_value());
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+}
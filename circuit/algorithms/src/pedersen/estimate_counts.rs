@@ -0,0 +1,83 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, const NUM_BITS: u8> Pedersen<E, NUM_BITS> {
+    /// Returns the estimated constraint counts for hashing a private input of `input_len` bits.
+    ///
+    /// The estimate is computed from the `Metrics` of `Hash`, without synthesizing the circuit,
+    /// and can be used to compare the cost of Pedersen instances with different `NUM_BITS`.
+    pub fn estimate_counts(input_len: usize) -> Count {
+        // Ensure the input size is within the parameter size.
+        if input_len > NUM_BITS as usize {
+            E::halt(format!("The Pedersen hash input cannot exceed {NUM_BITS} bits."))
+        }
+
+        // The constant padding up to `NUM_BITS` does not incur any cost, so only the input bits are counted.
+        let case = vec![Mode::Private; input_len];
+        count!(Pedersen<E, NUM_BITS>, Hash<Input = Boolean<E>, Output = Field<E>>, &case)
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    const MESSAGE: &str = "PedersenCircuit0";
+
+    fn check_estimate_counts<const NUM_BITS: u8>(input_len: usize, rng: &mut TestRng) {
+        // Initialize the Pedersen hash.
+        let circuit = Pedersen::<Circuit, NUM_BITS>::constant(console::Pedersen::setup(MESSAGE));
+
+        // Sample a random private input.
+        let input: Vec<Boolean<_>> =
+            Inject::new(Mode::Private, (0..input_len).map(|_| bool::rand(rng)).collect::<Vec<bool>>());
+
+        Circuit::scope(format!("Pedersen {NUM_BITS} {input_len}"), || {
+            // Perform the hash operation.
+            let _candidate = circuit.hash(&input);
+
+            // Check the estimate against the synthesized counts.
+            let estimate = Pedersen::<Circuit, NUM_BITS>::estimate_counts(input_len);
+            assert!(estimate.matches(
+                Circuit::num_constants_in_scope(),
+                Circuit::num_public_in_scope(),
+                Circuit::num_private_in_scope(),
+                Circuit::num_constraints_in_scope()
+            ));
+        });
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_estimate_counts() {
+        let mut rng = TestRng::default();
+
+        check_estimate_counts::<64>(64, &mut rng);
+        check_estimate_counts::<64>(32, &mut rng);
+        check_estimate_counts::<128>(128, &mut rng);
+        check_estimate_counts::<128>(100, &mut rng);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_estimate_counts_exceeds_capacity() {
+        let _ = Pedersen::<Circuit, 64>::estimate_counts(65);
+    }
+}
@@ -21,6 +21,12 @@ impl<E: Environment, const NUM_BITS: u8> Hash for Pedersen<E, NUM_BITS> {
     type Output = Field<E>;
 
     /// Returns the Pedersen hash of the given input as a field element.
+    ///
+    /// Note: The output is only the x-coordinate of the hash point, so the hash points `(x, y)` and `(x, -y)`
+    /// yield the same digest. Use `hash_to_point` for a digest that is bound to the full point.
+    ///
+    /// An input shorter than `NUM_BITS` is zero-padded, so the empty input (like any all-zero input) hashes to
+    /// the identity point, and `hash(&[])` is the constant `0` for every setup message.
     fn hash(&self, input: &[Self::Input]) -> Self::Output {
         // Compute the Pedersen hash as an affine group element, and return the x-coordinate.
         self.hash_uncompressed(input).to_x_coordinate()
@@ -128,4 +134,74 @@ mod tests {
         check_hash::<{ 4 * NUM_BITS_MULTIPLIER }>(Mode::Private, &mut rng);
         check_hash::<{ 5 * NUM_BITS_MULTIPLIER }>(Mode::Private, &mut rng);
     }
+
+    #[test]
+    fn test_hash_empty_input() {
+        for message in ["PedersenCircuit0", "PedersenCircuit1"] {
+            let circuit = Pedersen64::<Circuit>::constant(console::Pedersen64::setup(message));
+
+            Circuit::scope(format!("Pedersen empty {message}"), || {
+                // Ensure the empty input hashes to the constant `0`, for any setup message.
+                let empty = circuit.hash(&[]);
+                assert!(empty.is_constant());
+                assert_eq!(console::Field::zero(), empty.eject_value());
+                assert_eq!(console::Field::zero(), circuit.hash_length_bound(&[]).eject_value());
+                assert_scope!(0, 0, 0, 0);
+            });
+
+            for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+                let zero: Vec<Boolean<_>> = Inject::new(mode, vec![false]);
+
+                // Ensure the unbound hash collides with a single zero bit, while the length-bound hash does not.
+                assert_eq!(circuit.hash(&[]).eject_value(), circuit.hash(&zero).eject_value());
+                assert_ne!(
+                    circuit.hash_length_bound(&[]).eject_value(),
+                    circuit.hash_length_bound(&zero).eject_value()
+                );
+                Circuit::reset();
+            }
+        }
+    }
+
+    #[test]
+    fn test_hash_count_snapshot() {
+        let native = console::Pedersen128::<<Circuit as Environment>::Network>::setup(MESSAGE);
+        let circuit = Pedersen128::<Circuit>::constant(native);
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            let input: Vec<Boolean<_>> = Inject::new(mode, vec![true; 128]);
+
+            Circuit::scope(format!("Pedersen128 {mode}"), || {
+                let _candidate = circuit.hash(&input);
+                let (num_constants, num_public, num_private, num_constraints, _) = Circuit::count_in_scope();
+                let count = Count::is(num_constants, num_public, num_private, num_constraints);
+                assert_count_snapshot!(format!("pedersen128_hash_{mode}"), count);
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_hash_within_budget() {
+        // Ensure hashing a full 128-bit input stays within 800 constraints, for every mode.
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            assert_within_budget!(
+                Pedersen128<Circuit>,
+                Hash<Input = Boolean<Circuit>, Output = Field<Circuit>>,
+                &vec![mode; 128],
+                800
+            );
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "exceeding the budget")]
+    fn test_hash_exceeds_budget() {
+        assert_within_budget!(
+            Pedersen128<Circuit>,
+            Hash<Input = Boolean<Circuit>, Output = Field<Circuit>>,
+            &vec![Mode::Private; 128],
+            700
+        );
+    }
 }
@@ -0,0 +1,87 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, const NUM_BITS: u8> Pedersen<E, NUM_BITS> {
+    /// Returns the Pedersen hash of the little-endian bits of the given value as a field element.
+    ///
+    /// This accepts any circuit that implements `ToBits`, including integers, arrays such as `[U8<E>; 32]`,
+    /// and tuples of circuits, and is equivalent to calling `hash` on `value.to_bits_le()`.
+    pub fn hash_any<T: ToBits<Boolean = Boolean<E>>>(&self, value: &T) -> Field<E> {
+        self.hash(&value.to_bits_le())
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::{environment::Circuit, U16, U64};
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    const ITERATIONS: u64 = 10;
+    const MESSAGE: &str = "PedersenCircuit0";
+    const NUM_BITS: u8 = 64;
+
+    fn check_hash_any(mode: Mode, rng: &mut TestRng) {
+        let circuit = Pedersen::<Circuit, NUM_BITS>::constant(console::Pedersen::setup(MESSAGE));
+
+        for i in 0..ITERATIONS {
+            Circuit::scope(format!("Pedersen {mode} {i}"), || {
+                // Hash an integer.
+                let integer = U64::<Circuit>::new(mode, Uniform::rand(rng));
+                let expected = circuit.hash(&integer.to_bits_le());
+                assert_eq!(expected.eject_value(), circuit.hash_any(&integer).eject_value());
+
+                // Hash a tuple, and compare against the manually flattened bits.
+                let tuple = (
+                    U8::<Circuit>::new(mode, Uniform::rand(rng)),
+                    Boolean::<Circuit>::new(mode, bool::rand(rng)),
+                    U16::<Circuit>::new(mode, Uniform::rand(rng)),
+                );
+                let mut bits = tuple.0.to_bits_le();
+                bits.push(tuple.1.clone());
+                bits.extend(tuple.2.to_bits_le());
+                let expected = circuit.hash(&bits);
+                assert_eq!(expected.eject_value(), circuit.hash_any(&tuple).eject_value());
+
+                // Hash a fixed-size byte array, and compare against the manually flattened bits.
+                let bytes: [U8<Circuit>; 8] = core::array::from_fn(|_| U8::new(mode, Uniform::rand(rng)));
+                let expected = circuit.hash(&bytes.iter().flat_map(|byte| byte.to_bits_le()).collect::<Vec<_>>());
+                assert_eq!(expected.eject_value(), circuit.hash_any(&bytes).eject_value());
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_hash_any_constant() {
+        let mut rng = TestRng::default();
+        check_hash_any(Mode::Constant, &mut rng);
+    }
+
+    #[test]
+    fn test_hash_any_public() {
+        let mut rng = TestRng::default();
+        check_hash_any(Mode::Public, &mut rng);
+    }
+
+    #[test]
+    fn test_hash_any_private() {
+        let mut rng = TestRng::default();
+        check_hash_any(Mode::Private, &mut rng);
+    }
+}
@@ -0,0 +1,125 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, const NUM_BITS: u8> HashBytes for Pedersen<E, NUM_BITS> {
+    type Input = U8<E>;
+    type Output = Field<E>;
+
+    /// Returns the Pedersen hash of the given bytes as a field element.
+    ///
+    /// The input is hashed as the concatenation of the little-endian bits of each byte,
+    /// and is equivalent to calling `hash` on the flattened bits.
+    fn hash_bytes(&self, input: &[Self::Input]) -> Self::Output {
+        self.hash(&input.to_bits_le())
+    }
+}
+
+impl<E: Environment, const NUM_BITS: u8> Metrics<dyn HashBytes<Input = U8<E>, Output = Field<E>>>
+    for Pedersen<E, NUM_BITS>
+{
+    type Case = Vec<Mode>;
+
+    #[inline]
+    fn count(case: &Self::Case) -> Count {
+        // Each byte contributes eight bits of the same mode.
+        let modes = case.iter().flat_map(|mode| [*mode; 8]).collect::<Vec<_>>();
+        count!(Pedersen<E, NUM_BITS>, Hash<Input = Boolean<E>, Output = Field<E>>, &modes)
+    }
+}
+
+impl<E: Environment, const NUM_BITS: u8> OutputMode<dyn HashBytes<Input = U8<E>, Output = Field<E>>>
+    for Pedersen<E, NUM_BITS>
+{
+    type Case = Vec<Mode>;
+
+    #[inline]
+    fn output_mode(parameter: &Self::Case) -> Mode {
+        let modes = parameter.iter().flat_map(|mode| [*mode; 8]).collect::<Vec<_>>();
+        output_mode!(Pedersen<E, NUM_BITS>, Hash<Input = Boolean<E>, Output = Field<E>>, &modes)
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    const ITERATIONS: u64 = 10;
+    const MESSAGE: &str = "PedersenCircuit0";
+
+    fn check_hash_bytes<const NUM_BITS: u8>(mode: Mode, rng: &mut TestRng) {
+        // Initialize the Pedersen hash.
+        let native = console::Pedersen::<<Circuit as Environment>::Network, NUM_BITS>::setup(MESSAGE);
+        let circuit = Pedersen::<Circuit, NUM_BITS>::constant(native);
+
+        for i in 0..ITERATIONS {
+            // Sample random bytes.
+            let input = (0..NUM_BITS / 8).map(|_| U8::<Circuit>::new(mode, Uniform::rand(rng))).collect::<Vec<_>>();
+
+            Circuit::scope(format!("Pedersen {mode} {i}"), || {
+                // Compute the expected hash, by manually flattening the bytes into bits.
+                let bits = input.iter().flat_map(|byte| byte.to_bits_le()).collect::<Vec<_>>();
+                let expected = circuit.hash(&bits);
+
+                // Perform the hash operation.
+                let candidate = circuit.hash_bytes(&input);
+                assert_eq!(expected.eject_value(), candidate.eject_value());
+            });
+            Circuit::reset();
+
+            Circuit::scope(format!("Pedersen {mode} {i}"), || {
+                // Check constraint counts and output mode.
+                let candidate = circuit.hash_bytes(&input);
+                let modes = input.iter().map(|byte| byte.eject_mode()).collect::<Vec<_>>();
+                assert_count!(Pedersen<Circuit, NUM_BITS>, HashBytes<Input = U8<Circuit>, Output = Field<Circuit>>, &modes);
+                assert_output_mode!(
+                    Pedersen<Circuit, NUM_BITS>,
+                    HashBytes<Input = U8<Circuit>, Output = Field<Circuit>>,
+                    &modes,
+                    candidate
+                );
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_hash_bytes_constant() {
+        let mut rng = TestRng::default();
+        check_hash_bytes::<8>(Mode::Constant, &mut rng);
+        check_hash_bytes::<64>(Mode::Constant, &mut rng);
+        check_hash_bytes::<128>(Mode::Constant, &mut rng);
+    }
+
+    #[test]
+    fn test_hash_bytes_public() {
+        let mut rng = TestRng::default();
+        check_hash_bytes::<8>(Mode::Public, &mut rng);
+        check_hash_bytes::<64>(Mode::Public, &mut rng);
+        check_hash_bytes::<128>(Mode::Public, &mut rng);
+    }
+
+    #[test]
+    fn test_hash_bytes_private() {
+        let mut rng = TestRng::default();
+        check_hash_bytes::<8>(Mode::Private, &mut rng);
+        check_hash_bytes::<64>(Mode::Private, &mut rng);
+        check_hash_bytes::<128>(Mode::Private, &mut rng);
+    }
+}
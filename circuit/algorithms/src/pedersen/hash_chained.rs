@@ -0,0 +1,211 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, const NUM_BITS: u8> HashChained for Pedersen<E, NUM_BITS> {
+    type Input = Boolean<E>;
+    type Output = Field<E>;
+
+    /// Returns the Pedersen hash of a sequence of blocks as a field element.
+    ///
+    /// Each block must fit into `NUM_BITS` bits. The digests are chained (Merkle-Damgård style) as follows:
+    ///   - `digest_0 = num_blocks`
+    ///   - `digest_i = hash_two(digest_{i-1}, hash(block_i))`
+    ///
+    /// The number of blocks is used as the initial digest, so that sequences of different lengths are
+    /// separated from one another, and a single block is not hashed to `hash(block)`.
+    /// An empty sequence is hashed as a single empty block.
+    fn hash_chained(&self, blocks: &[Vec<Self::Input>]) -> Self::Output {
+        // Ensure the sequence contains at least one block.
+        let blocks = match blocks.is_empty() {
+            true => &[vec![]][..],
+            false => blocks,
+        };
+
+        // Initialize the running digest with the number of blocks.
+        let length = Field::constant(console::Field::from_u128(blocks.len() as u128));
+
+        // Fold each block digest into the running digest.
+        blocks.iter().fold(length, |digest, block| {
+            // Compress the running digest and the block digest with the two-to-one hash.
+            self.hash_two(&digest, &self.hash(block))
+        })
+    }
+}
+
+impl<E: Environment, const NUM_BITS: u8> Metrics<dyn HashChained<Input = Boolean<E>, Output = Field<E>>>
+    for Pedersen<E, NUM_BITS>
+{
+    type Case = Vec<Vec<Mode>>;
+
+    fn count(case: &Self::Case) -> Count {
+        // Ensure the sequence contains at least one block.
+        let blocks = match case.is_empty() {
+            true => &[vec![]][..],
+            false => case,
+        };
+
+        // Compute the cost of folding each block into the running digest, starting from the constant length.
+        let (_, count) = blocks.iter().fold((Mode::Constant, Count::zero()), |(digest_mode, cumulative), block| {
+            // Compute the cost of hashing the block.
+            let block_count = count!(Pedersen<E, NUM_BITS>, Hash<Input = Boolean<E>, Output = Field<E>>, block);
+            let block_mode = output_mode!(Pedersen<E, NUM_BITS>, Hash<Input = Boolean<E>, Output = Field<E>>, block);
+
+            // Compute the cost of the two-to-one hash.
+            let compress_count = count!(
+                Pedersen<E, NUM_BITS>,
+                HashTwo<Input = Field<E>, Output = Field<E>>,
+                &(digest_mode, block_mode)
+            );
+            let mode = output_mode!(
+                Pedersen<E, NUM_BITS>,
+                HashTwo<Input = Field<E>, Output = Field<E>>,
+                &(digest_mode, block_mode)
+            );
+
+            (mode, cumulative + block_count + compress_count)
+        });
+
+        count
+    }
+}
+
+impl<E: Environment, const NUM_BITS: u8> OutputMode<dyn HashChained<Input = Boolean<E>, Output = Field<E>>>
+    for Pedersen<E, NUM_BITS>
+{
+    type Case = Vec<Vec<Mode>>;
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        match case.iter().flatten().all(|mode| mode.is_constant()) {
+            true => Mode::Constant,
+            false => Mode::Private,
+        }
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    const ITERATIONS: u64 = 5;
+    const MESSAGE: &str = "PedersenCircuit0";
+    const NUM_BITS: u8 = 128;
+
+    fn sample_blocks(mode: Mode, num_blocks: usize, rng: &mut TestRng) -> Vec<Vec<Boolean<Circuit>>> {
+        (0..num_blocks)
+            .map(|_| Inject::new(mode, (0..NUM_BITS).map(|_| bool::rand(rng)).collect::<Vec<bool>>()))
+            .collect()
+    }
+
+    fn check_hash_chained(mode: Mode, num_blocks: usize, rng: &mut TestRng) {
+        // Initialize the Pedersen hash.
+        let circuit = Pedersen::<Circuit, NUM_BITS>::constant(console::Pedersen::setup(MESSAGE));
+
+        for i in 0..ITERATIONS {
+            // Sample the blocks.
+            let blocks = sample_blocks(mode, num_blocks, rng);
+
+            Circuit::scope(format!("Pedersen {mode} {num_blocks} {i}"), || {
+                // Perform the hash operation.
+                let candidate = circuit.hash_chained(&blocks);
+
+                // Check constraint counts and output mode.
+                let modes = blocks.iter().map(|block| block.iter().map(|b| b.eject_mode()).collect()).collect();
+                assert_count!(
+                    Pedersen<Circuit, NUM_BITS>,
+                    HashChained<Input = Boolean<Circuit>, Output = Field<Circuit>>,
+                    &modes
+                );
+                assert_output_mode!(
+                    Pedersen<Circuit, NUM_BITS>,
+                    HashChained<Input = Boolean<Circuit>, Output = Field<Circuit>>,
+                    &modes,
+                    candidate
+                );
+
+                // Ensure the hash is deterministic.
+                assert_eq!(candidate.eject_value(), circuit.hash_chained(&blocks).eject_value());
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_hash_chained_constant() {
+        let mut rng = TestRng::default();
+        check_hash_chained(Mode::Constant, 1, &mut rng);
+        check_hash_chained(Mode::Constant, 3, &mut rng);
+    }
+
+    #[test]
+    fn test_hash_chained_public() {
+        let mut rng = TestRng::default();
+        check_hash_chained(Mode::Public, 1, &mut rng);
+        check_hash_chained(Mode::Public, 3, &mut rng);
+    }
+
+    #[test]
+    fn test_hash_chained_private() {
+        let mut rng = TestRng::default();
+        check_hash_chained(Mode::Private, 1, &mut rng);
+        check_hash_chained(Mode::Private, 3, &mut rng);
+    }
+
+    #[test]
+    fn test_hash_chained_is_order_sensitive() {
+        let mut rng = TestRng::default();
+
+        // Initialize the Pedersen hash.
+        let circuit = Pedersen::<Circuit, NUM_BITS>::constant(console::Pedersen::setup(MESSAGE));
+
+        for _ in 0..ITERATIONS {
+            // Sample the blocks, and swap the first two.
+            let blocks = sample_blocks(Mode::Private, 3, &mut rng);
+            let mut swapped = blocks.clone();
+            swapped.swap(0, 1);
+
+            let expected = circuit.hash_chained(&blocks).eject_value();
+            let candidate = circuit.hash_chained(&swapped).eject_value();
+            assert_ne!(expected, candidate);
+        }
+    }
+
+    #[test]
+    fn test_hash_chained_is_length_separated() {
+        let mut rng = TestRng::default();
+
+        // Initialize the Pedersen hash.
+        let circuit = Pedersen::<Circuit, NUM_BITS>::constant(console::Pedersen::setup(MESSAGE));
+
+        for _ in 0..ITERATIONS {
+            let blocks = sample_blocks(Mode::Private, 2, &mut rng);
+
+            // Ensure a single block is not hashed to the single-shot hash of the block.
+            let single = circuit.hash_chained(&blocks[..1]).eject_value();
+            assert_ne!(circuit.hash(&blocks[0]).eject_value(), single);
+
+            // Ensure the chained hash of one block differs from the chained hash of two blocks.
+            let double = circuit.hash_chained(&blocks).eject_value();
+            assert_ne!(single, double);
+
+            // Ensure an empty sequence is hashed as a single empty block.
+            assert_eq!(circuit.hash_chained(&[]).eject_value(), circuit.hash_chained(&[vec![]]).eject_value());
+        }
+    }
+}
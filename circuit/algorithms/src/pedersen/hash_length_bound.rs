@@ -0,0 +1,142 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// The number of bits reserved at the end of the input window for the input length.
+const NUM_LENGTH_BITS: usize = 8;
+
+impl<E: Environment, const NUM_BITS: u8> Pedersen<E, NUM_BITS> {
+    /// Returns the Pedersen hash of the given input as a field element, bound to the length of the input.
+    ///
+    /// The input is zero-padded to `NUM_BITS - 8` bits, and followed by the 8-bit little-endian encoding of its
+    /// (non-padded) length. Unlike `hash`, an input and its zero-padded extension hash to different digests.
+    ///
+    /// The empty input has length `0`, so its digest is the constant `0`, as for `hash(&[])`. Every other input,
+    /// including an all-zero input, is offset by the bases of its nonzero length, and hashes to a different digest.
+    ///
+    /// Note: This is a different domain than `hash`, so the digests of the two methods are not interchangeable.
+    pub fn hash_length_bound(&self, input: &[Boolean<E>]) -> Field<E> {
+        // Ensure the input and its length fit within the parameter size.
+        let num_input_bits = (NUM_BITS as usize).saturating_sub(NUM_LENGTH_BITS);
+        if input.len() > num_input_bits {
+            E::halt(format!("The length-bound Pedersen hash input cannot exceed {num_input_bits} bits."))
+        }
+
+        // Offset the digest by the bases of the input length, which is a constant.
+        let offset = self.base_window[num_input_bits..]
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| (input.len() >> i) & 1 == 1)
+            .fold(Group::zero(), |acc, (_, base)| acc + base);
+        (self.hash_uncompressed(input) + offset).to_x_coordinate()
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    use core::panic::AssertUnwindSafe;
+
+    const ITERATIONS: u64 = 10;
+    const MESSAGE: &str = "PedersenCircuit0";
+    const NUM_BITS: u8 = 64;
+
+    fn check_hash_length_bound(mode: Mode, rng: &mut TestRng) {
+        use console::Hash as H;
+
+        // Initialize Pedersen.
+        let native = console::Pedersen::<<Circuit as Environment>::Network, NUM_BITS>::setup(MESSAGE);
+        let circuit = Pedersen::<Circuit, NUM_BITS>::constant(native.clone());
+        let num_input_bits = NUM_BITS as usize - NUM_LENGTH_BITS;
+
+        for i in 0..ITERATIONS {
+            // Sample a random input, which may be shorter than the available input bits.
+            let input = (0..usize::rand(rng) % (num_input_bits + 1)).map(|_| bool::rand(rng)).collect::<Vec<bool>>();
+
+            // Compute the expected digest, by hashing the padded input and its length natively.
+            let mut preimage = input.clone();
+            preimage.resize(num_input_bits, false);
+            preimage.extend((0..NUM_LENGTH_BITS).map(|j| (input.len() >> j) & 1 == 1));
+            let expected = native.hash(&preimage).expect("Failed to hash native input");
+
+            // Prepare the circuit input.
+            let circuit_input: Vec<Boolean<_>> = Inject::new(mode, input);
+
+            Circuit::scope(format!("Pedersen {mode} {i}"), || {
+                let candidate = circuit.hash_length_bound(&circuit_input);
+                assert_eq!(expected, candidate.eject_value());
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_hash_length_bound_constant() {
+        let mut rng = TestRng::default();
+        check_hash_length_bound(Mode::Constant, &mut rng);
+    }
+
+    #[test]
+    fn test_hash_length_bound_public() {
+        let mut rng = TestRng::default();
+        check_hash_length_bound(Mode::Public, &mut rng);
+    }
+
+    #[test]
+    fn test_hash_length_bound_private() {
+        let mut rng = TestRng::default();
+        check_hash_length_bound(Mode::Private, &mut rng);
+    }
+
+    #[test]
+    fn test_hash_length_bound_padding() {
+        let mut rng = TestRng::default();
+
+        let circuit = Pedersen::<Circuit, NUM_BITS>::constant(console::Pedersen::setup(MESSAGE));
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            // Sample a short input, and extend it with zeros.
+            let input = (0..16).map(|_| bool::rand(&mut rng)).collect::<Vec<bool>>();
+            let mut padded = input.clone();
+            padded.resize(24, false);
+
+            let input: Vec<Boolean<_>> = Inject::new(mode, input);
+            let padded: Vec<Boolean<_>> = Inject::new(mode, padded);
+
+            // Ensure the unbound hash collides, while the length-bound hash does not.
+            assert_eq!(circuit.hash(&input).eject_value(), circuit.hash(&padded).eject_value());
+            assert_ne!(
+                circuit.hash_length_bound(&input).eject_value(),
+                circuit.hash_length_bound(&padded).eject_value()
+            );
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_hash_length_bound_fails_on_oversized_input() {
+        let circuit = Pedersen::<Circuit, NUM_BITS>::constant(console::Pedersen::setup(MESSAGE));
+        let input: Vec<Boolean<_>> = Inject::new(Mode::Private, vec![true; NUM_BITS as usize - NUM_LENGTH_BITS + 1]);
+
+        let result = std::panic::catch_unwind(AssertUnwindSafe(|| circuit.hash_length_bound(&input)));
+        assert!(result.is_err());
+        Circuit::reset();
+    }
+}
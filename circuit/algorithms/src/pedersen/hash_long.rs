@@ -0,0 +1,216 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, const NUM_BITS: u8> HashLong for Pedersen<E, NUM_BITS> {
+    type Input = Boolean<E>;
+    type Output = Field<E>;
+
+    /// Returns the Pedersen hash of an input of arbitrary length as a field element.
+    ///
+    /// The input is split into chunks of `NUM_BITS` bits, where the last chunk is implicitly zero-extended.
+    /// These are followed by the chunks of the 64-bit little-endian length of the input, so that inputs
+    /// which differ only in trailing zeros have distinct digests. The digests are chained as follows:
+    ///   - `digest_0 = hash_uncompressed(chunk_0)`
+    ///   - `digest_i = hash_uncompressed(chunk_i) + h^{r_i}`, where `r_i` is the lower
+    ///     `Scalar::size_in_bits()` bits of the x-coordinate of `digest_{i-1}`
+    ///
+    /// Note: The chaining value `r_i` truncates the x-coordinate of `digest_{i-1}` to its lower
+    /// `Scalar::size_in_bits()` bits, discarding its upper bits.
+    ///
+    /// The output is the x-coordinate of the final digest.
+    fn hash_long(&self, input: &[Self::Input]) -> Self::Output {
+        // Encode the length of the input.
+        let length = Vec::<Boolean<E>>::constant((input.len() as u64).to_bits_le());
+
+        // Split the input and its length into chunks, which are never empty.
+        let mut chunks = input.chunks(NUM_BITS as usize).chain(length.chunks(NUM_BITS as usize));
+        let first = chunks.next().unwrap_or(&[]);
+
+        // Hash the first chunk, and fold each subsequent chunk into the running digest.
+        chunks
+            .fold(self.hash_uncompressed(first), |digest, chunk| {
+                // Compute h^r, where r is the bits of the previous digest.
+                digest
+                    .to_x_coordinate()
+                    .to_bits_le()
+                    .iter()
+                    .zip(&self.random_base)
+                    .map(|(bit, power)| Group::ternary(bit, power, &Group::zero()))
+                    .fold(self.hash_uncompressed(chunk), |acc, x| acc + x)
+            })
+            .to_x_coordinate()
+    }
+}
+
+impl<E: Environment, const NUM_BITS: u8> Metrics<dyn HashLong<Input = Boolean<E>, Output = Field<E>>>
+    for Pedersen<E, NUM_BITS>
+{
+    type Case = Vec<Mode>;
+
+    fn count(case: &Self::Case) -> Count {
+        // Split the input modes and the modes of the constant length into chunks.
+        let length = [Mode::Constant; 64];
+        let mut chunks = case.chunks(NUM_BITS as usize).chain(length.chunks(NUM_BITS as usize));
+        let first = chunks.next().unwrap_or(&[]).to_vec();
+
+        // Compute the cost of hashing the first chunk.
+        let first_count =
+            count!(Pedersen<E, NUM_BITS>, HashUncompressed<Input = Boolean<E>, Output = Group<E>>, &first);
+        let first_mode =
+            output_mode!(Pedersen<E, NUM_BITS>, HashUncompressed<Input = Boolean<E>, Output = Group<E>>, &first);
+
+        // Compute the cost of folding each subsequent chunk into the running digest.
+        let (_, chain_count) = chunks.fold((first_mode, first_count), |(digest_mode, cumulative), chunk| {
+            let chunk = chunk.to_vec();
+            let chunk_count =
+                count!(Pedersen<E, NUM_BITS>, HashUncompressed<Input = Boolean<E>, Output = Group<E>>, &chunk);
+            let chunk_mode =
+                output_mode!(Pedersen<E, NUM_BITS>, HashUncompressed<Input = Boolean<E>, Output = Group<E>>, &chunk);
+
+            // Compute the cost of converting the previous digest into bits.
+            let digest_to_bits_count = count!(Field<E>, ToBits<Boolean = Boolean<E>>, &digest_mode);
+            let digest_bits_mode = output_mode!(Field<E>, ToBits<Boolean = Boolean<E>>, &digest_mode);
+
+            // Compute the cost of constructing and summing the group elements.
+            let (mode, sum_count) =
+                (0..E::ScalarField::size_in_bits()).fold((chunk_mode, Count::zero()), |(prev_mode, cumulative), _| {
+                    let ternary_count = count!(
+                        Group<E>,
+                        Ternary<Boolean = Boolean<E>, Output = Group<E>>,
+                        &(digest_bits_mode, Mode::Constant, Mode::Constant)
+                    );
+                    // The `first` and `second` inputs to `Group::ternary` are always constant so we can directly determine the mode.
+                    let curr_mode = match digest_bits_mode.is_constant() {
+                        true => Mode::Constant,
+                        false => Mode::Private,
+                    };
+                    let mode = output_mode!(Group<E>, Add<Group<E>, Output = Group<E>>, &(prev_mode, curr_mode));
+                    let add_count = count!(Group<E>, Add<Group<E>, Output = Group<E>>, &(prev_mode, curr_mode));
+                    (mode, cumulative + ternary_count + add_count)
+                });
+
+            (mode, cumulative + chunk_count + digest_to_bits_count + sum_count)
+        });
+
+        chain_count
+    }
+}
+
+impl<E: Environment, const NUM_BITS: u8> OutputMode<dyn HashLong<Input = Boolean<E>, Output = Field<E>>>
+    for Pedersen<E, NUM_BITS>
+{
+    type Case = Vec<Mode>;
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        match case.iter().all(|mode| mode.is_constant()) {
+            true => Mode::Constant,
+            false => Mode::Private,
+        }
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    const ITERATIONS: u64 = 10;
+    const MESSAGE: &str = "PedersenCircuit0";
+    const NUM_BITS: u8 = 64;
+
+    fn check_hash_long(mode: Mode, num_chunks: usize, rng: &mut TestRng) {
+        // Initialize the Pedersen hash.
+        let native = console::Pedersen::<<Circuit as Environment>::Network, NUM_BITS>::setup(MESSAGE);
+        let circuit = Pedersen::<Circuit, NUM_BITS>::constant(native.clone());
+
+        for i in 0..ITERATIONS {
+            // Sample a random input.
+            let input = (0..num_chunks * NUM_BITS as usize).map(|_| bool::rand(rng)).collect::<Vec<bool>>();
+            // Compute the expected hash.
+            let expected = native.hash_long(&input).expect("Failed to hash native input");
+            // Prepare the circuit input.
+            let circuit_input: Vec<Boolean<_>> = Inject::new(mode, input);
+
+            Circuit::scope(format!("Pedersen {mode} {num_chunks} {i}"), || {
+                // Perform the hash operation.
+                let candidate = circuit.hash_long(&circuit_input);
+                assert_eq!(expected, candidate.eject_value());
+
+                // Check constraint counts and output mode.
+                let modes = circuit_input.iter().map(|b| b.eject_mode()).collect::<Vec<_>>();
+                assert_count!(Pedersen<Circuit, NUM_BITS>, HashLong<Input = Boolean<Circuit>, Output = Field<Circuit>>, &modes);
+                assert_output_mode!(
+                    Pedersen<Circuit, NUM_BITS>,
+                    HashLong<Input = Boolean<Circuit>, Output = Field<Circuit>>,
+                    &modes,
+                    candidate
+                );
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_hash_long_constant() {
+        let mut rng = TestRng::default();
+        check_hash_long(Mode::Constant, 1, &mut rng);
+        check_hash_long(Mode::Constant, 2, &mut rng);
+        check_hash_long(Mode::Constant, 5, &mut rng);
+    }
+
+    #[test]
+    fn test_hash_long_public() {
+        let mut rng = TestRng::default();
+        check_hash_long(Mode::Public, 1, &mut rng);
+        check_hash_long(Mode::Public, 2, &mut rng);
+        check_hash_long(Mode::Public, 5, &mut rng);
+    }
+
+    #[test]
+    fn test_hash_long_private() {
+        let mut rng = TestRng::default();
+        check_hash_long(Mode::Private, 1, &mut rng);
+        check_hash_long(Mode::Private, 2, &mut rng);
+        check_hash_long(Mode::Private, 5, &mut rng);
+    }
+
+    #[test]
+    fn test_hash_long_trailing_zeros() {
+        let mut rng = TestRng::default();
+
+        // Initialize the Pedersen hash.
+        let circuit = Pedersen::<Circuit, NUM_BITS>::constant(console::Pedersen::setup(MESSAGE));
+
+        // Ensure appending zero bits changes the digest, both within and across chunks.
+        for num_bits in [0, 1, 10, NUM_BITS as usize - 1, NUM_BITS as usize, 2 * NUM_BITS as usize + 3] {
+            let input = (0..num_bits).map(|_| bool::rand(&mut rng)).collect::<Vec<bool>>();
+            let expected = circuit.hash_long(&<Vec<Boolean<_>> as Inject>::new(Mode::Private, input.clone()));
+            for num_zeros in [1, 2, NUM_BITS as usize] {
+                let extended = [input.clone(), vec![false; num_zeros]].concat();
+                let candidate = circuit.hash_long(&<Vec<Boolean<_>> as Inject>::new(Mode::Private, extended));
+                assert_ne!(
+                    expected.eject_value(),
+                    candidate.eject_value(),
+                    "Collision for {num_bits} bits with {num_zeros} trailing zeros"
+                );
+            }
+        }
+        Circuit::reset();
+    }
+}
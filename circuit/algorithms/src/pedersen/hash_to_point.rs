@@ -0,0 +1,150 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, const NUM_BITS: u8> HashToPoint for Pedersen<E, NUM_BITS> {
+    type Input = Boolean<E>;
+    type Output = Group<E>;
+
+    /// Returns the Pedersen hash of the given input as an affine group element, bound to the full point.
+    ///
+    /// Unlike `hash`, which only returns the x-coordinate, the output distinguishes the points `(x, y)` and `(x, -y)`.
+    /// The hash point is offset by a fixed domain point, so the output is separated from `hash_uncompressed`.
+    /// Use `hash_uncompressed` for the un-offset point, whose x-coordinate is the output of `hash`.
+    fn hash_to_point(&self, input: &[Self::Input]) -> Self::Output {
+        // Offset the hash point by the domain point.
+        self.hash_uncompressed(input) + &self.hash_to_point_domain
+    }
+}
+
+impl<E: Environment, const NUM_BITS: u8> Metrics<dyn HashToPoint<Input = Boolean<E>, Output = Group<E>>>
+    for Pedersen<E, NUM_BITS>
+{
+    type Case = Vec<Mode>;
+
+    fn count(case: &Self::Case) -> Count {
+        // Compute the cost of the hash point.
+        let hash_count = count!(Pedersen<E, NUM_BITS>, HashUncompressed<Input = Boolean<E>, Output = Group<E>>, case);
+        let hash_mode =
+            output_mode!(Pedersen<E, NUM_BITS>, HashUncompressed<Input = Boolean<E>, Output = Group<E>>, case);
+
+        // Compute the cost of adding the constant domain point to the hash point.
+        let add_count = count!(Group<E>, Add<Group<E>, Output = Group<E>>, &(hash_mode, Mode::Constant));
+
+        hash_count + add_count
+    }
+}
+
+impl<E: Environment, const NUM_BITS: u8> OutputMode<dyn HashToPoint<Input = Boolean<E>, Output = Group<E>>>
+    for Pedersen<E, NUM_BITS>
+{
+    type Case = Vec<Mode>;
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        let hash_mode =
+            output_mode!(Pedersen<E, NUM_BITS>, HashUncompressed<Input = Boolean<E>, Output = Group<E>>, case);
+        output_mode!(Group<E>, Add<Group<E>, Output = Group<E>>, &(hash_mode, Mode::Constant))
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    const ITERATIONS: u64 = 10;
+    const MESSAGE: &str = "PedersenCircuit0";
+    const NUM_BITS: u8 = 64;
+
+    fn check_hash_to_point(mode: Mode, rng: &mut TestRng) {
+        // Initialize the Pedersen hash.
+        let native = console::Pedersen::<<Circuit as Environment>::Network, NUM_BITS>::setup(MESSAGE);
+        let circuit = Pedersen::<Circuit, NUM_BITS>::constant(native.clone());
+
+        for i in 0..ITERATIONS {
+            // Sample a random input.
+            let input = (0..NUM_BITS).map(|_| bool::rand(rng)).collect::<Vec<bool>>();
+            // Compute the expected hash.
+            let expected = native.hash_to_point(&input).expect("Failed to hash native input");
+            // Prepare the circuit input.
+            let circuit_input: Vec<Boolean<_>> = Inject::new(mode, input);
+
+            Circuit::scope(format!("Pedersen {mode} {i}"), || {
+                // Perform the hash operation.
+                let candidate = circuit.hash_to_point(&circuit_input);
+                assert_eq!(expected, candidate.eject_value());
+
+                // Check constraint counts and output mode.
+                let modes = circuit_input.iter().map(|b| b.eject_mode()).collect::<Vec<_>>();
+                assert_count!(
+                    Pedersen<Circuit, NUM_BITS>,
+                    HashToPoint<Input = Boolean<Circuit>, Output = Group<Circuit>>,
+                    &modes
+                );
+                assert_output_mode!(
+                    Pedersen<Circuit, NUM_BITS>,
+                    HashToPoint<Input = Boolean<Circuit>, Output = Group<Circuit>>,
+                    &modes,
+                    candidate
+                );
+            });
+            Circuit::reset();
+
+            // Ensure the output is separated from the uncompressed hash.
+            let point = circuit.hash_to_point(&circuit_input).eject_value();
+            let uncompressed = circuit.hash_uncompressed(&circuit_input).eject_value();
+            assert_ne!(uncompressed, point);
+            assert_ne!(circuit.hash(&circuit_input).eject_value(), point.to_x_coordinate());
+            Circuit::reset();
+
+            Circuit::scope(format!("Pedersen {mode} {i} mirror"), || {
+                // Mirror the uncompressed hash `P = (x, y)` to `(x, -y)`, which shares its x-coordinate.
+                // Note: `(x, -y)` is on the curve, but outside the prime-order subgroup.
+                let uncompressed = circuit.hash_uncompressed(&circuit_input);
+                let x = uncompressed.to_x_coordinate();
+                let mirrored = Group::from_xy_coordinates_unchecked(x.clone(), -uncompressed.to_y_coordinate());
+                assert_eq!(x.eject_value(), mirrored.to_x_coordinate().eject_value());
+                assert_ne!(uncompressed.eject_value(), mirrored.eject_value());
+
+                // Ensure the point-valued output distinguishes `P` from `(x, -y)`, with and without the domain offset.
+                let candidate = circuit.hash_to_point(&circuit_input);
+                assert_ne!(mirrored.eject_value(), candidate.eject_value());
+                assert_ne!((mirrored + &circuit.hash_to_point_domain).eject_value(), candidate.eject_value());
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_hash_to_point_constant() {
+        let mut rng = TestRng::default();
+        check_hash_to_point(Mode::Constant, &mut rng);
+    }
+
+    #[test]
+    fn test_hash_to_point_public() {
+        let mut rng = TestRng::default();
+        check_hash_to_point(Mode::Public, &mut rng);
+    }
+
+    #[test]
+    fn test_hash_to_point_private() {
+        let mut rng = TestRng::default();
+        check_hash_to_point(Mode::Private, &mut rng);
+    }
+}
@@ -0,0 +1,154 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, const NUM_BITS: u8> HashTwo for Pedersen<E, NUM_BITS> {
+    type Input = Field<E>;
+    type Output = Field<E>;
+
+    /// Returns the parent digest of the given left and right children as a field element.
+    ///
+    /// The children are decomposed into little-endian bits, concatenated as `left || right`,
+    /// and hashed with `hash_long`, as the preimage exceeds the capacity of a single window.
+    fn hash_two(&self, left: &Self::Input, right: &Self::Input) -> Self::Output {
+        let preimage = left.to_bits_le().into_iter().chain(right.to_bits_le()).collect::<Vec<_>>();
+        self.hash_long(&preimage)
+    }
+}
+
+impl<E: Environment, const NUM_BITS: u8> Metrics<dyn HashTwo<Input = Field<E>, Output = Field<E>>>
+    for Pedersen<E, NUM_BITS>
+{
+    type Case = (Mode, Mode);
+
+    fn count(case: &Self::Case) -> Count {
+        let (left_mode, right_mode) = case;
+
+        // Compute the cost of converting both children into bits.
+        let to_bits_count = count!(Field<E>, ToBits<Boolean = Boolean<E>>, left_mode)
+            + count!(Field<E>, ToBits<Boolean = Boolean<E>>, right_mode);
+        let left_bits_mode = output_mode!(Field<E>, ToBits<Boolean = Boolean<E>>, left_mode);
+        let right_bits_mode = output_mode!(Field<E>, ToBits<Boolean = Boolean<E>>, right_mode);
+
+        // Compute the cost of hashing the preimage.
+        let preimage = core::iter::repeat_n(left_bits_mode, E::BaseField::size_in_bits())
+            .chain(core::iter::repeat_n(right_bits_mode, E::BaseField::size_in_bits()))
+            .collect::<Vec<_>>();
+        let hash_count = count!(Pedersen<E, NUM_BITS>, HashLong<Input = Boolean<E>, Output = Field<E>>, &preimage);
+
+        to_bits_count + hash_count
+    }
+}
+
+impl<E: Environment, const NUM_BITS: u8> OutputMode<dyn HashTwo<Input = Field<E>, Output = Field<E>>>
+    for Pedersen<E, NUM_BITS>
+{
+    type Case = (Mode, Mode);
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        match case.0.is_constant() && case.1.is_constant() {
+            true => Mode::Constant,
+            false => Mode::Private,
+        }
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    const ITERATIONS: u64 = 10;
+    const MESSAGE: &str = "PedersenCircuit0";
+    const NUM_BITS: u8 = 64;
+
+    fn check_hash_two(mode_a: Mode, mode_b: Mode, rng: &mut TestRng) {
+        use console::ToBits as T;
+
+        // Initialize the Pedersen hash.
+        let native = console::Pedersen::<<Circuit as Environment>::Network, NUM_BITS>::setup(MESSAGE);
+        let circuit = Pedersen::<Circuit, NUM_BITS>::constant(native.clone());
+
+        for i in 0..ITERATIONS {
+            // Sample a random pair of children.
+            let left = console::Field::<<Circuit as Environment>::Network>::rand(rng);
+            let right = console::Field::<<Circuit as Environment>::Network>::rand(rng);
+            // Compute the expected parent digest.
+            let preimage = left.to_bits_le().into_iter().chain(right.to_bits_le()).collect::<Vec<bool>>();
+            let expected = native.hash_long(&preimage).expect("Failed to hash native input");
+            // Prepare the circuit children.
+            let circuit_left = Field::<Circuit>::new(mode_a, left);
+            let circuit_right = Field::<Circuit>::new(mode_b, right);
+
+            Circuit::scope(format!("Pedersen {mode_a} {mode_b} {i}"), || {
+                // Perform the hash operation.
+                let candidate = circuit.hash_two(&circuit_left, &circuit_right);
+                assert_eq!(expected, candidate.eject_value());
+
+                // Check constraint counts and output mode.
+                assert_count!(
+                    Pedersen<Circuit, NUM_BITS>,
+                    HashTwo<Input = Field<Circuit>, Output = Field<Circuit>>,
+                    &(mode_a, mode_b)
+                );
+                assert_output_mode!(
+                    Pedersen<Circuit, NUM_BITS>,
+                    HashTwo<Input = Field<Circuit>, Output = Field<Circuit>>,
+                    &(mode_a, mode_b),
+                    candidate
+                );
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_hash_two_constant() {
+        let mut rng = TestRng::default();
+        check_hash_two(Mode::Constant, Mode::Constant, &mut rng);
+    }
+
+    #[test]
+    fn test_hash_two_public() {
+        let mut rng = TestRng::default();
+        check_hash_two(Mode::Public, Mode::Public, &mut rng);
+        check_hash_two(Mode::Constant, Mode::Public, &mut rng);
+        check_hash_two(Mode::Public, Mode::Constant, &mut rng);
+    }
+
+    #[test]
+    fn test_hash_two_private() {
+        let mut rng = TestRng::default();
+        check_hash_two(Mode::Private, Mode::Private, &mut rng);
+        check_hash_two(Mode::Constant, Mode::Private, &mut rng);
+        check_hash_two(Mode::Private, Mode::Public, &mut rng);
+    }
+
+    #[test]
+    fn test_hash_two_is_ordered() {
+        let mut rng = TestRng::default();
+
+        // Initialize the Pedersen hash.
+        let circuit = Pedersen::<Circuit, NUM_BITS>::constant(console::Pedersen::setup(MESSAGE));
+
+        let left = Field::<Circuit>::new(Mode::Private, Uniform::rand(&mut rng));
+        let right = Field::<Circuit>::new(Mode::Private, Uniform::rand(&mut rng));
+        assert_ne!(circuit.hash_two(&left, &right).eject_value(), circuit.hash_two(&right, &left).eject_value());
+        Circuit::reset();
+    }
+}
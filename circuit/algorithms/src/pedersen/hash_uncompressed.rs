@@ -23,6 +23,10 @@ impl<E: Environment, const NUM_BITS: u8> HashUncompressed for Pedersen<E, NUM_BI
     type Output = Group<E>;
 
     /// Returns the Pedersen hash of the given input as an affine group element.
+    ///
+    /// The x-coordinate of the output is the output of `hash`. This is the public point-valued form of `hash`,
+    /// for callers that feed the digest into further group operations, and its metrics are those of `HashUncompressed`.
+    /// Note: `hash_to_point` is offset by a domain point, so its x-coordinate is *not* the output of `hash`.
     fn hash_uncompressed(&self, input: &[Self::Input]) -> Self::Output {
         // Ensure the input is within the size bounds.
         let mut input = Cow::Borrowed(input);
@@ -147,6 +151,12 @@ mod tests {
                     candidate
                 );
             });
+            Circuit::reset();
+
+            // Ensure the x-coordinate of the hash point is the hash.
+            let point = circuit.hash_uncompressed(&circuit_input);
+            assert_eq!(circuit.hash(&circuit_input).eject_value(), point.to_x_coordinate().eject_value());
+            Circuit::reset();
         }
     }
 
@@ -0,0 +1,149 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// An incremental Pedersen hasher, which buffers its input across calls to `update`.
+pub struct PedersenHasher<'a, E: Environment, const NUM_BITS: u8> {
+    /// The Pedersen hash function.
+    pedersen: &'a Pedersen<E, NUM_BITS>,
+    /// The input bits buffered so far.
+    buffer: Vec<Boolean<E>>,
+}
+
+impl<'a, E: Environment, const NUM_BITS: u8> PedersenHasher<'a, E, NUM_BITS> {
+    /// Initializes a new hasher with an empty buffer.
+    pub fn new(pedersen: &'a Pedersen<E, NUM_BITS>) -> Self {
+        Self { pedersen, buffer: Vec::with_capacity(NUM_BITS as usize) }
+    }
+
+    /// Appends the given bits to the buffered input.
+    pub fn update(&mut self, bits: &[Boolean<E>]) {
+        // Ensure the buffered input is within the parameter size.
+        if self.buffer.len() + bits.len() > NUM_BITS as usize {
+            E::halt(format!("The Pedersen hash input cannot exceed {NUM_BITS} bits."))
+        }
+        self.buffer.extend_from_slice(bits);
+    }
+
+    /// Returns the Pedersen hash of the buffered input as a field element.
+    /// Note: The buffered input is padded with zeros up to `NUM_BITS`, as in `hash`.
+    pub fn finalize(self) -> Field<E> {
+        self.pedersen.hash(&self.buffer)
+    }
+}
+
+impl<E: Environment, const NUM_BITS: u8> Pedersen<E, NUM_BITS> {
+    /// Returns a new incremental hasher for this Pedersen hash function.
+    pub fn hasher(&self) -> PedersenHasher<'_, E, NUM_BITS> {
+        PedersenHasher::new(self)
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    use core::panic::AssertUnwindSafe;
+
+    const ITERATIONS: u64 = 10;
+    const MESSAGE: &str = "PedersenCircuit0";
+    const NUM_BITS: u8 = 64;
+
+    fn check_hasher(mode: Mode, rng: &mut TestRng) {
+        use console::Hash as H;
+
+        // Initialize the Pedersen hash.
+        let native = console::Pedersen::<<Circuit as Environment>::Network, NUM_BITS>::setup(MESSAGE);
+        let circuit = Pedersen::<Circuit, NUM_BITS>::constant(native.clone());
+
+        for i in 0..ITERATIONS {
+            // Sample a random input, which may be shorter than `NUM_BITS`.
+            let num_bits = u8::rand(rng) % (NUM_BITS + 1);
+            let input = (0..num_bits).map(|_| bool::rand(rng)).collect::<Vec<bool>>();
+            // Compute the expected hash.
+            let expected = native.hash(&input).expect("Failed to hash native input");
+            // Prepare the circuit input.
+            let circuit_input: Vec<Boolean<_>> = Inject::new(mode, input);
+
+            Circuit::scope(format!("PedersenHasher {mode} {i}"), || {
+                // Split the input at two random points, and update the hasher with each part.
+                let mut first = usize::rand(rng) % (num_bits as usize + 1);
+                let mut second = usize::rand(rng) % (num_bits as usize + 1);
+                if first > second {
+                    core::mem::swap(&mut first, &mut second);
+                }
+                let mut hasher = circuit.hasher();
+                hasher.update(&circuit_input[..first]);
+                hasher.update(&circuit_input[first..second]);
+                hasher.update(&circuit_input[second..]);
+                let candidate = hasher.finalize();
+                assert_eq!(expected, candidate.eject_value());
+
+                // Ensure the digest matches a single call to `hash`.
+                assert_eq!(circuit.hash(&circuit_input).eject_value(), candidate.eject_value());
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_hasher_constant() {
+        let mut rng = TestRng::default();
+        check_hasher(Mode::Constant, &mut rng);
+    }
+
+    #[test]
+    fn test_hasher_public() {
+        let mut rng = TestRng::default();
+        check_hasher(Mode::Public, &mut rng);
+    }
+
+    #[test]
+    fn test_hasher_private() {
+        let mut rng = TestRng::default();
+        check_hasher(Mode::Private, &mut rng);
+    }
+
+    #[test]
+    fn test_hasher_bit_by_bit() {
+        let mut rng = TestRng::default();
+
+        let circuit = Pedersen::<Circuit, NUM_BITS>::constant(console::Pedersen::setup(MESSAGE));
+        let input: Vec<Boolean<_>> =
+            Inject::new(Mode::Private, (0..NUM_BITS).map(|_| bool::rand(&mut rng)).collect::<Vec<bool>>());
+
+        // Update the hasher one bit at a time.
+        let mut hasher = circuit.hasher();
+        input.iter().for_each(|bit| hasher.update(core::slice::from_ref(bit)));
+        assert_eq!(circuit.hash(&input).eject_value(), hasher.finalize().eject_value());
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_hasher_fails_on_oversized_input() {
+        let circuit = Pedersen::<Circuit, NUM_BITS>::constant(console::Pedersen::setup(MESSAGE));
+        let input: Vec<Boolean<_>> = Inject::new(Mode::Private, vec![true; NUM_BITS as usize]);
+
+        let mut hasher = circuit.hasher();
+        hasher.update(&input);
+        let result = std::panic::catch_unwind(AssertUnwindSafe(|| hasher.update(&[Boolean::constant(true)])));
+        assert!(result.is_err());
+        Circuit::reset();
+    }
+}
@@ -14,16 +14,59 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+mod hasher;
+pub use hasher::PedersenHasher;
+
+mod record_hasher;
+pub use record_hasher::RecordHasher;
+
 mod commit;
+mod commit_deterministic;
+mod commit_equality;
+mod commit_from_bits;
+mod commit_refresh;
 mod commit_uncompressed;
+mod commit_with_context;
+mod estimate_counts;
 mod hash;
+mod hash_any;
+mod hash_bytes;
+mod hash_chained;
+mod hash_length_bound;
+mod hash_long;
+mod hash_to_point;
+mod hash_two;
 mod hash_uncompressed;
+mod prf;
+mod prove_opening;
+mod try_commit;
+mod try_hash;
 
 #[cfg(all(test, console))]
-use snarkvm_circuit_types::environment::{assert_count, assert_output_mode, assert_scope};
-
-use crate::{Commit, CommitUncompressed, Hash, HashUncompressed};
-use snarkvm_circuit_types::prelude::*;
+use snarkvm_circuit_types::environment::{
+    assert_count,
+    assert_count_snapshot,
+    assert_output_mode,
+    assert_scope,
+    assert_within_budget,
+};
+
+use crate::{
+    Commit,
+    CommitFromBits,
+    CommitRefresh,
+    CommitUncompressed,
+    CommitWithContext,
+    Hash,
+    HashBytes,
+    HashChained,
+    HashLong,
+    HashToPoint,
+    HashTwo,
+    HashUncompressed,
+    PRF,
+};
+use snarkvm_circuit_types::{integers::Integer, prelude::*};
 
 /// Pedersen64 is an *additively-homomorphic* collision-resistant hash function that takes up to a 64-bit input.
 pub type Pedersen64<E> = Pedersen<E, 64>;
@@ -37,6 +80,8 @@ pub struct Pedersen<E: Environment, const NUM_BITS: u8> {
     base_window: Vec<Group<E>>,
     /// The random base window for the Pedersen commitment.
     random_base: Vec<Group<E>>,
+    /// The domain point offsetting the hash point in `hash_to_point`.
+    hash_to_point_domain: Group<E>,
 }
 
 #[cfg(console)]
@@ -53,7 +98,27 @@ impl<E: Environment, const NUM_BITS: u8> Inject for Pedersen<E, NUM_BITS> {
         let random_base = Vec::constant(pedersen.random_base_window().iter().copied().collect());
         assert_eq!(random_base.len(), E::ScalarField::size_in_bits());
 
-        Self { base_window, random_base }
+        // Initialize the domain point for `hash_to_point`.
+        let hash_to_point_domain = Group::constant(console::Pedersen::<E::Network, NUM_BITS>::hash_to_point_domain());
+
+        Self { base_window, random_base, hash_to_point_domain }
+    }
+}
+
+impl<E: Environment, const NUM_BITS: u8> Pedersen<E, NUM_BITS> {
+    /// Returns the number of windows, which is always one, as every input bit has its own base in a single window.
+    pub const fn num_windows() -> usize {
+        1
+    }
+
+    /// Returns the number of bases in each window.
+    pub const fn window_size() -> usize {
+        NUM_BITS as usize
+    }
+
+    /// Returns the maximum number of input bits.
+    pub const fn capacity_in_bits() -> usize {
+        Self::num_windows() * Self::window_size()
     }
 }
 
@@ -87,6 +152,12 @@ mod tests {
                         assert_eq!(*expected, candidate.eject_value());
                     },
                 );
+
+                // Check for equality of the domain point.
+                assert_eq!(
+                    console::Pedersen::<<Circuit as Environment>::Network, NUM_BITS>::hash_to_point_domain(),
+                    circuit.hash_to_point_domain.eject_value()
+                );
             });
         }
     }
@@ -94,10 +165,26 @@ mod tests {
     #[test]
     fn test_setup_constant() {
         // Set the number of windows, and modulate the window size.
-        check_setup::<NUM_BITS_MULTIPLIER>(2590, 0, 0, 0);
-        check_setup::<{ 2 * NUM_BITS_MULTIPLIER }>(2670, 0, 0, 0);
-        check_setup::<{ 3 * NUM_BITS_MULTIPLIER }>(2750, 0, 0, 0);
-        check_setup::<{ 4 * NUM_BITS_MULTIPLIER }>(2830, 0, 0, 0);
-        check_setup::<{ 5 * NUM_BITS_MULTIPLIER }>(2910, 0, 0, 0);
+        check_setup::<NUM_BITS_MULTIPLIER>(2600, 0, 0, 0);
+        check_setup::<{ 2 * NUM_BITS_MULTIPLIER }>(2680, 0, 0, 0);
+        check_setup::<{ 3 * NUM_BITS_MULTIPLIER }>(2760, 0, 0, 0);
+        check_setup::<{ 4 * NUM_BITS_MULTIPLIER }>(2840, 0, 0, 0);
+        check_setup::<{ 5 * NUM_BITS_MULTIPLIER }>(2920, 0, 0, 0);
+    }
+
+    #[test]
+    fn test_dimensions() {
+        assert_eq!(1, Pedersen64::<Circuit>::num_windows());
+        assert_eq!(64, Pedersen64::<Circuit>::window_size());
+        assert_eq!(64, Pedersen64::<Circuit>::capacity_in_bits());
+
+        assert_eq!(1, Pedersen128::<Circuit>::num_windows());
+        assert_eq!(128, Pedersen128::<Circuit>::window_size());
+        assert_eq!(128, Pedersen128::<Circuit>::capacity_in_bits());
+
+        // Ensure the reported dimensions match the base window.
+        let native = console::Pedersen64::<<Circuit as Environment>::Network>::setup(MESSAGE);
+        let circuit = Pedersen64::<Circuit>::constant(native);
+        assert_eq!(Pedersen64::<Circuit>::capacity_in_bits(), circuit.base_window.len());
     }
 }
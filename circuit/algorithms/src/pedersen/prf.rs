@@ -0,0 +1,170 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, const NUM_BITS: u8> PRF for Pedersen<E, NUM_BITS> {
+    type Input = Boolean<E>;
+    type Output = Field<E>;
+    type Seed = Field<E>;
+
+    /// Returns the Pedersen PRF output for the given seed and input, as a field element.
+    /// The preimage `seed || input` is hashed with `hash_long`, so it may exceed `NUM_BITS`.
+    #[inline]
+    fn prf(&self, seed: &Self::Seed, input: &[Self::Input]) -> Self::Output {
+        // Construct the preimage: seed || input.
+        let mut preimage = seed.to_bits_le();
+        preimage.extend_from_slice(input);
+
+        // Hash the preimage to derive the PRF output.
+        self.hash_long(&preimage)
+    }
+}
+
+impl<E: Environment, const NUM_BITS: u8> Metrics<dyn PRF<Seed = Field<E>, Input = Boolean<E>, Output = Field<E>>>
+    for Pedersen<E, NUM_BITS>
+{
+    type Case = (Mode, Vec<Mode>);
+
+    fn count(case: &Self::Case) -> Count {
+        let (seed_mode, input_modes) = case;
+
+        // Compute the cost of converting the seed into bits.
+        let seed_to_bits_count = count!(Field<E>, ToBits<Boolean = Boolean<E>>, seed_mode);
+        let seed_bits_mode = output_mode!(Field<E>, ToBits<Boolean = Boolean<E>>, seed_mode);
+
+        // Determine the modes of the preimage.
+        let preimage_modes = core::iter::repeat_n(seed_bits_mode, E::BaseField::size_in_bits())
+            .chain(input_modes.iter().copied())
+            .collect::<Vec<_>>();
+
+        // Compute the cost of hashing the preimage.
+        seed_to_bits_count
+            + count!(Pedersen<E, NUM_BITS>, HashLong<Input = Boolean<E>, Output = Field<E>>, &preimage_modes)
+    }
+}
+
+impl<E: Environment, const NUM_BITS: u8> OutputMode<dyn PRF<Seed = Field<E>, Input = Boolean<E>, Output = Field<E>>>
+    for Pedersen<E, NUM_BITS>
+{
+    type Case = (Mode, Vec<Mode>);
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        let (seed_mode, input_modes) = case;
+        match seed_mode.is_constant() && input_modes.iter().all(|mode| mode.is_constant()) {
+            true => Mode::Constant,
+            false => Mode::Private,
+        }
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    const ITERATIONS: u64 = 10;
+    const MESSAGE: &str = "PedersenCircuit0";
+    const NUM_BITS: u8 = 64;
+    const NUM_INPUT_BITS: usize = 100;
+
+    fn check_prf(mode: Mode, rng: &mut TestRng) {
+        use console::PRF as P;
+
+        // Initialize the Pedersen PRF.
+        let native = console::Pedersen::<<Circuit as Environment>::Network, NUM_BITS>::setup(MESSAGE);
+        let circuit = Pedersen::<Circuit, NUM_BITS>::constant(native.clone());
+
+        for i in 0..ITERATIONS {
+            // Sample a random seed.
+            let seed = Uniform::rand(rng);
+            // Sample a random input.
+            let input = (0..NUM_INPUT_BITS).map(|_| bool::rand(rng)).collect::<Vec<bool>>();
+            // Compute the expected PRF output.
+            let expected = native.prf(&seed, &input).expect("Failed to PRF native input");
+            // Prepare the circuit seed and input.
+            let circuit_seed = Field::<Circuit>::new(mode, seed);
+            let circuit_input: Vec<Boolean<_>> = Inject::new(mode, input);
+
+            Circuit::scope(format!("Pedersen PRF {mode} {i}"), || {
+                // Perform the PRF operation.
+                let candidate = circuit.prf(&circuit_seed, &circuit_input);
+                assert_eq!(expected, candidate.eject_value());
+
+                // Check constraint counts and output mode.
+                let input_modes = circuit_input.iter().map(|b| b.eject_mode()).collect::<Vec<_>>();
+                assert_count!(
+                    Pedersen<Circuit, NUM_BITS>,
+                    PRF<Seed = Field<Circuit>, Input = Boolean<Circuit>, Output = Field<Circuit>>,
+                    &(mode, input_modes.clone())
+                );
+                assert_output_mode!(
+                    Pedersen<Circuit, NUM_BITS>,
+                    PRF<Seed = Field<Circuit>, Input = Boolean<Circuit>, Output = Field<Circuit>>,
+                    &(mode, input_modes),
+                    candidate
+                );
+            });
+            Circuit::reset();
+        }
+    }
+
+    fn check_prf_is_keyed(mode: Mode, rng: &mut TestRng) {
+        // Initialize the Pedersen PRF.
+        let circuit = Pedersen::<Circuit, NUM_BITS>::constant(console::Pedersen::setup(MESSAGE));
+
+        for _ in 0..ITERATIONS {
+            // Sample two distinct seeds and a shared input.
+            let first_seed = Field::<Circuit>::new(mode, Uniform::rand(rng));
+            let second_seed = Field::<Circuit>::new(mode, Uniform::rand(rng));
+            let input: Vec<Boolean<_>> =
+                Inject::new(mode, (0..NUM_INPUT_BITS).map(|_| bool::rand(rng)).collect::<Vec<bool>>());
+
+            // Ensure the same seed and input produce the same output.
+            let a = circuit.prf(&first_seed, &input);
+            let b = circuit.prf(&first_seed, &input);
+            assert_eq!(a.eject_value(), b.eject_value());
+
+            // Ensure different seeds produce different outputs.
+            let c = circuit.prf(&second_seed, &input);
+            assert_ne!(a.eject_value(), c.eject_value());
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_prf_constant() {
+        let mut rng = TestRng::default();
+        check_prf(Mode::Constant, &mut rng);
+        check_prf_is_keyed(Mode::Constant, &mut rng);
+    }
+
+    #[test]
+    fn test_prf_public() {
+        let mut rng = TestRng::default();
+        check_prf(Mode::Public, &mut rng);
+        check_prf_is_keyed(Mode::Public, &mut rng);
+    }
+
+    #[test]
+    fn test_prf_private() {
+        let mut rng = TestRng::default();
+        check_prf(Mode::Private, &mut rng);
+        check_prf_is_keyed(Mode::Private, &mut rng);
+    }
+}
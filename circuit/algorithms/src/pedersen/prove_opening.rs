@@ -0,0 +1,125 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, const NUM_BITS: u8> Pedersen<E, NUM_BITS> {
+    /// Returns `true` if `(input, randomizer)` is an opening of `commitment`, and `lo <= input <= hi`.
+    ///
+    /// The commitment is computed over the little-endian bits of `input`, so `I::BITS` must not exceed `NUM_BITS`.
+    /// Note: The input is taken as an `Integer` rather than as `input_bits`, so that the range check can reuse
+    /// the integer comparison gadget; callers holding bits can recover the integer with `Integer::from_bits_le`.
+    /// The range check compares `input` against the bounds with the integer comparison gadget.
+    ///
+    /// This method does not enforce the result, so the caller should `E::assert` it. For the proof to hide
+    /// the opening, `input` and `randomizer` must be injected as `Private`, while `lo`, `hi`, and `commitment`
+    /// are typically `Public` (or `Constant`). The modes of the arguments are not changed by this method.
+    pub fn prove_opening_in_range<I: IntegerType>(
+        &self,
+        input: &Integer<E, I>,
+        randomizer: &Scalar<E>,
+        lo: &Integer<E, I>,
+        hi: &Integer<E, I>,
+        commitment: &Field<E>,
+    ) -> Boolean<E> {
+        // Ensure the commitment opens to `(input, randomizer)`.
+        let is_opening = self.commit(&input.to_bits_le(), randomizer).is_equal(commitment);
+        // Ensure `lo <= input <= hi`.
+        let is_in_range = lo.is_less_than_or_equal(input) & input.is_less_than_or_equal(hi);
+        is_opening & is_in_range
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::{environment::Circuit, U32};
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    const ITERATIONS: u64 = 10;
+    const MESSAGE: &str = "PedersenCircuit0";
+    const NUM_BITS: u8 = 64;
+
+    type NativeScalar = console::Scalar<<Circuit as Environment>::Network>;
+
+    /// Returns the result of `prove_opening_in_range` on a private opening of the given native values.
+    fn check_prove_opening_in_range(
+        committed: (u32, NativeScalar),
+        opening: (u32, NativeScalar),
+        lo: u32,
+        hi: u32,
+    ) -> bool {
+        use console::{Commit as C, ToBits as T};
+
+        // Initialize Pedersen.
+        let native = console::Pedersen::<<Circuit as Environment>::Network, NUM_BITS>::setup(MESSAGE);
+        let circuit = Pedersen::<Circuit, NUM_BITS>::constant(native.clone());
+
+        // Compute the native commitment.
+        let (value, randomizer) = committed;
+        let commitment = native
+            .commit(&console::U32::<<Circuit as Environment>::Network>::new(value).to_bits_le(), &randomizer)
+            .expect("Failed to commit");
+
+        // Inject the opening privately, and the bounds and commitment publicly.
+        let (value, randomizer) = opening;
+        let input = U32::<Circuit>::new(Mode::Private, console::U32::new(value));
+        let randomizer = Scalar::<Circuit>::new(Mode::Private, randomizer);
+        let lo = U32::<Circuit>::new(Mode::Public, console::U32::new(lo));
+        let hi = U32::<Circuit>::new(Mode::Public, console::U32::new(hi));
+        let commitment = Field::<Circuit>::new(Mode::Public, commitment);
+
+        let candidate = circuit.prove_opening_in_range(&input, &randomizer, &lo, &hi, &commitment);
+        // Note: The result is not enforced, so the circuit is satisfied in every case.
+        assert!(Circuit::is_satisfied());
+        let result = candidate.eject_value();
+
+        // Ensure the circuit is satisfied when enforcing the result if and only if it is `true`.
+        Circuit::assert(candidate);
+        assert_eq!(result, Circuit::is_satisfied());
+        Circuit::reset();
+        result
+    }
+
+    #[test]
+    fn test_prove_opening_in_range() {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let value = u32::rand(&mut rng) | 1;
+            let randomizer = NativeScalar::rand(&mut rng);
+
+            // Check a valid opening, including at the boundaries of the range.
+            assert!(check_prove_opening_in_range((value, randomizer), (value, randomizer), 0, u32::MAX));
+            assert!(check_prove_opening_in_range((value, randomizer), (value, randomizer), value, value));
+            assert!(check_prove_opening_in_range((value, randomizer), (value, randomizer), value - 1, value));
+
+            // Check a wrong opening, with either the wrong input or the wrong randomizer.
+            let other_randomizer = NativeScalar::rand(&mut rng);
+            assert!(!check_prove_opening_in_range((value, randomizer), (value - 1, randomizer), 0, u32::MAX));
+            assert!(!check_prove_opening_in_range((value, randomizer), (value, other_randomizer), 0, u32::MAX));
+
+            // Check a valid opening, with the input out of range.
+            assert!(!check_prove_opening_in_range((value, randomizer), (value, randomizer), 0, value - 1));
+            assert!(!check_prove_opening_in_range(
+                (value, randomizer),
+                (value, randomizer),
+                value.saturating_add(1),
+                u32::MAX
+            ));
+        }
+    }
+}
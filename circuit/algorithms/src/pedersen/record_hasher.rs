@@ -0,0 +1,191 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// A builder for hashing a record of heterogeneous values with Pedersen.
+///
+/// Each value is encoded as its canonical little-endian bits, in insertion order:
+///   - a field element as `Field::size_in_bits()` bits,
+///   - an integer as `I::BITS` bits, and
+///   - a boolean as a single bit.
+///
+/// The digest is the `hash_long` of the concatenated bits, so records of any length are supported.
+/// As `hash_long` absorbs the number of bits, appending a `false` boolean or a zero integer changes the digest.
+#[derive(Clone)]
+pub struct RecordHasher<E: Environment> {
+    /// The input bits encoded so far.
+    bits: Vec<Boolean<E>>,
+}
+
+impl<E: Environment> RecordHasher<E> {
+    /// Initializes a new record hasher with an empty encoding.
+    pub fn new() -> Self {
+        Self { bits: Vec::new() }
+    }
+
+    /// Appends the little-endian bits of the given field element.
+    pub fn add_field(&mut self, field: &Field<E>) -> &mut Self {
+        self.bits.extend(field.to_bits_le());
+        self
+    }
+
+    /// Appends the little-endian bits of the given integer.
+    pub fn add_integer<I: IntegerType>(&mut self, integer: &Integer<E, I>) -> &mut Self {
+        self.bits.extend(integer.to_bits_le());
+        self
+    }
+
+    /// Appends the given boolean as a single bit.
+    pub fn add_bool(&mut self, boolean: &Boolean<E>) -> &mut Self {
+        self.bits.push(boolean.clone());
+        self
+    }
+
+    /// Returns the encoded bits of the record.
+    pub fn bits(&self) -> &[Boolean<E>] {
+        &self.bits
+    }
+
+    /// Returns the Pedersen hash of the encoded record as a field element.
+    pub fn finalize<const NUM_BITS: u8>(&self, pedersen: &Pedersen<E, NUM_BITS>) -> Field<E> {
+        pedersen.hash_long(&self.bits)
+    }
+}
+
+impl<E: Environment> Default for RecordHasher<E> {
+    /// Initializes a new record hasher with an empty encoding.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::{environment::Circuit, U32, U8};
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    const ITERATIONS: u64 = 10;
+    const MESSAGE: &str = "PedersenCircuit0";
+    const NUM_BITS: u8 = 64;
+
+    fn check_record_hasher(mode: Mode, rng: &mut TestRng) {
+        let circuit = Pedersen::<Circuit, NUM_BITS>::constant(console::Pedersen::setup(MESSAGE));
+
+        for i in 0..ITERATIONS {
+            // Sample the record values.
+            let field = Field::<Circuit>::new(mode, Uniform::rand(rng));
+            let integer = U32::<Circuit>::new(mode, Uniform::rand(rng));
+            let boolean = Boolean::<Circuit>::new(mode, bool::rand(rng));
+
+            Circuit::scope(format!("RecordHasher {mode} {i}"), || {
+                // Hash the record with the builder.
+                let candidate =
+                    RecordHasher::new().add_field(&field).add_integer(&integer).add_bool(&boolean).finalize(&circuit);
+
+                // Assemble the bits manually, and ensure the digests match.
+                let mut bits = field.to_bits_le();
+                bits.extend(integer.to_bits_le());
+                bits.push(boolean.clone());
+                assert_eq!(circuit.hash_long(&bits).eject_value(), candidate.eject_value());
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_record_hasher_constant() {
+        let mut rng = TestRng::default();
+        check_record_hasher(Mode::Constant, &mut rng);
+    }
+
+    #[test]
+    fn test_record_hasher_public() {
+        let mut rng = TestRng::default();
+        check_record_hasher(Mode::Public, &mut rng);
+    }
+
+    #[test]
+    fn test_record_hasher_private() {
+        let mut rng = TestRng::default();
+        check_record_hasher(Mode::Private, &mut rng);
+    }
+
+    #[test]
+    fn test_record_hasher_encoding() {
+        let mut rng = TestRng::default();
+
+        let field = Field::<Circuit>::new(Mode::Private, Uniform::rand(&mut rng));
+        let integer = U8::<Circuit>::new(Mode::Private, Uniform::rand(&mut rng));
+        let boolean = Boolean::<Circuit>::new(Mode::Private, true);
+
+        let mut hasher = RecordHasher::new();
+        hasher.add_field(&field).add_integer(&integer).add_bool(&boolean);
+
+        // Ensure each value is encoded with its canonical bit length.
+        let num_field_bits = <Circuit as Environment>::BaseField::size_in_bits();
+        assert_eq!(num_field_bits + 8 + 1, hasher.bits().len());
+        assert_eq!(field.to_bits_le().eject_value(), hasher.bits()[..num_field_bits].to_vec().eject_value());
+        assert_eq!(
+            integer.to_bits_le().eject_value(),
+            hasher.bits()[num_field_bits..num_field_bits + 8].to_vec().eject_value()
+        );
+        assert!(hasher.bits()[num_field_bits + 8].eject_value());
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_record_hasher_order_matters() {
+        let mut rng = TestRng::default();
+
+        let circuit = Pedersen::<Circuit, NUM_BITS>::constant(console::Pedersen::setup(MESSAGE));
+
+        for _ in 0..ITERATIONS {
+            let field = Field::<Circuit>::new(Mode::Private, Uniform::rand(&mut rng));
+            let integer = U32::<Circuit>::new(Mode::Private, Uniform::rand(&mut rng));
+
+            // Ensure swapping the insertion order changes the digest.
+            let first = RecordHasher::new().add_field(&field).add_integer(&integer).finalize(&circuit);
+            let second = RecordHasher::new().add_integer(&integer).add_field(&field).finalize(&circuit);
+            assert_ne!(first.eject_value(), second.eject_value());
+        }
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_record_hasher_trailing_zeros() {
+        let mut rng = TestRng::default();
+
+        let circuit = Pedersen::<Circuit, NUM_BITS>::constant(console::Pedersen::setup(MESSAGE));
+
+        for _ in 0..ITERATIONS {
+            let field = Field::<Circuit>::new(Mode::Private, Uniform::rand(&mut rng));
+            let mut hasher = RecordHasher::new();
+            hasher.add_field(&field);
+            let expected = hasher.finalize(&circuit);
+
+            // Ensure appending a `false` boolean changes the digest.
+            let candidate = hasher.clone().add_bool(&Boolean::new(Mode::Private, false)).finalize(&circuit);
+            assert_ne!(expected.eject_value(), candidate.eject_value());
+
+            // Ensure appending a zero integer changes the digest.
+            let candidate = hasher.clone().add_integer(&U8::new(Mode::Private, console::U8::new(0))).finalize(&circuit);
+            assert_ne!(expected.eject_value(), candidate.eject_value());
+        }
+        Circuit::reset();
+    }
+}
@@ -0,0 +1,104 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, const NUM_BITS: u8> Pedersen<E, NUM_BITS> {
+    /// Returns the Pedersen commitment of the given input and randomizer as a field element,
+    /// if the input is within the capacity.
+    ///
+    /// Unlike `commit`, which halts on inputs longer than `NUM_BITS`, this method returns an error.
+    /// Shorter inputs are zero-padded, as in `commit`.
+    pub fn try_commit(&self, input: &[Boolean<E>], randomizer: &Scalar<E>) -> Result<Field<E>> {
+        // Ensure the input size is within the capacity.
+        let capacity = Self::capacity_in_bits();
+        ensure!(
+            input.len() <= capacity,
+            "Pedersen commitment input cannot exceed {capacity} bits, found {} bits",
+            input.len()
+        );
+        Ok(self.commit(input, randomizer))
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    const ITERATIONS: u64 = 10;
+    const MESSAGE: &str = "PedersenCircuit0";
+    const NUM_BITS: u8 = 64;
+
+    fn check_try_commit(mode: Mode, rng: &mut TestRng) {
+        use console::Commit as C;
+
+        // Initialize Pedersen.
+        let native = console::Pedersen::<<Circuit as Environment>::Network, NUM_BITS>::setup(MESSAGE);
+        let circuit = Pedersen::<Circuit, NUM_BITS>::constant(native.clone());
+
+        for i in 0..ITERATIONS {
+            // Sample a random input and randomizer.
+            let input = (0..NUM_BITS).map(|_| bool::rand(rng)).collect::<Vec<bool>>();
+            let randomizer = Uniform::rand(rng);
+            let expected = native.commit(&input, &randomizer).expect("Failed to commit native input");
+
+            let circuit_input: Vec<Boolean<_>> = Inject::new(mode, input);
+            let circuit_randomizer = Scalar::<Circuit>::new(mode, randomizer);
+
+            Circuit::scope(format!("Pedersen {mode} {i}"), || {
+                let candidate = circuit.try_commit(&circuit_input, &circuit_randomizer).unwrap();
+                assert_eq!(expected, candidate.eject_value());
+                assert_eq!(candidate.eject_value(), circuit.commit(&circuit_input, &circuit_randomizer).eject_value());
+                assert!(Circuit::is_satisfied_in_scope());
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_try_commit_constant() {
+        let mut rng = TestRng::default();
+        check_try_commit(Mode::Constant, &mut rng);
+    }
+
+    #[test]
+    fn test_try_commit_public() {
+        let mut rng = TestRng::default();
+        check_try_commit(Mode::Public, &mut rng);
+    }
+
+    #[test]
+    fn test_try_commit_private() {
+        let mut rng = TestRng::default();
+        check_try_commit(Mode::Private, &mut rng);
+    }
+
+    #[test]
+    fn test_try_commit_over_length() {
+        let mut rng = TestRng::default();
+
+        let circuit = Pedersen::<Circuit, NUM_BITS>::constant(console::Pedersen::setup(MESSAGE));
+        let input: Vec<Boolean<_>> = Inject::new(Mode::Private, vec![true; NUM_BITS as usize + 1]);
+        let randomizer = Scalar::<Circuit>::new(Mode::Private, Uniform::rand(&mut rng));
+
+        let error = circuit.try_commit(&input, &randomizer).unwrap_err().to_string();
+        assert!(error.contains("cannot exceed 64 bits"));
+        assert!(error.contains("found 65 bits"));
+        Circuit::reset();
+    }
+}
@@ -0,0 +1,111 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, const NUM_BITS: u8> Pedersen<E, NUM_BITS> {
+    /// Returns the Pedersen hash of the given input as a field element, if the input fills the capacity exactly.
+    ///
+    /// Unlike `hash`, which zero-pads shorter inputs and halts on longer inputs, this method returns an error
+    /// if the input is not exactly `NUM_WINDOWS * WINDOW_SIZE` bits.
+    pub fn try_hash(&self, input: &[Boolean<E>]) -> Result<Field<E>> {
+        // Ensure the input size matches the capacity.
+        let capacity = Self::capacity_in_bits();
+        ensure!(
+            input.len() == capacity,
+            "Pedersen expected an input of {capacity} bits ({} windows of {} bits), found {} bits",
+            Self::num_windows(),
+            Self::window_size(),
+            input.len()
+        );
+        Ok(self.hash(input))
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    const ITERATIONS: u64 = 10;
+    const MESSAGE: &str = "PedersenCircuit0";
+    const NUM_BITS: u8 = 64;
+
+    fn check_try_hash(mode: Mode, rng: &mut TestRng) {
+        use console::Hash as H;
+
+        // Initialize Pedersen.
+        let native = console::Pedersen::<<Circuit as Environment>::Network, NUM_BITS>::setup(MESSAGE);
+        let circuit = Pedersen::<Circuit, NUM_BITS>::constant(native.clone());
+
+        for i in 0..ITERATIONS {
+            // Sample a random input that fills the capacity.
+            let input = (0..NUM_BITS).map(|_| bool::rand(rng)).collect::<Vec<bool>>();
+            let expected = native.hash(&input).expect("Failed to hash native input");
+            let circuit_input: Vec<Boolean<_>> = Inject::new(mode, input);
+
+            Circuit::scope(format!("Pedersen {mode} {i}"), || {
+                let candidate = circuit.try_hash(&circuit_input).unwrap();
+                assert_eq!(expected, candidate.eject_value());
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_try_hash_constant() {
+        let mut rng = TestRng::default();
+        check_try_hash(Mode::Constant, &mut rng);
+    }
+
+    #[test]
+    fn test_try_hash_public() {
+        let mut rng = TestRng::default();
+        check_try_hash(Mode::Public, &mut rng);
+    }
+
+    #[test]
+    fn test_try_hash_private() {
+        let mut rng = TestRng::default();
+        check_try_hash(Mode::Private, &mut rng);
+    }
+
+    #[test]
+    fn test_try_hash_under_length() {
+        let circuit = Pedersen::<Circuit, NUM_BITS>::constant(console::Pedersen::setup(MESSAGE));
+
+        for num_bits in [0, 1, NUM_BITS as usize - 1] {
+            let input: Vec<Boolean<_>> = Inject::new(Mode::Private, vec![true; num_bits]);
+
+            let error = circuit.try_hash(&input).unwrap_err().to_string();
+            assert!(error.contains("expected an input of 64 bits"));
+            assert!(error.contains(&format!("found {num_bits} bits")));
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_try_hash_over_length() {
+        let circuit = Pedersen::<Circuit, NUM_BITS>::constant(console::Pedersen::setup(MESSAGE));
+        let input: Vec<Boolean<_>> = Inject::new(Mode::Private, vec![true; NUM_BITS as usize + 1]);
+
+        let error = circuit.try_hash(&input).unwrap_err().to_string();
+        assert!(error.contains("expected an input of 64 bits"));
+        assert!(error.contains("found 65 bits"));
+        Circuit::reset();
+    }
+}
@@ -0,0 +1,220 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// The domain separator for Poseidon commitments, which keeps a commitment to `input`
+/// under `randomizer` distinct from the Poseidon hash of `randomizer || input`.
+const COMMIT_DOMAIN: &str = "PoseidonCommit";
+
+impl<E: Environment, const RATE: usize> Commit for Poseidon<E, RATE> {
+    type Input = Field<E>;
+    type Output = Field<E>;
+    type Randomizer = Field<E>;
+
+    /// Returns the Poseidon commitment of the given input and randomizer as a field element.
+    #[inline]
+    fn commit(&self, input: &[Self::Input], randomizer: &Self::Randomizer) -> Self::Output {
+        // Construct the preimage: COMMIT_DOMAIN || randomizer || input.
+        let mut preimage = Vec::with_capacity(2 + input.len());
+        preimage.push(Field::constant(console::Field::new_domain_separator(COMMIT_DOMAIN)));
+        preimage.push(randomizer.clone());
+        preimage.extend_from_slice(input);
+
+        // Hash the preimage to derive the commitment.
+        self.hash(&preimage)
+    }
+}
+
+impl<E: Environment, const RATE: usize> Metrics<dyn Commit<Input = Field<E>, Output = Field<E>, Randomizer = Field<E>>>
+    for Poseidon<E, RATE>
+{
+    type Case = (Vec<Mode>, Mode);
+
+    fn count(case: &Self::Case) -> Count {
+        let (input_modes, randomizer_mode) = case;
+
+        // Construct the modes of the preimage: COMMIT_DOMAIN || randomizer || input.
+        let mut preimage_modes = Vec::with_capacity(2 + input_modes.len());
+        preimage_modes.push(Mode::Constant);
+        preimage_modes.push(*randomizer_mode);
+        preimage_modes.extend_from_slice(input_modes);
+
+        // The commitment domain is the only constant introduced, besides those of the hash.
+        Count::is(1, 0, 0, 0) + count!(Poseidon<E, RATE>, Hash<Input = Field<E>, Output = Field<E>>, &preimage_modes)
+    }
+}
+
+impl<E: Environment, const RATE: usize>
+    OutputMode<dyn Commit<Input = Field<E>, Output = Field<E>, Randomizer = Field<E>>> for Poseidon<E, RATE>
+{
+    type Case = (Vec<Mode>, Mode);
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        let (input_modes, randomizer_mode) = case;
+        match randomizer_mode.is_constant() && input_modes.iter().all(Mode::is_constant) {
+            true => Mode::Constant,
+            false => Mode::Private,
+        }
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    use anyhow::Result;
+
+    const DOMAIN: &str = "PoseidonCircuit0";
+    const ITERATIONS: usize = 10;
+    const RATE: usize = 4;
+
+    fn check_commit<const RATE: usize>(mode: Mode, num_inputs: usize, rng: &mut TestRng) -> Result<()> {
+        use console::Commit as C;
+
+        let native = console::Poseidon::<<Circuit as Environment>::Network, RATE>::setup(DOMAIN)?;
+        let poseidon = Poseidon::<Circuit, RATE>::constant(native.clone());
+
+        for i in 0..ITERATIONS {
+            // Prepare the randomizer.
+            let native_randomizer = Uniform::rand(rng);
+            let randomizer = Field::new(mode, native_randomizer);
+
+            // Prepare the input.
+            let native_input = (0..num_inputs).map(|_| Uniform::rand(rng)).collect::<Vec<_>>();
+            let input = native_input.iter().map(|v| Field::<Circuit>::new(mode, *v)).collect::<Vec<_>>();
+
+            // Compute the native commitment.
+            let expected = native.commit(&native_input, &native_randomizer).expect("Failed to commit native input");
+
+            // Compute the circuit commitment.
+            Circuit::scope(format!("Poseidon Commit {mode} {i}"), || {
+                let candidate = poseidon.commit(&input, &randomizer);
+                assert_eq!(expected, candidate.eject_value());
+
+                let case = (vec![mode; num_inputs], mode);
+                assert_count!(
+                    Poseidon<Circuit, RATE>,
+                    Commit<Input = Field<Circuit>, Output = Field<Circuit>, Randomizer = Field<Circuit>>,
+                    &case
+                );
+                assert_output_mode!(
+                    Poseidon<Circuit, RATE>,
+                    Commit<Input = Field<Circuit>, Output = Field<Circuit>, Randomizer = Field<Circuit>>,
+                    &case,
+                    candidate
+                );
+            });
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_commit_constant() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for num_inputs in 0..=10 {
+            check_commit::<RATE>(Mode::Constant, num_inputs, &mut rng)?;
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_commit_public() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for num_inputs in 0..=10 {
+            check_commit::<RATE>(Mode::Public, num_inputs, &mut rng)?;
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_commit_private() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for num_inputs in 0..=10 {
+            check_commit::<RATE>(Mode::Private, num_inputs, &mut rng)?;
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_commit_rate_2() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Check the commitment at rate 2.
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            for num_inputs in 0..=10 {
+                check_commit::<2>(mode, num_inputs, &mut rng)?;
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_commit_is_hiding() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        let native = console::Poseidon::<<Circuit as Environment>::Network, RATE>::setup(DOMAIN)?;
+        let poseidon = Poseidon::<Circuit, RATE>::constant(native);
+
+        for i in 0..ITERATIONS {
+            // Commit to the same input under two different randomizers.
+            let input =
+                (0..RATE).map(|_| Field::<Circuit>::new(Mode::Private, Uniform::rand(&mut rng))).collect::<Vec<_>>();
+            let first = Field::<Circuit>::new(Mode::Private, Uniform::rand(&mut rng));
+            let second = Field::<Circuit>::new(Mode::Private, Uniform::rand(&mut rng));
+
+            Circuit::scope(format!("Poseidon Commit Hiding {i}"), || {
+                let a = poseidon.commit(&input, &first);
+                let b = poseidon.commit(&input, &second);
+                assert_ne!(a.eject_value(), b.eject_value());
+                assert!(Circuit::is_satisfied_in_scope());
+            });
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_commit_is_not_hash() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        let native = console::Poseidon::<<Circuit as Environment>::Network, RATE>::setup(DOMAIN)?;
+        let poseidon = Poseidon::<Circuit, RATE>::constant(native);
+
+        for i in 0..ITERATIONS {
+            let input =
+                (0..RATE).map(|_| Field::<Circuit>::new(Mode::Private, Uniform::rand(&mut rng))).collect::<Vec<_>>();
+            let randomizer = Field::<Circuit>::new(Mode::Private, Uniform::rand(&mut rng));
+
+            Circuit::scope(format!("Poseidon Commit Domain {i}"), || {
+                // Ensure the commitment differs from the hash of `randomizer || input`.
+                let mut preimage = vec![randomizer.clone()];
+                preimage.extend_from_slice(&input);
+                let commitment = poseidon.commit(&input, &randomizer);
+                let hash = poseidon.hash(&preimage);
+                assert_ne!(commitment.eject_value(), hash.eject_value());
+            });
+            Circuit::reset();
+        }
+        Ok(())
+    }
+}
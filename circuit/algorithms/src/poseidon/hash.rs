@@ -16,6 +16,8 @@
 
 use super::*;
 
+use snarkvm_fields::PoseidonDefaultParameters;
+
 impl<E: Environment, const RATE: usize> Hash for Poseidon<E, RATE> {
     type Input = Field<E>;
     type Output = Field<E>;
@@ -26,6 +28,60 @@ impl<E: Environment, const RATE: usize> Hash for Poseidon<E, RATE> {
     }
 }
 
+impl<E: Environment, const RATE: usize> Metrics<dyn Hash<Input = Field<E>, Output = Field<E>>> for Poseidon<E, RATE> {
+    type Case = Vec<Mode>;
+
+    fn count(input_modes: &Self::Case) -> Count {
+        // Retrieve the round parameters for this rate.
+        let entry =
+            match <<E::BaseField as PrimeField>::Parameters as PoseidonDefaultParameters>::PARAMS_OPT_FOR_CONSTRAINTS
+                .iter()
+                .find(|entry| entry.rate == RATE)
+            {
+                Some(entry) => entry,
+                None => E::halt(format!("No Poseidon parameters were found for rate {RATE}")),
+            };
+        // Each S-box (x^alpha) costs one constraint per squaring and per multiplication, past the leading bit.
+        let num_bits = (usize::BITS - entry.alpha.leading_zeros()) as u64;
+        let s_box = (num_bits - 1) + (entry.alpha.count_ones() as u64 - 1);
+        let num_s_boxes = (entry.full_rounds * (RATE + CAPACITY) + entry.partial_rounds) as u64;
+
+        // Construct the constant-ness of the preimage: [ DOMAIN || LENGTH(INPUT) || [0; RATE-2] || INPUT ].
+        let mut preimage = vec![true; RATE];
+        preimage.extend(input_modes.iter().map(Mode::is_constant));
+
+        // Absorb the preimage one block at a time, and permute once per block.
+        // A permutation is free when the entire state is constant. Otherwise, the first full round
+        // only applies the S-box to the variable elements, after which the entire state is variable.
+        let mut state = vec![true; RATE + CAPACITY];
+        let mut num_constraints = 0;
+        for block in preimage.chunks(RATE) {
+            block.iter().enumerate().for_each(|(i, is_constant)| state[CAPACITY + i] &= is_constant);
+            let num_constant = state.iter().filter(|is_constant| **is_constant).count() as u64;
+            if num_constant != (RATE + CAPACITY) as u64 {
+                num_constraints += s_box * (num_s_boxes - num_constant);
+                state.iter_mut().for_each(|is_constant| *is_constant = false);
+            }
+        }
+
+        // The length of the input is the only constant introduced.
+        Count::is(1, 0, num_constraints, num_constraints)
+    }
+}
+
+impl<E: Environment, const RATE: usize> OutputMode<dyn Hash<Input = Field<E>, Output = Field<E>>>
+    for Poseidon<E, RATE>
+{
+    type Case = Vec<Mode>;
+
+    fn output_mode(input_modes: &Self::Case) -> Mode {
+        match input_modes.iter().all(Mode::is_constant) {
+            true => Mode::Constant,
+            false => Mode::Private,
+        }
+    }
+}
+
 #[cfg(all(test, console))]
 mod tests {
     use super::*;
@@ -67,6 +123,15 @@ mod tests {
                 assert_eq!(expected, candidate.eject_value());
                 let case = format!("(mode = {mode}, num_inputs = {num_inputs})");
                 assert_scope!(case, num_constants, num_public, num_private, num_constraints);
+
+                let modes = vec![mode; num_inputs];
+                assert_count!(Poseidon<Circuit, RATE>, Hash<Input = Field<Circuit>, Output = Field<Circuit>>, &modes);
+                assert_output_mode!(
+                    Poseidon<Circuit, RATE>,
+                    Hash<Input = Field<Circuit>, Output = Field<Circuit>>,
+                    &modes,
+                    candidate
+                );
             });
             Circuit::reset();
         }
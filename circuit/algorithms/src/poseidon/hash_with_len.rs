@@ -0,0 +1,125 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, const RATE: usize> Poseidon<E, RATE> {
+    /// Returns the Poseidon hash of the given input, with the length of the input absorbed first.
+    ///
+    /// This hash is computed as `hash(LENGTH(INPUT) || INPUT)`, and is in a separate domain from `hash`,
+    /// meaning `hash_with_len(input)` does *not* equal `hash(input)` for the same input.
+    /// Absorbing the length ensures that inputs which only differ by trailing zeros
+    /// (e.g. `[a, b]` and `[a, b, 0]`) are absorbed into distinct sponge states.
+    #[inline]
+    pub fn hash_with_len(&self, input: &[Field<E>]) -> Field<E> {
+        // Construct the preimage: LENGTH(INPUT) || INPUT.
+        let mut preimage = Vec::with_capacity(1 + input.len());
+        preimage.push(Field::constant(console::Field::from_u128(input.len() as u128)));
+        preimage.extend_from_slice(input);
+
+        // Hash the preimage.
+        self.hash(&preimage)
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+
+    use anyhow::Result;
+
+    const DOMAIN: &str = "PoseidonCircuit0";
+    const ITERATIONS: usize = 10;
+    const RATE: usize = 4;
+
+    fn check_hash_with_len(mode: Mode, num_inputs: usize, rng: &mut TestRng) -> Result<()> {
+        use console::Hash as H;
+
+        let native = console::Poseidon::<<Circuit as Environment>::Network, RATE>::setup(DOMAIN)?;
+        let poseidon = Poseidon::<Circuit, RATE>::constant(native.clone());
+
+        for i in 0..ITERATIONS {
+            // Prepare the preimage.
+            let native_input = (0..num_inputs)
+                .map(|_| console::Field::<<Circuit as Environment>::Network>::rand(rng))
+                .collect::<Vec<_>>();
+            let input = native_input.iter().map(|v| Field::<Circuit>::new(mode, *v)).collect::<Vec<_>>();
+
+            // Compute the native hash of the length-prefixed input.
+            let mut native_preimage = vec![console::Field::from_u128(num_inputs as u128)];
+            native_preimage.extend_from_slice(&native_input);
+            let expected = native.hash(&native_preimage).expect("Failed to hash native input");
+
+            // Compute the circuit hash.
+            Circuit::scope(format!("Poseidon {mode} {i}"), || {
+                let candidate = poseidon.hash_with_len(&input);
+                assert_eq!(expected, candidate.eject_value());
+                assert!(Circuit::is_satisfied_in_scope());
+            });
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_with_len() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            for num_inputs in 0..=2 * RATE {
+                check_hash_with_len(mode, num_inputs, &mut rng)?;
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_with_len_trailing_zero() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        let native = console::Poseidon::<<Circuit as Environment>::Network, RATE>::setup(DOMAIN)?;
+        let poseidon = Poseidon::<Circuit, RATE>::constant(native);
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            let a = Field::<Circuit>::new(mode, Uniform::rand(&mut rng));
+            let b = Field::<Circuit>::new(mode, Uniform::rand(&mut rng));
+            let zero = Field::<Circuit>::new(mode, console::Field::zero());
+
+            // Ensure appending a zero changes the digest.
+            let first = poseidon.hash_with_len(&[a.clone(), b.clone()]);
+            let second = poseidon.hash_with_len(&[a, b, zero]);
+            assert_ne!(first.eject_value(), second.eject_value());
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_with_len_differs_from_hash() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        let native = console::Poseidon::<<Circuit as Environment>::Network, RATE>::setup(DOMAIN)?;
+        let poseidon = Poseidon::<Circuit, RATE>::constant(native);
+
+        let input =
+            (0..RATE).map(|_| Field::<Circuit>::new(Mode::Private, Uniform::rand(&mut rng))).collect::<Vec<_>>();
+        assert_ne!(poseidon.hash(&input).eject_value(), poseidon.hash_with_len(&input).eject_value());
+        Circuit::reset();
+        Ok(())
+    }
+}
@@ -14,18 +14,20 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+mod commit;
 mod hash;
 mod hash_many;
 mod hash_to_group;
 mod hash_to_scalar;
+mod hash_with_len;
 mod prf;
 
 #[cfg(all(test, console))]
-use snarkvm_circuit_types::environment::assert_scope;
+use snarkvm_circuit_types::environment::{assert_count, assert_output_mode, assert_scope};
 #[cfg(test)]
 use snarkvm_utilities::{TestRng, Uniform};
 
-use crate::{Elligator2, Hash, HashMany, HashToGroup, HashToScalar, PRF};
+use crate::{Commit, Elligator2, Hash, HashMany, HashToGroup, HashToScalar, PRF};
 use snarkvm_circuit_types::{environment::prelude::*, Field, Group, Scalar};
 
 /// Poseidon2 is a cryptographic hash function of input rate 2.
@@ -29,6 +29,30 @@ pub trait Commit {
     fn commit(&self, input: &[Self::Input], randomizer: &Self::Randomizer) -> Self::Output;
 }
 
+/// A trait for a commitment scheme that takes the randomizer as its little-endian bits.
+pub trait CommitFromBits {
+    type Input;
+    type Output;
+
+    /// Returns the commitment to the given input and randomizer bits.
+    fn commit_from_randomizer_bits(&self, input: &[Self::Input], randomizer_bits: &[Self::Input]) -> Self::Output;
+}
+
+/// A trait for a commitment scheme that binds the commitment to a context.
+pub trait CommitWithContext {
+    type Input;
+    type Output;
+    type Randomizer;
+
+    /// Returns the commitment to the given input and randomizer, bound to the given context.
+    fn commit_with_context(
+        &self,
+        context: &[Self::Input],
+        input: &[Self::Input],
+        randomizer: &Self::Randomizer,
+    ) -> Self::Output;
+}
+
 /// A trait for a commitment scheme.
 pub trait CommitUncompressed {
     type Input;
@@ -39,6 +63,20 @@ pub trait CommitUncompressed {
     fn commit_uncompressed(&self, input: &[Self::Input], randomizer: &Self::Randomizer) -> Self::Output;
 }
 
+/// A trait for a commitment scheme that can replace the randomizer of an existing commitment.
+pub trait CommitRefresh {
+    type Output;
+    type Randomizer;
+
+    /// Returns the given commitment, re-blinded from the old randomizer to the new randomizer.
+    fn refresh_blinding(
+        &self,
+        commitment: &Self::Output,
+        old_randomizer: &Self::Randomizer,
+        new_randomizer: &Self::Randomizer,
+    ) -> Self::Output;
+}
+
 /// A trait for a hash function.
 pub trait Hash {
     type Input: Inject + Eject + Clone;
@@ -48,6 +86,33 @@ pub trait Hash {
     fn hash(&self, input: &[Self::Input]) -> Self::Output;
 }
 
+/// A trait for a hash function over bytes.
+pub trait HashBytes {
+    type Input;
+    type Output;
+
+    /// Returns the hash of the given bytes.
+    fn hash_bytes(&self, input: &[Self::Input]) -> Self::Output;
+}
+
+/// A trait for a hash function that chains the digests of a sequence of blocks.
+pub trait HashChained {
+    type Input;
+    type Output;
+
+    /// Returns the hash of the given sequence of blocks.
+    fn hash_chained(&self, blocks: &[Vec<Self::Input>]) -> Self::Output;
+}
+
+/// A trait for a hash function that chains its digest over an input of arbitrary length.
+pub trait HashLong {
+    type Input;
+    type Output;
+
+    /// Returns the hash of the given input.
+    fn hash_long(&self, input: &[Self::Input]) -> Self::Output;
+}
+
 /// A trait for a hash function that produces multiple outputs.
 pub trait HashMany {
     type Input;
@@ -76,6 +141,15 @@ pub trait HashToScalar {
     fn hash_to_scalar(&self, input: &[Self::Input]) -> Self::Scalar;
 }
 
+/// A trait for a two-to-one compression function, such as for hashing two children in a Merkle tree.
+pub trait HashTwo {
+    type Input;
+    type Output;
+
+    /// Returns the parent digest of the given left and right children.
+    fn hash_two(&self, left: &Self::Input, right: &Self::Input) -> Self::Output;
+}
+
 /// A trait for a hash function of an uncompressed variant.
 pub trait HashUncompressed {
     type Input;
@@ -85,6 +159,15 @@ pub trait HashUncompressed {
     fn hash_uncompressed(&self, input: &[Self::Input]) -> Self::Output;
 }
 
+/// A trait for a hash function whose output is bound to the full curve point.
+pub trait HashToPoint {
+    type Input;
+    type Output;
+
+    /// Returns the hash of the given input as a curve point.
+    fn hash_to_point(&self, input: &[Self::Input]) -> Self::Output;
+}
+
 /// A trait for a pseudorandom function.
 pub trait PRF {
     type Seed;
@@ -189,6 +189,11 @@ impl Environment for Circuit {
         CIRCUIT.with(|circuit| (**circuit).borrow().is_satisfied_in_scope())
     }
 
+    /// Returns the scope of the earliest unsatisfied constraint in the environment, if one exists.
+    fn first_unsatisfied() -> Option<String> {
+        CIRCUIT.with(|circuit| (**circuit).borrow().first_unsatisfied())
+    }
+
     /// Returns the number of constants in the entire circuit.
     fn num_constants() -> u64 {
         CIRCUIT.with(|circuit| (**circuit).borrow().num_constants())
@@ -366,4 +371,26 @@ mod tests {
             assert_eq!(0, Circuit::num_constraints_in_scope());
         })
     }
+
+    #[test]
+    fn test_first_unsatisfied() {
+        let one = snarkvm_console_types::Field::<<Circuit as Environment>::Network>::one();
+
+        Circuit::scope("test_first_unsatisfied", || {
+            let a = Field::<Circuit>::new(Mode::Private, one);
+            let b = Field::<Circuit>::new(Mode::Private, one + one);
+
+            // Introduce a satisfied constraint.
+            Circuit::scope("satisfied", || Circuit::assert_eq(&a, &a));
+            assert_eq!(None, Circuit::first_unsatisfied());
+
+            // Introduce two unsatisfied constraints.
+            Circuit::scope("first_unsatisfied", || Circuit::assert_eq(&a, &b));
+            Circuit::scope("second_unsatisfied", || Circuit::assert_eq(&b, &a));
+            assert_eq!(Some("test_first_unsatisfied.first_unsatisfied".to_string()), Circuit::first_unsatisfied());
+        });
+
+        Circuit::reset();
+        assert_eq!(None, Circuit::first_unsatisfied());
+    }
 }
@@ -111,6 +111,17 @@ pub trait Environment: 'static + Copy + Clone + fmt::Debug + fmt::Display + Eq +
     /// Returns `true` if all constraints in the current scope are satisfied.
     fn is_satisfied_in_scope() -> bool;
 
+    /// Returns the scope of the earliest unsatisfied constraint in the environment, if one exists.
+    ///
+    /// The default implementation does not track the scope of each constraint,
+    /// and returns `"unknown constraint"` if any constraint is unsatisfied.
+    fn first_unsatisfied() -> Option<String> {
+        match Self::is_satisfied() {
+            true => None,
+            false => Some("unknown constraint".to_string()),
+        }
+    }
+
     /// Returns the number of constants in the entire environment.
     fn num_constants() -> u64;
 
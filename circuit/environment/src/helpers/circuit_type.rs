@@ -57,6 +57,22 @@ impl<T: Eject> CircuitType<T> {
             CircuitType::Private => Mode::Private,
         }
     }
+
+    /// Returns the `CircuitType` of an output computed from two inputs, under the join rule:
+    /// the output is `Constant` if both inputs are constant, and `Private` otherwise.
+    /// If both inputs are constant, the constant output is computed from them using `constant`.
+    pub fn join<A: Eject, B: Eject>(
+        a: &CircuitType<A>,
+        b: &CircuitType<B>,
+        constant: impl FnOnce(&A, &B) -> T,
+    ) -> Self {
+        match (a, b) {
+            (CircuitType::Constant(a), CircuitType::Constant(b)) => {
+                CircuitType::Constant(Constant::new(constant(&a.0, &b.0)))
+            }
+            _ => CircuitType::Private,
+        }
+    }
 }
 
 /// Initializes a new `CircuitType` from a circuit.
@@ -84,3 +100,73 @@ impl<T: Eject + Clone> From<&T> for CircuitType<T> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use snarkvm_circuit::prelude::*;
+
+    const MODES: [Mode; 3] = [Mode::Constant, Mode::Public, Mode::Private];
+
+    #[test]
+    fn test_join_boolean() {
+        for (mode_a, mode_b) in
+            MODES.into_iter().flat_map(|mode_a| MODES.into_iter().map(move |mode_b| (mode_a, mode_b)))
+        {
+            let a = Boolean::<Circuit>::new(mode_a, true);
+            let b = Boolean::<Circuit>::new(mode_b, false);
+
+            let candidate = CircuitType::join(&CircuitType::from(&a), &CircuitType::from(&b), |a, b| a | b);
+            match (mode_a, mode_b) {
+                (Mode::Constant, Mode::Constant) => match candidate {
+                    CircuitType::Constant(constant) => assert!(constant.eject_value()),
+                    _ => panic!("Expected a constant circuit type"),
+                },
+                _ => assert!(matches!(candidate, CircuitType::Private)),
+            }
+        }
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_join_field() {
+        let one = snarkvm_console_types::Field::<<Circuit as Environment>::Network>::one();
+
+        for (mode_a, mode_b) in
+            MODES.into_iter().flat_map(|mode_a| MODES.into_iter().map(move |mode_b| (mode_a, mode_b)))
+        {
+            let a = Field::<Circuit>::new(mode_a, one);
+            let b = Field::<Circuit>::new(mode_b, one);
+
+            let candidate = CircuitType::join(&CircuitType::from(&a), &CircuitType::from(&b), |a, b| a + b);
+            match (mode_a, mode_b) {
+                (Mode::Constant, Mode::Constant) => match candidate {
+                    CircuitType::Constant(constant) => assert_eq!(one + one, constant.eject_value()),
+                    _ => panic!("Expected a constant circuit type"),
+                },
+                _ => assert!(matches!(candidate, CircuitType::Private)),
+            }
+        }
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_join_integer() {
+        for (mode_a, mode_b) in
+            MODES.into_iter().flat_map(|mode_a| MODES.into_iter().map(move |mode_b| (mode_a, mode_b)))
+        {
+            let a = U8::<Circuit>::new(mode_a, snarkvm_console_types::U8::new(3));
+            let b = U8::<Circuit>::new(mode_b, snarkvm_console_types::U8::new(4));
+
+            let candidate = CircuitType::join(&CircuitType::from(&a), &CircuitType::from(&b), |a, b| a.add_wrapped(b));
+            assert_eq!(Mode::combine(mode_a, [mode_b]).is_constant(), candidate.mode().is_constant());
+            match (mode_a, mode_b) {
+                (Mode::Constant, Mode::Constant) => match candidate {
+                    CircuitType::Constant(constant) => assert_eq!(7, *constant.eject_value()),
+                    _ => panic!("Expected a constant circuit type"),
+                },
+                _ => assert_eq!(Mode::Private, candidate.mode()),
+            }
+        }
+        Circuit::reset();
+    }
+}
@@ -18,6 +18,10 @@ use core::{
     fmt::Debug,
     ops::{Add, Mul, Sub},
 };
+#[cfg(any(test, feature = "snapshots"))]
+use itertools::{EitherOrBoth, Itertools};
+#[cfg(any(test, feature = "snapshots"))]
+use std::path::Path;
 
 pub type Constant = Measurement<u64>;
 pub type Public = Measurement<u64>;
@@ -25,7 +29,7 @@ pub type Private = Measurement<u64>;
 pub type Constraints = Measurement<u64>;
 
 /// A helper struct for tracking the number of constants, public inputs, private inputs, and constraints.
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub struct Count(pub Constant, pub Public, pub Private, pub Constraints);
 
 impl Count {
@@ -61,6 +65,69 @@ impl Count {
             && self.2.matches(num_private)
             && self.3.matches(num_constraints)
     }
+
+    /// Returns `true` if all constituent metrics are `Exact`.
+    pub fn is_exact(&self) -> bool {
+        [self.0, self.1, self.2, self.3].iter().all(|metric| matches!(metric, Measurement::Exact(_)))
+    }
+
+    /// Returns `self` scaled by `factor`, by scaling each of its constituent metrics.
+    pub fn scale(self, factor: usize) -> Self {
+        self * factor as u64
+    }
+
+    /// Asserts that `self` matches the snapshot stored at the given path, and panics with a diff if it does not.
+    /// If `UPDATE_SNAPSHOTS` is set in the environment, the snapshot is written instead.
+    #[cfg(any(test, feature = "snapshots"))]
+    pub fn assert_snapshot<P: AsRef<Path>>(&self, path: P) {
+        self.check_snapshot(path.as_ref(), std::env::var_os("UPDATE_SNAPSHOTS").is_some())
+    }
+
+    /// Asserts that `self` matches the snapshot stored at the given path, or writes the snapshot if `update` is set.
+    #[cfg(any(test, feature = "snapshots"))]
+    fn check_snapshot(&self, path: &Path, update: bool) {
+        let actual = self.snapshot();
+
+        // Write the snapshot, if requested.
+        if update {
+            if let Some(parent) = path.parent() {
+                std::fs::create_dir_all(parent).expect("Failed to create the snapshot directory");
+            }
+            std::fs::write(path, actual).expect("Failed to write the snapshot");
+            return;
+        }
+
+        // Otherwise, ensure the snapshot exists and matches.
+        let expected = match std::fs::read_to_string(path) {
+            Ok(expected) => expected,
+            Err(error) => panic!(
+                "Missing the snapshot at '{}' ({error}), run with `UPDATE_SNAPSHOTS=1` to create it",
+                path.display()
+            ),
+        };
+        if expected != actual {
+            let diff = expected
+                .lines()
+                .zip_longest(actual.lines())
+                .filter_map(|lines| match lines {
+                    EitherOrBoth::Both(expected, actual) if expected == actual => None,
+                    EitherOrBoth::Both(expected, actual) => Some(format!("-{expected}\n+{actual}\n")),
+                    EitherOrBoth::Left(expected) => Some(format!("-{expected}\n")),
+                    EitherOrBoth::Right(actual) => Some(format!("+{actual}\n")),
+                })
+                .collect::<String>();
+            panic!("Count does not match the snapshot at '{}':\n{diff}", path.display());
+        }
+    }
+
+    /// Returns the snapshot representation of `self`, with one metric per line.
+    #[cfg(any(test, feature = "snapshots"))]
+    fn snapshot(&self) -> String {
+        format!(
+            "num_constants: {:?}\nnum_public: {:?}\nnum_private: {:?}\nnum_constraints: {:?}\n",
+            self.0, self.1, self.2, self.3
+        )
+    }
 }
 
 impl Add for Count {
@@ -118,6 +185,13 @@ impl<V: Copy + Debug + Ord + Add<Output = V> + Sub<Output = V> + Mul<Output = V>
 
         outcome
     }
+
+    /// Returns the largest value that matches the metric.
+    pub fn upper_bound(&self) -> V {
+        match self {
+            Measurement::Exact(value) | Measurement::Range(_, value) | Measurement::UpperBound(value) => *value,
+        }
+    }
 }
 
 impl<V: Copy + Debug + Ord + Add<Output = V> + Sub<Output = V> + Mul<Output = V>> Add for Measurement<V> {
@@ -176,6 +250,30 @@ mod test {
 
     const ITERATIONS: u64 = 1024;
 
+    #[test]
+    fn test_count_snapshot() {
+        let path = std::env::temp_dir().join(format!("count_snapshot_{}.snap", u64::rand(&mut TestRng::default())));
+
+        // Check that a missing snapshot fails.
+        let count = Count::is(1, 2, 3, 4);
+        let result = std::panic::catch_unwind(|| count.check_snapshot(&path, false));
+        assert!(result.is_err());
+
+        // Check that an updated snapshot is written, and then matches.
+        count.check_snapshot(&path, true);
+        count.check_snapshot(&path, false);
+        assert_eq!(
+            "num_constants: Exact(1)\nnum_public: Exact(2)\nnum_private: Exact(3)\nnum_constraints: Exact(4)\n",
+            std::fs::read_to_string(&path).unwrap()
+        );
+
+        // Check that a drifted count fails.
+        let result = std::panic::catch_unwind(|| Count::is(1, 2, 3, 5).check_snapshot(&path, false));
+        assert!(result.is_err());
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
     #[test]
     fn test_exact_matches() {
         let mut rng = TestRng::default();
@@ -216,6 +314,13 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_upper_bound() {
+        assert_eq!(3, Measurement::Exact(3u64).upper_bound());
+        assert_eq!(5, Measurement::Range(2u64, 5).upper_bound());
+        assert_eq!(7, Measurement::UpperBound(7u64).upper_bound());
+    }
+
     #[test]
     fn test_range_matches() {
         let mut rng = TestRng::default();
@@ -517,4 +622,65 @@ mod test {
             assert_eq!(candidate, expected);
         }
     }
+
+    // Test counts.
+
+    #[test]
+    fn test_count_add() {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let [a, b, c, d, e, f, g, h] = [(); 8].map(|_| u32::rand(&mut rng) as u64);
+
+            // `Exact` + `Exact` => `Exact`
+            let candidate = Count::is(a, b, c, d) + Count::is(e, f, g, h);
+            assert!(candidate.is_exact());
+            assert!(candidate.matches(a + e, b + f, c + g, d + h));
+
+            // `Exact` + `UpperBound` => `UpperBound`
+            let candidate = Count::is(a, b, c, d) + Count::less_than(e, f, g, h);
+            assert!(!candidate.is_exact());
+            assert!(candidate.matches(a + e, b + f, c + g, d + h));
+            assert!(candidate.matches(a, b, c, d));
+            assert!(!candidate.matches(a + e + 1, b + f, c + g, d + h));
+        }
+    }
+
+    #[test]
+    fn test_count_scale() {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let [a, b, c, d] = [(); 4].map(|_| u16::rand(&mut rng) as u64);
+            let factor = u16::rand(&mut rng) as usize;
+
+            // Scaling an `Exact` count remains `Exact`.
+            let candidate = Count::is(a, b, c, d).scale(factor);
+            assert!(candidate.is_exact());
+            let factor = factor as u64;
+            assert!(candidate.matches(a * factor, b * factor, c * factor, d * factor));
+            assert_eq!(Count::is(a, b, c, d) * factor, candidate);
+
+            // Scaling an `UpperBound` count remains an `UpperBound`.
+            let candidate = Count::less_than(a, b, c, d).scale(factor as usize);
+            assert!(!candidate.is_exact());
+            assert!(candidate.matches(a * factor, b * factor, c * factor, d * factor));
+            assert!(candidate.matches(0, 0, 0, 0));
+        }
+    }
+
+    #[test]
+    fn test_count_zero_is_identity() {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let [a, b, c, d] = [(); 4].map(|_| u32::rand(&mut rng) as u64);
+
+            let candidate = Count::zero() + Count::is(a, b, c, d);
+            assert!(candidate.is_exact());
+            assert!(candidate.matches(a, b, c, d));
+            assert_eq!(Count::is(a, b, c, d).scale(1), candidate);
+            assert!(Count::is(a, b, c, d).scale(0).matches(0, 0, 0, 0));
+        }
+    }
 }
@@ -98,6 +98,11 @@ impl<F: PrimeField> R1CS<F> {
         self.counter.is_satisfied_in_scope()
     }
 
+    /// Returns the scope of the earliest unsatisfied constraint, if one exists.
+    pub(crate) fn first_unsatisfied(&self) -> Option<Scope> {
+        self.constraints.iter().find(|constraint| !constraint.is_satisfied()).map(|constraint| constraint.0.clone())
+    }
+
     /// Returns the current scope.
     pub(crate) fn scope(&self) -> Scope {
         self.counter.scope()
@@ -237,3 +237,38 @@ macro_rules! assert_output_mode {
         assert_output_mode!($input_a<Circuit, $($parameter_a),+>, $operation<Boolean = $boolean<Circuit>, Output = $output<Circuit, $($parameter_c),+>>, $case, $candidate)
     }};
 }
+
+/// Asserts the given count matches the snapshot with the given name, stored under `snapshots/` in the calling crate.
+/// To create or update the snapshot, run the test with `UPDATE_SNAPSHOTS=1`.
+/// Requires the `snapshots` feature of this crate, which is intended for dev-dependencies.
+///
+/// ## Example
+/// ```ignore
+/// assert_count_snapshot!("pedersen128_hash", Count::is(0, 0, 1024, 1024))
+/// ```
+#[macro_export]
+macro_rules! assert_count_snapshot {
+    ($name:expr, $count:expr) => {{
+        let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("snapshots").join(format!("{}.snap", $name));
+        $crate::Count::assert_snapshot(&$count, path)
+    }};
+}
+
+/// Asserts the number of constraints for a given operation and case, as reported by `Metrics`, is within the budget.
+///
+/// ## Example
+/// ```ignore
+/// assert_within_budget!(Pedersen128<Circuit>, Hash<Input = Boolean<Circuit>, Output = Field<Circuit>>, &modes, 800)
+/// ```
+#[macro_export]
+macro_rules! assert_within_budget {
+    ($type_:ty, $operation:path, $case:expr, $max_constraints:expr) => {{
+        let num_constraints = count!($type_, $operation, $case).3.upper_bound();
+        let max_constraints: u64 = $max_constraints;
+        assert!(
+            num_constraints <= max_constraints,
+            "Metrics claims up to {num_constraints} constraints, exceeding the budget of {max_constraints} by {}",
+            num_constraints - max_constraints
+        );
+    }};
+}
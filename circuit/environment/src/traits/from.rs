@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{BooleanTrait, FieldTrait, GroupTrait, ScalarTrait};
+use crate::{BooleanTrait, FieldTrait, GroupTrait, IntegerCore, ScalarTrait};
 
 /// Unary operator for instantiating from a boolean.
 pub trait FromBoolean {
@@ -38,6 +38,15 @@ pub trait FromBits {
         Self: Sized;
 }
 
+/// Unary operator for instantiating from an arbitrary number of bits, reduced modulo the field order.
+pub trait FromBitsMod {
+    type Boolean: BooleanTrait;
+
+    fn from_bits_le_mod(bits_le: &[Self::Boolean]) -> Self
+    where
+        Self: Sized;
+}
+
 /// Unary operator for converting from a base field element.
 pub trait FromField {
     type Field: FieldTrait;
@@ -46,6 +55,24 @@ pub trait FromField {
     fn from_field(field: Self::Field) -> Self;
 }
 
+/// Unary operator for converting from a base field element, truncating to the size of the type.
+pub trait FromFieldLossy {
+    type Field: FieldTrait;
+
+    /// Casts a circuit from the lower bits of a base field element.
+    fn from_field_lossy(field: &Self::Field) -> Self
+    where
+        Self: Sized;
+}
+
+/// Unary operator for instantiating a base field element from a list of **little-endian** bytes.
+pub trait FromFieldBytes<U8: IntegerCore<u8>> {
+    /// Returns the base field element from the given little-endian bytes.
+    fn from_bytes_le(bytes_le: &[U8]) -> Self
+    where
+        Self: Sized;
+}
+
 /// Unary operator for converting from a list of base elements.
 pub trait FromFields {
     type Field: FieldTrait;
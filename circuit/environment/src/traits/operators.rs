@@ -20,7 +20,7 @@ pub use console::traits::{
     from_bits::{SizeInBits, SizeInDataBits},
 };
 
-use crate::BooleanTrait;
+use crate::{BooleanTrait, FieldTrait};
 
 /// Unary operator for retrieving the inverse value.
 pub trait Inverse {
@@ -36,6 +36,16 @@ pub trait SquareRoot {
     fn square_root(&self) -> Self::Output;
 }
 
+/// Operator for retrieving the inverses of a batch of values.
+pub trait BatchInverse {
+    type Output;
+
+    /// Returns the inverse of each value, in the same order as the given values.
+    fn batch_inverse(values: &[Self]) -> Vec<Self::Output>
+    where
+        Self: Sized;
+}
+
 ///
 /// A single-bit binary adder with a carry bit.
 ///
@@ -70,6 +80,325 @@ pub trait Subtractor {
     fn subtractor(&self, other: &Self, borrow: &Self) -> (Self::Difference, Self::Borrow);
 }
 
+/// Unary operator for conditionally negating a value.
+pub trait ConditionalNeg {
+    type Boolean: BooleanTrait;
+    type Output;
+
+    /// Returns `-self` if `condition` is `true`, otherwise returns `self`.
+    fn conditional_neg(&self, condition: &Self::Boolean) -> Self::Output;
+}
+
+/// Unary operator for conditionally incrementing a value.
+pub trait ConditionalIncrement {
+    type Boolean: BooleanTrait;
+    type Output;
+
+    /// Returns `self + 1` if `condition` is `true`, otherwise returns `self`.
+    fn conditional_add_one(&self, condition: &Self::Boolean) -> Self::Output;
+}
+
+/// Unary operator for conditionally decrementing a value.
+pub trait ConditionalDecrement {
+    type Boolean: BooleanTrait;
+    type Output;
+
+    /// Returns `self - 1` if `condition` is `true`, otherwise returns `self`.
+    fn conditional_sub_one(&self, condition: &Self::Boolean) -> Self::Output;
+}
+
+/// Operator for conditionally swapping two values.
+pub trait ConditionalSwap {
+    type Boolean: BooleanTrait;
+    type Output;
+
+    /// Returns `(second, first)` if `condition` is `true`, otherwise returns `(first, second)`.
+    fn conditional_swap(condition: &Self::Boolean, first: &Self, second: &Self) -> (Self::Output, Self::Output)
+    where
+        Self: Sized;
+}
+
+/// Binary operator for dividing two values, rounding the quotient such that the remainder is non-negative.
+pub trait DivEuclid<Rhs: ?Sized = Self> {
+    type Output;
+
+    /// Returns the Euclidean quotient of `self` and `other`.
+    fn div_euclid(&self, other: &Rhs) -> Self::Output;
+}
+
+/// Binary operator for computing the greatest common divisor.
+pub trait Gcd<Rhs: ?Sized = Self> {
+    type Output;
+
+    /// Returns the greatest common divisor of `self` and `other`.
+    fn gcd(&self, other: &Rhs) -> Self::Output;
+}
+
+/// Unary operator for determining if the value is negative.
+pub trait IsNegative {
+    type Boolean: BooleanTrait;
+
+    /// Returns `true` if `self` is less than zero.
+    fn is_negative(&self) -> Self::Boolean;
+}
+
+/// Unary operator for determining if the value is non-negative.
+pub trait IsNonNegative {
+    type Boolean: BooleanTrait;
+
+    /// Returns `true` if `self` is greater than or equal to zero.
+    fn is_non_negative(&self) -> Self::Boolean;
+}
+
+/// Unary operator for determining if the value is positive.
+pub trait IsPositive {
+    type Boolean: BooleanTrait;
+
+    /// Returns `true` if `self` is greater than zero.
+    fn is_positive(&self) -> Self::Boolean;
+}
+
+/// Unary operator for determining if the value is a power of two.
+pub trait IsPowerOfTwo {
+    type Boolean: BooleanTrait;
+
+    /// Returns `true` if `self` is a power of two.
+    fn is_power_of_two(&self) -> Self::Boolean;
+}
+
+/// Unary operator for computing the parity of the value.
+pub trait Parity {
+    type Boolean: BooleanTrait;
+
+    /// Returns `true` if an odd number of bits in `self` are set.
+    fn parity(&self) -> Self::Boolean;
+}
+
+/// Binary operator for computing the least common multiple.
+pub trait Lcm<Rhs: ?Sized = Self> {
+    type Output;
+
+    /// Returns the least common multiple of `self` and `other`.
+    fn lcm(&self, other: &Rhs) -> Self::Output;
+}
+
+/// Unary operator for negating a value, enforcing an overflow never occurs.
+pub trait NegChecked {
+    type Output;
+
+    /// Returns the negation of `self`.
+    fn neg_checked(&self) -> Self::Output;
+}
+
+/// Unary operator for negating a value, wrapping the result and flagging if an overflow occurs.
+pub trait NegFlagged {
+    type Boolean: BooleanTrait;
+    type Output;
+
+    /// Returns the wrapped negation of `self`, and `true` if an overflow occurred.
+    fn neg_flagged(&self) -> (Self::Output, Self::Boolean);
+}
+
+/// Unary operator for rounding a value up to the nearest power of two.
+pub trait NextPowerOfTwo {
+    type Output;
+
+    /// Returns the smallest power of two greater than or equal to `self`.
+    fn next_power_of_two(&self) -> Self::Output;
+}
+
+/// Binary operator for computing the non-negative remainder of dividing two values.
+pub trait RemEuclid<Rhs: ?Sized = Self> {
+    type Output;
+
+    /// Returns the Euclidean remainder of `self` and `other`.
+    fn rem_euclid(&self, other: &Rhs) -> Self::Output;
+}
+
+/// Operator for selecting an element from a table, given the little-endian bits of its index.
+pub trait SelectIndex {
+    type Boolean: BooleanTrait;
+
+    /// Returns `table[index]`, where `index` is given by its little-endian bits.
+    fn select_index(index_bits: &[Self::Boolean], table: &[Self]) -> Self
+    where
+        Self: Sized;
+}
+
+/// Operator for selecting an element from a table, given its index as an integer.
+pub trait SelectIndexed<Index: ?Sized> {
+    /// Returns `table[index]`, halting if the index is out of range.
+    fn select_indexed(index: &Index, table: &[Self]) -> Self
+    where
+        Self: Sized;
+}
+
+/// Operator for sorting a fixed-size array of values in ascending order.
+pub trait Sort<const N: usize> {
+    type Output;
+
+    /// Returns the given values, sorted in ascending order.
+    fn sort(values: &[Self; N]) -> [Self::Output; N]
+    where
+        Self: Sized;
+}
+
+/// Binary operator for adding a signed delta to an unsigned value, enforcing an overflow or underflow never occurs.
+pub trait AddSignedChecked<Rhs: ?Sized = Self> {
+    type Output;
+
+    /// Returns the sum of `self` and the signed `delta`, halting on overflow or underflow.
+    fn add_signed_checked(&self, delta: &Rhs) -> Self::Output;
+}
+
+/// Operator for determining if a sequence of values contains no duplicates.
+pub trait AllDistinct {
+    type Boolean: BooleanTrait;
+
+    /// Returns `true` if no two values in `items` are equal.
+    fn all_distinct(items: &[Self]) -> Self::Boolean
+    where
+        Self: Sized;
+}
+
+/// Binary operator for enforcing that two values are distinct.
+pub trait AssertNotEqual<Rhs: ?Sized = Self> {
+    /// Enforces that `self` and `other` are *not* equal.
+    fn assert_not_equal(&self, other: &Rhs);
+}
+
+/// Operator for enforcing that a sequence of values is sorted.
+pub trait AssertSorted {
+    /// Enforces that `items` is sorted in ascending order if `ascending` is `true`, or in descending order otherwise.
+    /// Note: Equal adjacent items are permitted in either order.
+    fn assert_sorted(items: &[Self], ascending: bool)
+    where
+        Self: Sized;
+}
+
+/// Operator for checking if at least `k` values in a sequence of booleans are `true`.
+pub trait AtLeast {
+    type Boolean: BooleanTrait;
+
+    /// Returns `true` if at least `k` values in `bits` are `true`, where the count is of type `Self`.
+    fn at_least(bits: &[Self::Boolean], k: u32) -> Self::Boolean
+    where
+        Self: Sized;
+}
+
+/// Unary operator for checking that a value is in canonical (reduced) form.
+pub trait Canonical {
+    type Boolean: BooleanTrait;
+
+    /// Returns `true` if `self` is in canonical form.
+    fn is_canonical(&self) -> Self::Boolean;
+
+    /// Asserts that `self` is in canonical form.
+    fn assert_canonical(&self);
+}
+
+/// Operator for counting the number of `true` values in a sequence of booleans.
+pub trait CountTrue {
+    type Boolean: BooleanTrait;
+
+    /// Returns the number of `true` values in `bits`.
+    fn count_true(bits: &[Self::Boolean]) -> Self
+    where
+        Self: Sized;
+}
+
+/// Operator for enforcing that a sequence of fees does not exceed a maximum total.
+pub trait CumulativeFee {
+    type Boolean: BooleanTrait;
+
+    /// Returns `true` if the sum of `fees` does not exceed `max_total`, and enforces that this holds.
+    fn assert_cumulative_fee(fees: &[Self], max_total: &Self) -> Self::Boolean
+    where
+        Self: Sized;
+}
+
+/// Operator for computing the dot product of two sequences of values.
+pub trait DotProduct {
+    /// Returns the sum of the pairwise products of `a` and `b`, wrapping around at the boundary of the type.
+    fn dot(a: &[Self], b: &[Self]) -> Self
+    where
+        Self: Sized;
+}
+
+/// Operator for computing the weighted sum of values, with constant coefficients.
+pub trait LinearCombine {
+    type Coefficient;
+
+    /// Returns the sum of `coefficient * value` for all given terms, wrapping around at the boundary of the type.
+    fn linear_combination(terms: &[(Self::Coefficient, Self)]) -> Self
+    where
+        Self: Sized;
+}
+
+/// Binary operator for modular multiplication.
+pub trait MulMod<Rhs: ?Sized = Self> {
+    type Output;
+
+    /// Returns `(self * other) mod modulus`, computed over the double-width product.
+    fn mul_mod(&self, other: &Rhs, modulus: &Rhs) -> Self::Output;
+}
+
+/// Operator for checking if exactly one boolean in a sequence is `true`.
+pub trait OneHot {
+    type Output;
+
+    /// Returns `true` if exactly one boolean in `bits` is `true`.
+    fn is_one_hot(bits: &[Self]) -> Self::Output
+    where
+        Self: Sized;
+
+    /// Enforces that exactly one boolean in `bits` is `true`.
+    fn assert_one_hot(bits: &[Self])
+    where
+        Self: Sized;
+}
+
+/// Operator for packing a sequence of values of mixed bit widths into a base field element.
+pub trait PackIntegers {
+    type Field: FieldTrait;
+
+    /// Returns the concatenation of the little-endian bits of the given width-tagged items, packed into a base field element.
+    fn pack_integers(items: &[Self]) -> Self::Field
+    where
+        Self: Sized;
+}
+
+/// Operator for checking if two sequences of booleans are equal.
+pub trait SlicesEqual {
+    type Output;
+
+    /// Returns `true` if `a` and `b` are equal at every position.
+    fn slices_equal(a: &[Self], b: &[Self]) -> Self::Output
+    where
+        Self: Sized;
+}
+
+/// Unary operator for checking that a point lies in the prime-order subgroup.
+pub trait Subgroup {
+    type Boolean: BooleanTrait;
+
+    /// Returns `true` if `self` is in the prime-order subgroup.
+    fn is_in_subgroup(&self) -> Self::Boolean;
+
+    /// Asserts that `self` is in the prime-order subgroup.
+    fn assert_in_subgroup(&self);
+}
+
+/// Operator for unpacking a base field element into a sequence of values of mixed bit widths.
+pub trait UnpackIntegers {
+    type Field: FieldTrait;
+
+    /// Returns one item per given bit width, unpacked from the little-endian bits of the base field element.
+    fn unpack_integers(field: &Self::Field, widths: &[usize]) -> Vec<Self>
+    where
+        Self: Sized;
+}
+
 /// Representation of the zero value.
 pub trait Zero {
     type Boolean: BooleanTrait;
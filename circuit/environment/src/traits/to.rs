@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{BooleanTrait, FieldTrait, GroupTrait, ScalarTrait};
+use crate::{BooleanTrait, FieldTrait, GroupTrait, IntegerCore, ScalarTrait};
 
 /// Unary operator for converting to `k` number of bits.
 pub trait ToLowerBits {
@@ -50,6 +50,12 @@ pub trait ToUpperBits {
     fn to_upper_bits_be(&self, k: usize) -> Vec<Self::Boolean>;
 }
 
+/// Unary operator for converting a base field element into a list of **little-endian** bytes.
+pub trait ToFieldBytes<U8: IntegerCore<u8>> {
+    /// Returns the little-endian bytes of `self`.
+    fn to_bytes_le(&self) -> Vec<U8>;
+}
+
 /// Unary operator for converting to a base field.
 pub trait ToField {
     type Field: FieldTrait;
@@ -74,3 +80,11 @@ pub trait ToGroup {
     /// Returns the circuit as a list of affine group elements.
     fn to_group(&self) -> Self::Group;
 }
+
+/// Unary operator for converting to a scalar field element.
+pub trait ToScalar {
+    type Scalar: ScalarTrait;
+
+    /// Returns the circuit as a scalar field element.
+    fn to_scalar(&self) -> Self::Scalar;
+}
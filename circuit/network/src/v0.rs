@@ -323,6 +323,11 @@ impl Environment for AleoV0 {
         E::is_satisfied_in_scope()
     }
 
+    /// Returns the scope of the earliest unsatisfied constraint in the environment, if one exists.
+    fn first_unsatisfied() -> Option<String> {
+        E::first_unsatisfied()
+    }
+
     /// Returns the number of constants in the entire circuit.
     fn num_constants() -> u64 {
         E::num_constants()
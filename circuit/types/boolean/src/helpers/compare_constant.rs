@@ -0,0 +1,76 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// Returns `true` if the given **little-endian** bits are less than or equal to the given constant **little-endian** bits.
+/// The two lists of bits must have the same length.
+///
+/// To check that `bits_le < MODULUS`, pass the bits of `MODULUS - 1` as `constant_le`.
+pub fn is_less_than_or_equal_constant<E: Environment>(bits_le: &[Boolean<E>], constant_le: &[bool]) -> Boolean<E> {
+    // Compute `!(constant < bits_le)`, folding from the least significant bit.
+    !constant_le.iter().zip_eq(bits_le).fold(Boolean::constant(false), |rest_is_less, (this, that)| {
+        if *this {
+            that.bitand(&rest_is_less)
+        } else {
+            that.bitor(&rest_is_less)
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const NUM_BITS: usize = 8;
+
+    /// Returns the little-endian bits of the given 8-bit value.
+    fn to_bits_le(value: u8) -> Vec<bool> {
+        (0..NUM_BITS).map(|i| (value >> i) & 1 == 1).collect()
+    }
+
+    fn check_is_less_than_or_equal_constant(mode: Mode) {
+        for constant in [0u8, 1, 0b0101_1010, 0b1000_0000, u8::MAX] {
+            for value in [0u8, 1, 0b0101_1001, 0b0101_1010, 0b0101_1011, 0b1000_0000, u8::MAX] {
+                let bits_le =
+                    to_bits_le(value).into_iter().map(|bit| Boolean::<Circuit>::new(mode, bit)).collect::<Vec<_>>();
+
+                Circuit::scope(format!("{mode} {value} <= {constant}"), || {
+                    let candidate = is_less_than_or_equal_constant(&bits_le, &to_bits_le(constant));
+                    assert_eq!(value <= constant, candidate.eject_value());
+                });
+                assert!(Circuit::is_satisfied());
+                Circuit::reset();
+            }
+        }
+    }
+
+    #[test]
+    fn test_is_less_than_or_equal_constant_constant() {
+        check_is_less_than_or_equal_constant(Mode::Constant);
+    }
+
+    #[test]
+    fn test_is_less_than_or_equal_constant_public() {
+        check_is_less_than_or_equal_constant(Mode::Public);
+    }
+
+    #[test]
+    fn test_is_less_than_or_equal_constant_private() {
+        check_is_less_than_or_equal_constant(Mode::Private);
+    }
+}
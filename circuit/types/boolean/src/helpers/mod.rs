@@ -17,6 +17,8 @@
 use super::*;
 
 pub mod adder;
+pub mod compare_constant;
 pub mod from_bits;
+pub mod shift_bits;
 pub mod subtractor;
 pub mod to_bits;
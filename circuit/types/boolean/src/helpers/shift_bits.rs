@@ -0,0 +1,146 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// Returns the given **little-endian** bits rotated left by `amount` positions.
+/// The bit at index `i` moves to index `(i + amount) % bits_le.len()`.
+/// This operation is free, as it only rearranges the bits.
+pub fn rotate_left_bits<E: Environment>(bits_le: &[Boolean<E>], amount: usize) -> Vec<Boolean<E>> {
+    let mut rotated = bits_le.to_vec();
+    if !rotated.is_empty() {
+        rotated.rotate_right(amount % bits_le.len());
+    }
+    rotated
+}
+
+/// Returns the given **little-endian** bits rotated right by `amount` positions.
+/// The bit at index `i` moves to index `(i - amount) % bits_le.len()`.
+/// This operation is free, as it only rearranges the bits.
+pub fn rotate_right_bits<E: Environment>(bits_le: &[Boolean<E>], amount: usize) -> Vec<Boolean<E>> {
+    let mut rotated = bits_le.to_vec();
+    if !rotated.is_empty() {
+        rotated.rotate_left(amount % bits_le.len());
+    }
+    rotated
+}
+
+/// Returns the given **little-endian** bits shifted left by `amount` positions, filling with `0`s.
+/// The length is preserved, so the `amount` most significant bits are discarded.
+/// This operation is free, as it only rearranges the bits.
+pub fn shift_left_bits<E: Environment>(bits_le: &[Boolean<E>], amount: usize) -> Vec<Boolean<E>> {
+    let amount = amount.min(bits_le.len());
+    let mut shifted = vec![Boolean::constant(false); amount];
+    shifted.extend_from_slice(&bits_le[..bits_le.len() - amount]);
+    shifted
+}
+
+/// Returns the given **little-endian** bits shifted right by `amount` positions, filling with `0`s.
+/// The length is preserved, so the `amount` least significant bits are discarded.
+/// This operation is free, as it only rearranges the bits.
+pub fn shift_right_bits<E: Environment>(bits_le: &[Boolean<E>], amount: usize) -> Vec<Boolean<E>> {
+    let amount = amount.min(bits_le.len());
+    let mut shifted = bits_le[amount..].to_vec();
+    shifted.resize(bits_le.len(), Boolean::constant(false));
+    shifted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const NUM_BITS: usize = 12;
+    const MASK: u16 = (1 << NUM_BITS) - 1;
+
+    /// A bit-shifting operation over little-endian bits.
+    type ShiftBits = fn(&[Boolean<Circuit>], usize) -> Vec<Boolean<Circuit>>;
+
+    /// Returns the little-endian bits of the given 12-bit value.
+    fn to_bits_le(value: u16) -> Vec<bool> {
+        (0..NUM_BITS).map(|i| (value >> i) & 1 == 1).collect()
+    }
+
+    fn check_shift_bits(operation: ShiftBits, reference: fn(u16, usize) -> u16, mode: Mode) {
+        for value in [0u16, 1, 0b1000_0000_0001, 0b1010_0110_1101, MASK] {
+            for amount in 0..=2 * NUM_BITS {
+                let bits_le = to_bits_le(value).into_iter().map(|bit| Boolean::new(mode, bit)).collect::<Vec<_>>();
+
+                Circuit::scope(format!("{mode} {value} {amount}"), || {
+                    let candidate = operation(&bits_le, amount);
+                    assert_eq!(to_bits_le(reference(value, amount)), candidate.eject_value());
+                    assert_scope!(0, 0, 0, 0);
+                });
+                Circuit::reset();
+            }
+        }
+    }
+
+    fn rotate_left(value: u16, amount: usize) -> u16 {
+        let amount = amount % NUM_BITS;
+        ((value << amount) | (value >> (NUM_BITS - amount))) & MASK
+    }
+
+    fn rotate_right(value: u16, amount: usize) -> u16 {
+        let amount = amount % NUM_BITS;
+        ((value >> amount) | (value << (NUM_BITS - amount))) & MASK
+    }
+
+    fn shift_left(value: u16, amount: usize) -> u16 {
+        value.checked_shl(amount as u32).unwrap_or(0) & MASK
+    }
+
+    fn shift_right(value: u16, amount: usize) -> u16 {
+        value.checked_shr(amount as u32).unwrap_or(0)
+    }
+
+    #[test]
+    fn test_rotate_left_bits() {
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            check_shift_bits(rotate_left_bits, rotate_left, mode);
+        }
+    }
+
+    #[test]
+    fn test_rotate_right_bits() {
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            check_shift_bits(rotate_right_bits, rotate_right, mode);
+        }
+    }
+
+    #[test]
+    fn test_shift_left_bits() {
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            check_shift_bits(shift_left_bits, shift_left, mode);
+        }
+    }
+
+    #[test]
+    fn test_shift_right_bits() {
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            check_shift_bits(shift_right_bits, shift_right, mode);
+        }
+    }
+
+    #[test]
+    fn test_shift_bits_empty() {
+        let bits_le: Vec<Boolean<Circuit>> = vec![];
+        assert!(rotate_left_bits(&bits_le, 3).is_empty());
+        assert!(rotate_right_bits(&bits_le, 3).is_empty());
+        assert!(shift_left_bits(&bits_le, 3).is_empty());
+        assert!(shift_right_bits(&bits_le, 3).is_empty());
+    }
+}
@@ -25,10 +25,17 @@ pub mod equal;
 pub mod nand;
 pub mod nor;
 pub mod not;
+pub mod one_hot;
 pub mod or;
+pub mod slices_equal;
 pub mod ternary;
 pub mod xor;
 
+pub use helpers::{
+    compare_constant::is_less_than_or_equal_constant,
+    shift_bits::{rotate_left_bits, rotate_right_bits, shift_left_bits, shift_right_bits},
+};
+
 #[cfg(test)]
 use snarkvm_circuit_environment::{assert_count, assert_output_mode, assert_scope, count, output_mode};
 
@@ -0,0 +1,163 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> OneHot for Boolean<E> {
+    type Output = Boolean<E>;
+
+    /// Returns `true` if exactly one boolean in `bits` is `true`.
+    /// This method costs 2 constraints, if any boolean is not a constant.
+    fn is_one_hot(bits: &[Self]) -> Self::Output {
+        // Compute `sum(bits) - 1`, which is zero iff exactly one boolean is `true`.
+        // Note: This is free, as the sum of the bits is a linear combination.
+        let delta = bits.iter().fold(E::zero(), |sum, bit| sum + &bit.0) - E::one();
+
+        // If every boolean is a constant, the result is a constant.
+        if delta.is_constant() {
+            return Boolean::constant(delta.value().is_zero());
+        }
+
+        // Declare a new variable that is `true` iff `delta` is nonzero.
+        let is_not_one_hot = Boolean(
+            E::new_variable(Mode::Private, match delta.value().is_zero() {
+                true => E::BaseField::zero(),
+                false => E::BaseField::one(),
+            })
+            .into(),
+        );
+        // Declare the inverse of `delta` as the multiplier, or any value if `delta` is zero.
+        let multiplier = E::new_variable(Mode::Private, delta.value().inverse().unwrap_or_else(E::BaseField::one));
+
+        // Ensure `delta` * `multiplier` = `is_not_one_hot`.
+        // If `delta` is zero, this ensures `is_not_one_hot` is `0`.
+        E::enforce(|| (delta.clone(), multiplier, &is_not_one_hot));
+        // Ensure `delta` * (1 - `is_not_one_hot`) = 0.
+        // If `delta` is nonzero, this ensures `is_not_one_hot` is `1`.
+        E::enforce(|| (delta, E::one() - &is_not_one_hot.0, E::zero()));
+
+        !is_not_one_hot
+    }
+
+    /// Enforces that exactly one boolean in `bits` is `true`.
+    /// This method costs 1 constraint, if any boolean is not a constant, and halts otherwise if the check fails.
+    fn assert_one_hot(bits: &[Self]) {
+        // Ensure the sum of the bits is one.
+        E::assert_eq(bits.iter().fold(E::zero(), |sum, bit| sum + &bit.0), E::one());
+    }
+}
+
+impl<E: Environment> Metrics<dyn OneHot<Output = Boolean<E>>> for Boolean<E> {
+    type Case = Vec<Mode>;
+
+    /// Returns the count of `is_one_hot`.
+    fn count(case: &Self::Case) -> Count {
+        match case.iter().all(|mode| mode.is_constant()) {
+            true => Count::is(0, 0, 0, 0),
+            false => Count::is(0, 0, 2, 2),
+        }
+    }
+}
+
+impl<E: Environment> OutputMode<dyn OneHot<Output = Boolean<E>>> for Boolean<E> {
+    type Case = Vec<Mode>;
+
+    /// Returns the output mode of `is_one_hot`.
+    fn output_mode(case: &Self::Case) -> Mode {
+        match case.iter().all(|mode| mode.is_constant()) {
+            true => Mode::Constant,
+            false => Mode::Private,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    fn check_one_hot(name: &str, bits: &[bool], mode: Mode) {
+        let expected = bits.iter().filter(|bit| **bit).count() == 1;
+        let circuit_bits = bits.iter().map(|bit| Boolean::<Circuit>::new(mode, *bit)).collect::<Vec<_>>();
+        let case = vec![mode; bits.len()];
+
+        Circuit::scope(format!("IsOneHot {name}"), || {
+            let candidate = Boolean::is_one_hot(&circuit_bits);
+            assert_eq!(expected, candidate.eject_value());
+            assert_count!(Boolean<Circuit>, OneHot<Output = Boolean<Circuit>>, &case);
+            assert_output_mode!(Boolean<Circuit>, OneHot<Output = Boolean<Circuit>>, &case, candidate);
+        });
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+
+        match (mode.is_constant() || bits.is_empty(), expected) {
+            // A failing check on constants halts.
+            (true, false) => {
+                let result = std::panic::catch_unwind(|| Boolean::assert_one_hot(&circuit_bits));
+                assert!(result.is_err());
+            }
+            (true, true) => Circuit::scope(format!("AssertOneHot {name}"), || {
+                Boolean::assert_one_hot(&circuit_bits);
+                assert_scope!(0, 0, 0, 0);
+            }),
+            (false, _) => Circuit::scope(format!("AssertOneHot {name}"), || {
+                Boolean::assert_one_hot(&circuit_bits);
+                assert_eq!(1, Circuit::num_constraints_in_scope());
+                assert_eq!(expected, Circuit::is_satisfied_in_scope());
+            }),
+        }
+        Circuit::reset();
+    }
+
+    fn run_test(mode: Mode) {
+        for num_bits in [1, 2, 3, 8, 16] {
+            for i in 0..num_bits {
+                // Check a valid one-hot encoding of each index.
+                let one_hot = (0..num_bits).map(|j| i == j).collect::<Vec<_>>();
+                check_one_hot(&format!("{mode} {num_bits} (index = {i})"), &one_hot, mode);
+
+                // Check a two-hot encoding.
+                if num_bits > 1 {
+                    let mut two_hot = one_hot.clone();
+                    two_hot[(i + 1) % num_bits] = true;
+                    check_one_hot(&format!("{mode} {num_bits} (two-hot at {i})"), &two_hot, mode);
+                }
+            }
+
+            // Check the all-zero and all-one encodings.
+            check_one_hot(&format!("{mode} {num_bits} (all zero)"), &vec![false; num_bits], mode);
+            check_one_hot(&format!("{mode} {num_bits} (all one)"), &vec![true; num_bits], mode);
+        }
+
+        // Check the empty encoding.
+        check_one_hot(&format!("{mode} empty"), &[], mode);
+    }
+
+    #[test]
+    fn test_one_hot_constant() {
+        run_test(Mode::Constant);
+    }
+
+    #[test]
+    fn test_one_hot_public() {
+        run_test(Mode::Public);
+    }
+
+    #[test]
+    fn test_one_hot_private() {
+        run_test(Mode::Private);
+    }
+}
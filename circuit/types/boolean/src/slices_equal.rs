@@ -0,0 +1,201 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> SlicesEqual for Boolean<E> {
+    type Output = Boolean<E>;
+
+    /// Returns `true` if `a` and `b` are equal at every position.
+    /// This method halts if `a` and `b` differ in length.
+    fn slices_equal(a: &[Self], b: &[Self]) -> Self::Output {
+        // Ensure the slices are of equal length.
+        if a.len() != b.len() {
+            E::halt(format!("Cannot compare bitstrings of different lengths ({} and {})", a.len(), b.len()))
+        }
+
+        // Compute the XNOR of each position, and AND the results.
+        a.iter().zip_eq(b).fold(Boolean::constant(true), |is_equal, (a, b)| is_equal & a.is_equal(b))
+    }
+}
+
+impl<E: Environment> Metrics<dyn SlicesEqual<Output = Boolean<E>>> for Boolean<E> {
+    type Case = (Vec<Mode>, Vec<Mode>);
+
+    /// Note: The count assumes the constant positions are equal. Otherwise, the result is the constant `false`,
+    /// and the count is an upper bound.
+    fn count(case: &Self::Case) -> Count {
+        let (modes_a, modes_b) = case;
+
+        // Each position is free if either bit is constant, and costs one constraint for its XOR otherwise.
+        let num_xors = modes_a.iter().zip_eq(modes_b).filter(|(a, b)| !a.is_constant() && !b.is_constant()).count();
+
+        // Each variable position after the first costs one constraint for its AND.
+        let num_variable = modes_a.iter().zip_eq(modes_b).filter(|(a, b)| !a.is_constant() || !b.is_constant()).count();
+        let num_ands = num_variable.saturating_sub(1);
+
+        let num_constraints = (num_xors + num_ands) as u64;
+        Count::is(0, 0, num_constraints, num_constraints)
+    }
+}
+
+impl<E: Environment> OutputMode<dyn SlicesEqual<Output = Boolean<E>>> for Boolean<E> {
+    type Case = (Vec<CircuitType<Boolean<E>>>, Vec<CircuitType<Boolean<E>>>);
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        let (a, b) = case;
+
+        // If any pair of constant positions differ, the output is the constant `false`.
+        let is_constant_mismatch = a.iter().zip_eq(b).any(|(a, b)| match (a, b) {
+            (CircuitType::Constant(a), CircuitType::Constant(b)) => a.eject_value() != b.eject_value(),
+            _ => false,
+        });
+        if is_constant_mismatch {
+            return Mode::Constant;
+        }
+
+        // Determine the mode of the XNOR of each variable position.
+        let mut variable_modes = a.iter().zip_eq(b).filter_map(|(a, b)| match (a, b) {
+            (CircuitType::Constant(_), CircuitType::Constant(_)) => None,
+            // Note: XNOR with the constant `true` returns the variable as is, and negates it otherwise.
+            (CircuitType::Constant(constant), variable) | (variable, CircuitType::Constant(constant)) => {
+                match constant.eject_value() {
+                    true => Some(variable.mode()),
+                    false => Some(Mode::Private),
+                }
+            }
+            (_, _) => Some(Mode::Private),
+        });
+
+        // A single variable position is returned as is, while multiple variable positions are ANDed together.
+        match (variable_modes.next(), variable_modes.next()) {
+            (None, _) => Mode::Constant,
+            (Some(mode), None) => mode,
+            (Some(_), Some(_)) => Mode::Private,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 32;
+
+    fn check_slices_equal(name: &str, expected: bool, a: &[bool], b: &[bool], mode_a: Mode, mode_b: Mode) {
+        let circuit_a = a.iter().map(|bit| Boolean::<Circuit>::new(mode_a, *bit)).collect::<Vec<_>>();
+        let circuit_b = b.iter().map(|bit| Boolean::<Circuit>::new(mode_b, *bit)).collect::<Vec<_>>();
+
+        Circuit::scope(name, || {
+            let candidate = Boolean::slices_equal(&circuit_a, &circuit_b);
+            assert_eq!(expected, candidate.eject_value());
+
+            let case = (vec![mode_a; a.len()], vec![mode_b; b.len()]);
+            assert_count!(Boolean<Circuit>, SlicesEqual<Output = Boolean<Circuit>>, &case);
+
+            let case = (
+                circuit_a.iter().map(CircuitType::from).collect::<Vec<_>>(),
+                circuit_b.iter().map(CircuitType::from).collect::<Vec<_>>(),
+            );
+            assert_output_mode!(Boolean<Circuit>, SlicesEqual<Output = Boolean<Circuit>>, &case, candidate);
+        });
+        Circuit::reset();
+    }
+
+    fn run_test(mode_a: Mode, mode_b: Mode) {
+        for num_bits in [0, 1, 2, 8, 64] {
+            for i in 0..ITERATIONS {
+                // Construct a pseudorandom bitstring.
+                let a = (0..num_bits).map(|j| (i as usize * 7 + j * 3) % 5 < 2).collect::<Vec<_>>();
+
+                // Check equal bitstrings.
+                check_slices_equal(
+                    &format!("SlicesEqual {mode_a} {mode_b} {num_bits} {i}"),
+                    true,
+                    &a,
+                    &a,
+                    mode_a,
+                    mode_b,
+                );
+
+                // Check bitstrings differing in a single bit.
+                // Note: A differing constant position yields the constant `false`, so mixed modes are skipped.
+                if num_bits > 0 && (mode_a.is_constant() == mode_b.is_constant()) {
+                    let mut b = a.clone();
+                    let index = i as usize % num_bits;
+                    b[index] = !b[index];
+                    let name = format!("SlicesEqual {mode_a} {mode_b} {num_bits} {i} (index = {index})");
+                    check_slices_equal(&name, false, &a, &b, mode_a, mode_b);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_slices_equal_constant_constant() {
+        run_test(Mode::Constant, Mode::Constant);
+    }
+
+    #[test]
+    fn test_slices_equal_constant_public() {
+        run_test(Mode::Constant, Mode::Public);
+    }
+
+    #[test]
+    fn test_slices_equal_constant_private() {
+        run_test(Mode::Constant, Mode::Private);
+    }
+
+    #[test]
+    fn test_slices_equal_public_constant() {
+        run_test(Mode::Public, Mode::Constant);
+    }
+
+    #[test]
+    fn test_slices_equal_public_public() {
+        run_test(Mode::Public, Mode::Public);
+    }
+
+    #[test]
+    fn test_slices_equal_public_private() {
+        run_test(Mode::Public, Mode::Private);
+    }
+
+    #[test]
+    fn test_slices_equal_private_constant() {
+        run_test(Mode::Private, Mode::Constant);
+    }
+
+    #[test]
+    fn test_slices_equal_private_public() {
+        run_test(Mode::Private, Mode::Public);
+    }
+
+    #[test]
+    fn test_slices_equal_private_private() {
+        run_test(Mode::Private, Mode::Private);
+    }
+
+    #[test]
+    fn test_slices_equal_fails_on_length_mismatch() {
+        let a = vec![Boolean::<Circuit>::new(Mode::Private, true); 4];
+        let b = vec![Boolean::<Circuit>::new(Mode::Private, true); 3];
+        let result = std::panic::catch_unwind(|| Boolean::slices_equal(&a, &b));
+        assert!(result.is_err());
+        Circuit::reset();
+    }
+}
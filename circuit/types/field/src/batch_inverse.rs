@@ -0,0 +1,126 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+use super::*;
+
+impl<E: Environment> BatchInverse for Field<E> {
+    type Output = Field<E>;
+
+    /// Returns the inverse of each value, halting if a constant value is zero.
+    /// Note: Each inverse costs a single constraint, so Montgomery's batching trick,
+    /// which trades inversions for multiplications, would only increase the cost in R1CS.
+    fn batch_inverse(values: &[Self]) -> Vec<Self::Output> {
+        values.iter().map(Inverse::inverse).collect()
+    }
+}
+
+impl<E: Environment> Metrics<dyn BatchInverse<Output = Field<E>>> for Field<E> {
+    type Case = Vec<Mode>;
+
+    fn count(case: &Self::Case) -> Count {
+        case.iter()
+            .fold(Count::zero(), |cumulative, mode| cumulative + count!(Field<E>, Inverse<Output = Field<E>>, mode))
+    }
+}
+
+impl<E: Environment> OutputMode<dyn BatchInverse<Output = Field<E>>> for Field<E> {
+    type Case = Vec<Mode>;
+
+    /// Returns the mode of the inverses, which is constant only if every value is constant.
+    fn output_mode(case: &Self::Case) -> Mode {
+        match case.iter().all(|mode| mode.is_constant()) {
+            true => Mode::Constant,
+            false => Mode::Private,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 100;
+
+    fn check_batch_inverse(name: &str, modes: &[Mode], rng: &mut TestRng) {
+        for _ in 0..ITERATIONS {
+            // Sample random nonzero elements, and compute their inverses natively.
+            let given = modes
+                .iter()
+                .map(|_| {
+                    loop {
+                        let element: console::Field<<Circuit as Environment>::Network> = Uniform::rand(rng);
+                        if !element.is_zero() {
+                            break element;
+                        }
+                    }
+                })
+                .collect::<Vec<_>>();
+            let expected = given.iter().map(|element| element.inverse().unwrap()).collect::<Vec<_>>();
+
+            let candidates = modes
+                .iter()
+                .zip_eq(&given)
+                .map(|(mode, element)| Field::<Circuit>::new(*mode, *element))
+                .collect::<Vec<_>>();
+
+            let case = modes.to_vec();
+            Circuit::scope(name, || {
+                let results = Field::batch_inverse(&candidates);
+                assert_eq!(expected, results.eject_value());
+                assert_count!(BatchInverse(Field) => Field, &case);
+                for result in &results {
+                    // Note: A batch with mixed modes has constant inverses for its constant values.
+                    if result.is_constant() == case.iter().all(|mode| mode.is_constant()) {
+                        assert_output_mode!(BatchInverse(Field) => Field, &case, result);
+                    }
+                }
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_batch_inverse() {
+        let mut rng = TestRng::default();
+
+        check_batch_inverse("Empty", &[], &mut rng);
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            check_batch_inverse(&format!("{mode}"), &[mode], &mut rng);
+            check_batch_inverse(&format!("{mode} x 4"), &[mode; 4], &mut rng);
+        }
+        check_batch_inverse("Mixed", &[Mode::Constant, Mode::Public, Mode::Private, Mode::Constant], &mut rng);
+    }
+
+    #[test]
+    fn test_batch_inverse_of_zero() {
+        let one = console::Field::<<Circuit as Environment>::Network>::one();
+        let zero = console::Field::<<Circuit as Environment>::Network>::zero();
+
+        // A constant zero halts.
+        let result = std::panic::catch_unwind(|| {
+            Field::batch_inverse(&[Field::<Circuit>::new(Mode::Private, one), Field::<Circuit>::constant(zero)])
+        });
+        assert!(result.is_err());
+        Circuit::reset();
+
+        // A variable zero is not satisfied.
+        let candidates = [Field::<Circuit>::new(Mode::Constant, one), Field::<Circuit>::new(Mode::Private, zero)];
+        let results = Field::batch_inverse(&candidates);
+        assert_eq!(vec![one, zero], results.eject_value());
+        assert!(!Circuit::is_satisfied());
+        Circuit::reset();
+    }
+}
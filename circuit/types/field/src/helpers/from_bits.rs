@@ -46,13 +46,8 @@ impl<E: Environment> FromBits for Field<E> {
             // and `bits_le` is greater than `size_in_data_bits`, it is safe to truncate `bits_le` to `size_in_bits`.
             let bits_le = &bits_le[..size_in_bits];
 
-            // Compute `!((BaseField::MODULUS - 1) < bits_le)`, which is equivalent to `bits_le < BaseField::MODULUS`.
-            let is_less_than_modulus = !modulus_minus_one.to_bits_le().iter().zip_eq(bits_le).fold(
-                Boolean::constant(false),
-                |rest_is_less, (this, that)| {
-                    if *this { that.bitand(&rest_is_less) } else { that.bitor(&rest_is_less) }
-                },
-            );
+            // Compute `bits_le <= BaseField::MODULUS - 1`, which is equivalent to `bits_le < BaseField::MODULUS`.
+            let is_less_than_modulus = is_less_than_or_equal_constant(bits_le, &modulus_minus_one.to_bits_le());
 
             // Ensure the field element is less than `BaseField::MODULUS`.
             E::assert(is_less_than_modulus);
@@ -93,8 +88,36 @@ impl<E: Environment> FromBits for Field<E> {
 impl<E: Environment> Metrics<dyn FromBits<Boolean = Boolean<E>>> for Field<E> {
     type Case = Vec<Mode>;
 
-    fn count(_modes: &Self::Case) -> Count {
-        todo!()
+    fn count(case: &Self::Case) -> Count {
+        // Retrieve the data and base field size.
+        let size_in_data_bits = E::BaseField::size_in_data_bits();
+        let size_in_bits = E::BaseField::size_in_bits();
+
+        // Split the modes into the bits that are packed and the excess bits.
+        let (packed, excess) = case.split_at(case.len().min(size_in_bits));
+
+        // Compute the cost of ensuring the excess bits are zero.
+        // The first non-constant excess bit is free, and each subsequent one costs an `OR`.
+        let excess_count = match excess.iter().filter(|mode| !mode.is_constant()).count() as u64 {
+            0 => Count::zero(),
+            num_variable => Count::is(0, 0, num_variable - 1, num_variable),
+        };
+
+        // Compute the cost of ensuring the packed bits are less than the modulus.
+        // The cost depends on the values of any constant bits, so a mix of modes is only bounded.
+        let modulus_count = match case.len() > size_in_data_bits {
+            false => Count::zero(),
+            true => {
+                match (packed.iter().all(|mode| mode.is_constant()), packed.iter().any(|mode| mode.is_constant())) {
+                    (true, _) => Count::zero(),
+                    (false, false) => Count::is(0, 0, size_in_bits as u64 - 1, size_in_bits as u64),
+                    (false, true) => Count::less_than(0, 0, size_in_bits as u64 - 1, size_in_bits as u64),
+                }
+            }
+        };
+
+        // Note: Reconstructing the field element is a linear combination, and is free.
+        excess_count + modulus_count
     }
 }
 
@@ -104,6 +127,8 @@ impl<E: Environment> OutputMode<dyn FromBits<Boolean = Boolean<E>>> for Field<E>
     fn output_mode(case: &Self::Case) -> Mode {
         match case.iter().all(|mode| mode.is_constant()) {
             true => Mode::Constant,
+            // Note: A single bit is reconstructed as itself.
+            false if case.len() == 1 => case[0],
             false => Mode::Private,
         }
     }
@@ -196,6 +221,86 @@ mod tests {
         }
     }
 
+    fn check_from_bits_le_count(modes: Vec<Mode>, rng: &mut TestRng) {
+        // Sample random bits that fit into the data bits, padded with zeros.
+        let size_in_data_bits = <Circuit as Environment>::BaseField::size_in_data_bits();
+        let values = (0..modes.len()).map(|i| i < size_in_data_bits && bool::rand(rng)).collect::<Vec<_>>();
+        let bits = modes.iter().zip_eq(&values).map(|(mode, value)| Boolean::new(*mode, *value)).collect::<Vec<_>>();
+
+        // Compute the expected field element.
+        let expected =
+            values.iter().rev().fold(console::Field::<<Circuit as Environment>::Network>::zero(), |acc, bit| {
+                acc.double() + if *bit { console::Field::one() } else { console::Field::zero() }
+            });
+
+        Circuit::scope(format!("FromBits {} bits", modes.len()), || {
+            let candidate = Field::<Circuit>::from_bits_le(&bits);
+            assert_eq!(expected, candidate.eject_value());
+            assert_count!(FromBits<Boolean>() => Field, &modes);
+            assert_output_mode!(FromBits<Boolean>() => Field, &modes, candidate);
+            assert!(Circuit::is_satisfied_in_scope());
+        });
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_from_bits_le_count() {
+        let mut rng = TestRng::default();
+        let size_in_bits = <Circuit as Environment>::BaseField::size_in_bits();
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            // Check inputs under, at, and over the field capacity.
+            for num_bits in [0, 1, 64, size_in_bits - 1, size_in_bits, size_in_bits + 1, size_in_bits + 8] {
+                check_from_bits_le_count(vec![mode; num_bits], &mut rng);
+            }
+            // Check inputs with constant excess bits.
+            let modes = [vec![mode; size_in_bits], vec![Mode::Constant; 4]].concat();
+            check_from_bits_le_count(modes, &mut rng);
+        }
+    }
+
+    #[test]
+    fn test_from_bits_le_round_trip() {
+        let mut rng = TestRng::default();
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            for _ in 0..ITERATIONS {
+                let expected = Field::<Circuit>::new(mode, Uniform::rand(&mut rng));
+                assert_eq!(expected.eject_value(), Field::from_bits_le(&expected.to_bits_le()).eject_value());
+                assert_eq!(expected.eject_value(), Field::from_bits_be(&expected.to_bits_be()).eject_value());
+            }
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_from_bits_le_over_capacity_is_not_satisfied() {
+        let size_in_bits = <Circuit as Environment>::BaseField::size_in_bits();
+
+        for mode in [Mode::Public, Mode::Private] {
+            // Set a bit beyond the field capacity.
+            let mut bits = vec![Boolean::<Circuit>::new(mode, false); size_in_bits + 1];
+            bits[size_in_bits] = Boolean::new(mode, true);
+
+            let _candidate = Field::<Circuit>::from_bits_le(&bits);
+            assert!(!Circuit::is_satisfied());
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Constant constraint failed")]
+    fn test_from_bits_le_over_capacity_constant_halts() {
+        let size_in_bits = <Circuit as Environment>::BaseField::size_in_bits();
+
+        // Set a bit beyond the field capacity.
+        let mut bits = vec![Boolean::<Circuit>::constant(false); size_in_bits + 1];
+        bits[size_in_bits] = Boolean::constant(true);
+
+        let _candidate = Field::<Circuit>::from_bits_le(&bits);
+    }
+
     #[test]
     fn test_from_bits_le_constant() {
         check_from_bits_le(Mode::Constant, 0, 0, 0, 0);
@@ -0,0 +1,136 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> FromBitsMod for Field<E> {
+    type Boolean = Boolean<E>;
+
+    /// Initializes a new base field element from an arbitrary number of **little-endian** bits,
+    /// by interpreting the bits as an integer and reducing it modulo the base field order.
+    ///
+    /// The reduction is a linear combination with the coefficients `2^i mod p`, and does not incur constraints.
+    /// Unlike `from_bits_le`, there is no bound on the number of bits, and no canonicity check is enforced.
+    ///
+    /// If the `n` bits are sampled uniformly at random, the output is within statistical distance `p / 2^n`
+    /// of the uniform distribution over the field. For example, reducing 512 bits into a 253-bit field
+    /// has a bias of less than `2^-259`. To obtain a negligible bias, use at least 128 more bits than the field size.
+    fn from_bits_le_mod(bits_le: &[Self::Boolean]) -> Self {
+        // Reconstruct the bits as a linear combination, reducing each coefficient modulo the base field order.
+        // `output` := (2^i * b_i + ... + 2^0 * b_0) mod p
+        let mut output = Field::zero();
+        let mut coefficient = Field::one();
+        for bit in bits_le {
+            output += Field::from_boolean(bit) * &coefficient;
+            coefficient = coefficient.double();
+        }
+        output
+    }
+}
+
+impl<E: Environment> Metrics<dyn FromBitsMod<Boolean = Boolean<E>>> for Field<E> {
+    type Case = Vec<Mode>;
+
+    fn count(_case: &Self::Case) -> Count {
+        Count::is(0, 0, 0, 0)
+    }
+}
+
+impl<E: Environment> OutputMode<dyn FromBitsMod<Boolean = Boolean<E>>> for Field<E> {
+    type Case = Vec<Mode>;
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        match case.iter().all(|mode| mode.is_constant()) {
+            true => Mode::Constant,
+            // Note: A single bit is reconstructed as itself.
+            false if case.len() == 1 => case[0],
+            false => Mode::Private,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 25;
+
+    fn check_from_bits_le_mod(mode: Mode, num_bytes: usize) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample random bytes, and compute the expected reduction natively.
+            let bytes = (0..num_bytes).map(|_| u8::rand(&mut rng)).collect::<Vec<_>>();
+            let expected = console::Field::<<Circuit as Environment>::Network>::new(
+                <Circuit as Environment>::BaseField::from_bytes_le_mod_order(&bytes),
+            );
+
+            // Inject the bits.
+            let bits_le = bytes.iter().flat_map(|byte| (0..8).map(move |j| (byte >> j) & 1 == 1)).collect::<Vec<_>>();
+            let given_bits: Vec<Boolean<Circuit>> = Inject::new(mode, bits_le);
+
+            Circuit::scope(format!("{mode} {num_bytes} {i}"), || {
+                let candidate = Field::<Circuit>::from_bits_le_mod(&given_bits);
+                assert_eq!(expected, candidate.eject_value());
+
+                let modes = vec![mode; num_bytes * 8];
+                assert_count!(FromBitsMod<Boolean>() => Field, &modes);
+                assert_output_mode!(FromBitsMod<Boolean>() => Field, &modes, candidate);
+
+                // Ensure the output is in canonical form when decomposed into bits.
+                assert_eq!(expected, Field::<Circuit>::from_bits_le(&candidate.to_bits_le()).eject_value());
+            });
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+    }
+
+    fn run_test(mode: Mode) {
+        for num_bytes in [0, 1, 16, 31, 32, 48, 64, 128] {
+            check_from_bits_le_mod(mode, num_bytes);
+        }
+    }
+
+    #[test]
+    fn test_from_bits_le_mod_constant() {
+        run_test(Mode::Constant);
+    }
+
+    #[test]
+    fn test_from_bits_le_mod_public() {
+        run_test(Mode::Public);
+    }
+
+    #[test]
+    fn test_from_bits_le_mod_private() {
+        run_test(Mode::Private);
+    }
+
+    #[test]
+    fn test_from_bits_le_mod_matches_from_bits_le() {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Ensure the reduction is the identity on canonical bits.
+            let expected = Field::<Circuit>::new(Mode::Private, Uniform::rand(&mut rng));
+            let candidate = Field::<Circuit>::from_bits_le_mod(&expected.to_bits_le());
+            assert_eq!(expected.eject_value(), candidate.eject_value());
+        }
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+}
@@ -0,0 +1,55 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, U8: IntegerCore<u8> + ToBits<Boolean = Boolean<E>>> FromFieldBytes<U8> for Field<E> {
+    /// Returns the field element from the given little-endian bytes.
+    /// This method enforces that the bytes are the canonical encoding of a field element,
+    /// i.e. the excess bits are zero, and the value is less than the field modulus.
+    fn from_bytes_le(bytes_le: &[U8]) -> Self {
+        // Ensure the number of bytes matches the encoding size.
+        let num_bytes = E::BaseField::size_in_bits().div_ceil(8);
+        if bytes_le.len() != num_bytes {
+            E::halt(format!("Expected {num_bytes} bytes for a field element, found {}", bytes_le.len()))
+        }
+
+        // Unpack the bytes into bits, and reconstruct the field element.
+        Field::from_bits_le(&bytes_le.iter().flat_map(|byte| byte.to_bits_le()).collect::<Vec<_>>())
+    }
+}
+
+impl<E: Environment, U8: IntegerCore<u8>> Metrics<dyn FromFieldBytes<U8>> for Field<E> {
+    type Case = Vec<Mode>;
+
+    fn count(case: &Self::Case) -> Count {
+        // The cost of enforcing that the excess bits are zero, and that the value is less than the modulus;
+        // the unpacking is free, and each bit inherits the mode of its byte.
+        let bit_modes = case.iter().flat_map(|mode| [*mode; 8]).collect::<Vec<_>>();
+        count!(Field<E>, FromBits<Boolean = Boolean<E>>, &bit_modes)
+    }
+}
+
+impl<E: Environment, U8: IntegerCore<u8>> OutputMode<dyn FromFieldBytes<U8>> for Field<E> {
+    type Case = Vec<Mode>;
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        match case.iter().all(|mode| mode.is_constant()) {
+            true => Mode::Constant,
+            false => Mode::Private,
+        }
+    }
+}
@@ -17,9 +17,12 @@
 use super::*;
 
 pub mod from_bits;
+pub mod from_bits_mod;
 pub mod from_boolean;
+pub mod from_bytes;
 pub mod one;
 pub mod to_bits;
+pub mod to_bytes;
 pub mod to_lower_bits;
 pub mod to_upper_bits;
 pub mod zero;
@@ -0,0 +1,46 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, U8: IntegerCore<u8> + FromBits<Boolean = Boolean<E>>> ToFieldBytes<U8> for Field<E> {
+    /// Returns the little-endian bytes of the field element, zero-padded up to a whole number of bytes.
+    fn to_bytes_le(&self) -> Vec<U8> {
+        // Retrieve the little-endian bits, and pad them up to a multiple of 8.
+        let mut bits_le = self.to_bits_le();
+        bits_le.resize(E::BaseField::size_in_bits().div_ceil(8) * 8, Boolean::constant(false));
+
+        // Pack the bits into bytes.
+        bits_le.chunks(8).map(U8::from_bits_le).collect()
+    }
+}
+
+impl<E: Environment, U8: IntegerCore<u8>> Metrics<dyn ToFieldBytes<U8>> for Field<E> {
+    type Case = Mode;
+
+    fn count(case: &Self::Case) -> Count {
+        // The cost of converting the field element into its little-endian bits; the padding and packing are free.
+        count!(Field<E>, ToBits<Boolean = Boolean<E>>, case)
+    }
+}
+
+impl<E: Environment, U8: IntegerCore<u8>> OutputMode<dyn ToFieldBytes<U8>> for Field<E> {
+    type Case = Mode;
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        output_mode!(Field<E>, ToBits<Boolean = Boolean<E>>, case)
+    }
+}
@@ -21,6 +21,7 @@
 mod helpers;
 
 pub mod add;
+pub mod batch_inverse;
 pub mod compare;
 pub mod div;
 pub mod div_unchecked;
@@ -30,6 +31,7 @@ pub mod inverse;
 pub mod mul;
 pub mod neg;
 pub mod pow;
+pub mod select_index;
 pub mod square;
 pub mod square_root;
 pub mod sub;
@@ -41,7 +43,7 @@ use console::{TestRng, Uniform};
 use snarkvm_circuit_environment::{assert_count, assert_output_mode, assert_scope, count, output_mode};
 
 use snarkvm_circuit_environment::prelude::*;
-use snarkvm_circuit_types_boolean::Boolean;
+use snarkvm_circuit_types_boolean::{is_less_than_or_equal_constant, Boolean};
 
 #[derive(Clone)]
 pub struct Field<E: Environment> {
@@ -19,6 +19,8 @@ use super::*;
 impl<E: Environment> Double for Group<E> {
     type Output = Group<E>;
 
+    /// Returns `2 * self`, using the dedicated twisted Edwards doubling formula.
+    /// Note: This is cheaper than `self + self`, as it requires fewer multiplications.
     fn double(&self) -> Self::Output {
         // If `self` is constant *and* `self` is zero, then return `self`.
         if self.is_constant() && self.eject_value().is_zero() {
@@ -62,6 +64,29 @@ impl<E: Environment> Double for Group<E> {
     }
 }
 
+impl<E: Environment> Metrics<dyn Double<Output = Group<E>>> for Group<E> {
+    type Case = Mode;
+
+    fn count(case: &Self::Case) -> Count {
+        match case.is_constant() {
+            // Doubling a constant zero is free.
+            true => Count::less_than(3, 0, 0, 0),
+            false => Count::is(1, 0, 5, 5),
+        }
+    }
+}
+
+impl<E: Environment> OutputMode<dyn Double<Output = Group<E>>> for Group<E> {
+    type Case = Mode;
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        match case.is_constant() {
+            true => Mode::Constant,
+            false => Mode::Private,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -85,6 +110,7 @@ mod tests {
                 let candidate = affine.double();
                 assert_eq!(expected, candidate.eject_value());
                 assert_scope!(3, 0, 0, 0);
+                assert_count!(Double(Group) => Group, &Mode::Constant);
             });
             Circuit::reset();
 
@@ -95,6 +121,7 @@ mod tests {
                 let candidate = affine.double();
                 assert_eq!(expected, candidate.eject_value());
                 assert_scope!(1, 0, 5, 5);
+                assert_count!(Double(Group) => Group, &Mode::Public);
             });
             Circuit::reset();
 
@@ -105,11 +132,57 @@ mod tests {
                 let candidate = affine.double();
                 assert_eq!(expected, candidate.eject_value());
                 assert_scope!(1, 0, 5, 5);
+                assert_count!(Double(Group) => Group, &Mode::Private);
             });
             Circuit::reset();
         }
     }
 
+    fn check_double(name: &str, expected: &console::Group<<Circuit as Environment>::Network>, a: &Group<Circuit>) {
+        Circuit::scope(name, || {
+            let candidate = a.double();
+            assert_eq!(*expected, candidate.eject_value());
+            assert_count!(Double(Group) => Group, &a.eject_mode());
+            assert_output_mode!(Double(Group) => Group, &a.eject_mode(), candidate);
+        });
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_double_special_points() {
+        // Initialize the identity and the point of order 2.
+        let zero = console::Group::<<Circuit as Environment>::Network>::zero();
+        let torsion = console::Group::from_xy_coordinates_unchecked(console::Field::zero(), -console::Field::one());
+        assert_eq!(zero, torsion + torsion);
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            check_double(&format!("Zero {mode}"), &zero, &Group::new(mode, zero));
+            check_double(&format!("Torsion {mode}"), &zero, &Group::new(mode, torsion));
+        }
+    }
+
+    #[test]
+    fn test_double_is_cheaper_than_add() {
+        let point = Uniform::rand(&mut TestRng::default());
+
+        for mode in [Mode::Public, Mode::Private] {
+            let a = Group::<Circuit>::new(mode, point);
+
+            // Count the constraints of doubling.
+            let start = Circuit::num_constraints();
+            let _ = a.double();
+            let num_double_constraints = Circuit::num_constraints() - start;
+
+            // Count the constraints of generic addition.
+            let start = Circuit::num_constraints();
+            let _ = &a + &a;
+            let num_add_constraints = Circuit::num_constraints() - start;
+
+            assert!(num_double_constraints < num_add_constraints);
+            Circuit::reset();
+        }
+    }
+
     #[test]
     fn test_double_matches() {
         // Sample two random elements.
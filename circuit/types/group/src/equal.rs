@@ -43,6 +43,32 @@ impl<E: Environment> Equal<Self> for Group<E> {
     }
 }
 
+impl<E: Environment> Metrics<dyn Equal<Group<E>, Output = Boolean<E>>> for Group<E> {
+    type Case = (Mode, Mode);
+
+    fn count(case: &Self::Case) -> Count {
+        // Compare the x-coordinates and the y-coordinates.
+        let coordinate_count = count!(Field<E>, Equal<Field<E>, Output = Boolean<E>>, case);
+        // Compute the AND of the two comparisons, which is free if both are constant.
+        let and_count = match case.0.is_constant() && case.1.is_constant() {
+            true => Count::zero(),
+            false => Count::is(0, 0, 1, 1),
+        };
+        coordinate_count.scale(2) + and_count
+    }
+}
+
+impl<E: Environment> OutputMode<dyn Equal<Group<E>, Output = Boolean<E>>> for Group<E> {
+    type Case = (Mode, Mode);
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        match case.0.is_constant() && case.1.is_constant() {
+            true => Mode::Constant,
+            false => Mode::Private,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -67,6 +93,8 @@ mod tests {
                 let equals = a.is_equal(&b);
                 assert!(!equals.eject_value());
                 assert_scope!(2, 0, 0, 0);
+                assert_count!(Equal(Group, Group) => Boolean, &(a.eject_mode(), b.eject_mode()));
+                assert_output_mode!(Equal(Group, Group) => Boolean, &(a.eject_mode(), b.eject_mode()), equals);
             });
             Circuit::reset();
 
@@ -91,6 +119,8 @@ mod tests {
                 let equals = a.is_equal(&b);
                 assert!(!equals.eject_value());
                 assert_scope!(0, 0, 5, 7);
+                assert_count!(Equal(Group, Group) => Boolean, &(a.eject_mode(), b.eject_mode()));
+                assert_output_mode!(Equal(Group, Group) => Boolean, &(a.eject_mode(), b.eject_mode()), equals);
             });
             Circuit::reset();
 
@@ -115,6 +145,8 @@ mod tests {
                 let equals = a.is_equal(&b);
                 assert!(!equals.eject_value());
                 assert_scope!(0, 0, 5, 7);
+                assert_count!(Equal(Group, Group) => Boolean, &(a.eject_mode(), b.eject_mode()));
+                assert_output_mode!(Equal(Group, Group) => Boolean, &(a.eject_mode(), b.eject_mode()), equals);
             });
             Circuit::reset();
 
@@ -139,6 +171,8 @@ mod tests {
                 let equals = a.is_equal(&b);
                 assert!(!equals.eject_value());
                 assert_scope!(0, 0, 5, 7);
+                assert_count!(Equal(Group, Group) => Boolean, &(a.eject_mode(), b.eject_mode()));
+                assert_output_mode!(Equal(Group, Group) => Boolean, &(a.eject_mode(), b.eject_mode()), equals);
             });
             Circuit::reset();
 
@@ -163,6 +197,8 @@ mod tests {
                 let equals = a.is_equal(&b);
                 assert!(!equals.eject_value());
                 assert_scope!(0, 0, 5, 7);
+                assert_count!(Equal(Group, Group) => Boolean, &(a.eject_mode(), b.eject_mode()));
+                assert_output_mode!(Equal(Group, Group) => Boolean, &(a.eject_mode(), b.eject_mode()), equals);
             });
             Circuit::reset();
 
@@ -0,0 +1,227 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// The number of scalar bits that are processed in each window.
+const WINDOW_SIZE: usize = 3;
+
+/// A precomputed table for multiplying a fixed (constant) base by many scalars.
+///
+/// The scalar is split into windows of `WINDOW_SIZE` bits, and the multiples `j * 2^{WINDOW_SIZE * i} * base`
+/// of each window `i` are precomputed at setup. Each window is then selected with a multilinear polynomial
+/// in its bits, which costs `2^{WINDOW_SIZE} - WINDOW_SIZE - 1` constraints, and the windows are summed.
+/// In comparison, the generic double-and-add path costs a doubling, an addition, and a ternary per scalar bit.
+#[derive(Clone)]
+pub struct FixedBaseMul<E: Environment> {
+    /// The coefficients of the multilinear polynomials for the `(x, y)` coordinates of each window.
+    windows: Vec<Vec<(Field<E>, Field<E>)>>,
+}
+
+impl<E: Environment> FixedBaseMul<E> {
+    /// Initializes the table of windowed multiples for the given constant base.
+    pub fn new(base: &Group<E>) -> Self {
+        // Ensure the base is a constant.
+        if !base.is_constant() {
+            E::halt("The base of a fixed-base multiplication must be a constant")
+        }
+
+        let num_bits = console::Scalar::<E::Network>::size_in_bits();
+        let mut window_base = base.eject_value();
+
+        let windows = (0..num_bits)
+            .step_by(WINDOW_SIZE)
+            .map(|start| {
+                let window_size = WINDOW_SIZE.min(num_bits - start);
+
+                // Compute the multiples `j * window_base` for each `j` in the window.
+                let mut multiples = Vec::with_capacity(1 << window_size);
+                let mut multiple = console::Group::<E::Network>::zero();
+                for _ in 0..(1 << window_size) {
+                    multiples.push((multiple.to_x_coordinate(), multiple.to_y_coordinate()));
+                    multiple += window_base;
+                }
+                // Update the base for the next window.
+                window_base = multiple;
+
+                // Compute the coefficients of the multilinear polynomial, via the Möbius transform.
+                for k in 0..window_size {
+                    for mask in 0..multiples.len() {
+                        if mask & (1 << k) != 0 {
+                            let (x, y) = multiples[mask ^ (1 << k)];
+                            multiples[mask].0 -= x;
+                            multiples[mask].1 -= y;
+                        }
+                    }
+                }
+                multiples.into_iter().map(|(x, y)| (Field::constant(x), Field::constant(y))).collect()
+            })
+            .collect();
+
+        Self { windows }
+    }
+
+    /// Returns the product of the fixed base and the given scalar.
+    pub fn mul(&self, scalar: &Scalar<E>) -> Group<E> {
+        let bits_le = scalar.to_bits_le();
+
+        bits_le
+            .chunks(WINDOW_SIZE)
+            .zip_eq(&self.windows)
+            .map(|(bits, coefficients)| {
+                // Compute the monomials of the window bits, where the monomial for `mask` is the product of its bits.
+                let mut monomials: Vec<Field<E>> = Vec::with_capacity(coefficients.len());
+                monomials.push(Field::one());
+                for mask in 1..coefficients.len() {
+                    let bit = Field::from_boolean(&bits[mask.trailing_zeros() as usize]);
+                    let rest = mask & (mask - 1);
+                    monomials.push(match rest {
+                        0 => bit,
+                        _ => &monomials[rest] * &bit,
+                    });
+                }
+
+                // Evaluate the multilinear polynomials to select the multiple.
+                let (x, y) = monomials.iter().zip_eq(coefficients).fold(
+                    (Field::zero(), Field::zero()),
+                    |(x, y), (monomial, (coefficient_x, coefficient_y))| {
+                        (x + monomial * coefficient_x, y + monomial * coefficient_y)
+                    },
+                );
+                Group::from_xy_coordinates_unchecked(x, y)
+            })
+            .reduce(|sum, point| sum + point)
+            .unwrap_or_else(Group::zero)
+    }
+}
+
+impl<E: Environment> Metrics<dyn Mul<Scalar<E>, Output = Group<E>>> for FixedBaseMul<E> {
+    type Case = Mode;
+
+    fn count(case: &Self::Case) -> Count {
+        let num_bits = console::Scalar::<E::Network>::size_in_bits() as u64;
+        let num_windows = num_bits.div_ceil(WINDOW_SIZE as u64);
+
+        match case.is_constant() {
+            true => Count::less_than(num_bits + 4 * (num_windows - 1), 0, 0, 0),
+            false => {
+                // Compute the cost of converting the scalar into bits.
+                let to_bits_count = Count::is(0, 0, num_bits, num_bits + 1);
+                // Compute the cost of the monomials of each window.
+                let last_window_size = num_bits - (num_windows - 1) * WINDOW_SIZE as u64;
+                let num_products = |window_size: u64| (1 << window_size) - window_size - 1;
+                let select_count = Count::is(
+                    0,
+                    0,
+                    (num_windows - 1) * num_products(WINDOW_SIZE as u64) + num_products(last_window_size),
+                    (num_windows - 1) * num_products(WINDOW_SIZE as u64) + num_products(last_window_size),
+                );
+                // Compute the cost of summing the windows.
+                let sum_count = count!(Group<E>, Add<Group<E>, Output = Group<E>>, &(Mode::Private, Mode::Private))
+                    .scale(num_windows as usize - 1);
+                to_bits_count + select_count + sum_count
+            }
+        }
+    }
+}
+
+impl<E: Environment> OutputMode<dyn Mul<Scalar<E>, Output = Group<E>>> for FixedBaseMul<E> {
+    type Case = Mode;
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        match case.is_constant() {
+            true => Mode::Constant,
+            false => Mode::Private,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 10;
+
+    fn check_fixed_base_mul(mode: Mode) {
+        let mut rng = TestRng::default();
+
+        // Initialize the table for a random base.
+        let base: console::Group<<Circuit as Environment>::Network> = Uniform::rand(&mut rng);
+        let table = FixedBaseMul::new(&Group::<Circuit>::constant(base));
+
+        let scalars = (0..ITERATIONS).map(|_| Uniform::rand(&mut rng)).chain([
+            console::Scalar::zero(),
+            console::Scalar::one(),
+            -console::Scalar::one(),
+        ]);
+
+        for (i, scalar) in scalars.enumerate() {
+            let expected = base * scalar;
+            let a = Group::<Circuit>::constant(base);
+            let b = Scalar::<Circuit>::new(mode, scalar);
+
+            Circuit::scope(format!("FixedBaseMul {mode} {i}"), || {
+                let candidate = table.mul(&b);
+                assert_eq!(expected, candidate.eject_value());
+                assert_count!(FixedBaseMul<Circuit>, Mul<Scalar<Circuit>, Output = Group<Circuit>>, &mode);
+                assert_output_mode!(
+                    FixedBaseMul<Circuit>,
+                    Mul<Scalar<Circuit>, Output = Group<Circuit>>,
+                    &mode,
+                    candidate
+                );
+            });
+
+            // Ensure the output matches the generic path, which is more expensive for a variable scalar.
+            Circuit::scope(format!("Mul {mode} {i}"), || {
+                let candidate = &a * &b;
+                assert_eq!(expected, candidate.eject_value());
+                if !mode.is_constant() {
+                    let fixed_base_count =
+                        count!(FixedBaseMul<Circuit>, Mul<Scalar<Circuit>, Output = Group<Circuit>>, &mode);
+                    assert!(2 * fixed_base_count.3.upper_bound() < Circuit::num_constraints_in_scope());
+                }
+            });
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_fixed_base_mul_constant() {
+        check_fixed_base_mul(Mode::Constant);
+    }
+
+    #[test]
+    fn test_fixed_base_mul_public() {
+        check_fixed_base_mul(Mode::Public);
+    }
+
+    #[test]
+    fn test_fixed_base_mul_private() {
+        check_fixed_base_mul(Mode::Private);
+    }
+
+    #[test]
+    fn test_fixed_base_mul_requires_constant_base() {
+        let mut rng = TestRng::default();
+        let base = Group::<Circuit>::new(Mode::Private, Uniform::rand(&mut rng));
+        let result = std::panic::catch_unwind(|| FixedBaseMul::new(&base));
+        assert!(result.is_err());
+        Circuit::reset();
+    }
+}
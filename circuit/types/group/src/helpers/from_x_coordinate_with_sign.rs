@@ -0,0 +1,158 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> Group<E> {
+    /// Initializes an affine group element from a given x-coordinate and sign.
+    ///
+    /// The sign selects between the two y-coordinates `y` and `-y` on the curve for the given x-coordinate,
+    /// and is `true` if and only if `y` is the lexicographically greater of the two, i.e. `y > -y`.
+    /// For safety, the resulting point is always enforced to be on the curve, which ensures `x` is a valid
+    /// x-coordinate, and the y-coordinate is enforced to match the sign.
+    /// Note: The resulting point is **not** enforced to be in the subgroup.
+    pub fn from_x_coordinate_with_sign(x: &Field<E>, sign: &Boolean<E>) -> Self {
+        // Derive the y-coordinate that matches the sign.
+        let y: Field<E> = witness!(|x, sign| match console::Group::from_x_coordinate(x) {
+            Ok(point) => {
+                let y = point.to_y_coordinate();
+                match (*y > *(-y)) == sign {
+                    true => y,
+                    false => -y,
+                }
+            }
+            Err(_) => console::Field::zero(),
+        });
+
+        // Ensure the point is on the curve.
+        let point = Self::from_xy_coordinates_unchecked(x.clone(), y);
+        point.enforce_on_curve();
+
+        // Ensure the y-coordinate matches the sign.
+        // Note: `y > -y` holds if and only if `y > (p - 1) / 2`, which only requires a comparison to a constant.
+        let half = match console::Field::<E::Network>::one().double().inverse() {
+            Ok(inverse) => Field::constant(-inverse),
+            Err(_) => E::halt("Failed to invert the field element two"),
+        };
+        E::assert_eq(point.y.is_greater_than(&half), sign);
+
+        point
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 100;
+
+    /// Returns the sign of the y-coordinate of the given point.
+    fn sign_of(point: &console::Group<<Circuit as Environment>::Network>) -> bool {
+        let y = point.to_y_coordinate();
+        *y > *(-y)
+    }
+
+    fn check_from_x_coordinate_with_sign(
+        mode: Mode,
+        num_constants: u64,
+        num_public: u64,
+        num_private: u64,
+        num_constraints: u64,
+    ) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample a random element.
+            let point: console::Group<<Circuit as Environment>::Network> = Uniform::rand(&mut rng);
+
+            // Inject the x-coordinate and sign.
+            let x_coordinate = Field::new(mode, point.to_x_coordinate());
+            let sign = Boolean::new(mode, sign_of(&point));
+
+            Circuit::scope(format!("{mode} {i}"), || {
+                let affine = Group::<Circuit>::from_x_coordinate_with_sign(&x_coordinate, &sign);
+                assert_eq!(point, affine.eject_value());
+                assert_scope!(num_constants, num_public, num_private, num_constraints);
+            });
+
+            // Ensure the round trip through the x-coordinate recovers the point.
+            let candidate = Group::<Circuit>::new(mode, point);
+            let affine = Group::<Circuit>::from_x_coordinate_with_sign(&candidate.to_x_coordinate(), &sign);
+            assert_eq!(point, affine.eject_value());
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_from_x_coordinate_with_sign_constant() {
+        check_from_x_coordinate_with_sign(Mode::Constant, 4, 0, 0, 0);
+    }
+
+    #[test]
+    fn test_from_x_coordinate_with_sign_public() {
+        check_from_x_coordinate_with_sign(Mode::Public, 256, 0, 508, 510);
+    }
+
+    #[test]
+    fn test_from_x_coordinate_with_sign_private() {
+        check_from_x_coordinate_with_sign(Mode::Private, 256, 0, 508, 510);
+    }
+
+    #[test]
+    fn test_from_x_coordinate_with_opposite_sign() {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample a random element.
+            let point: console::Group<<Circuit as Environment>::Network> = Uniform::rand(&mut rng);
+
+            // Recover the point with the opposite sign, which negates the y-coordinate.
+            let x_coordinate = Field::new(Mode::Private, point.to_x_coordinate());
+            let sign = Boolean::new(Mode::Private, !sign_of(&point));
+
+            let affine = Group::<Circuit>::from_x_coordinate_with_sign(&x_coordinate, &sign);
+            assert_eq!(point.to_x_coordinate(), affine.to_x_coordinate().eject_value());
+            assert_eq!(-point.to_y_coordinate(), affine.to_y_coordinate().eject_value());
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_from_x_coordinate_with_sign_invalid() {
+        let mut rng = TestRng::default();
+
+        let mut num_invalid = 0;
+        for _ in 0..ITERATIONS {
+            // Sample a random x-coordinate.
+            let x: console::Field<<Circuit as Environment>::Network> = Uniform::rand(&mut rng);
+            if console::Group::<<Circuit as Environment>::Network>::from_x_coordinate(x).is_ok() {
+                continue;
+            }
+            num_invalid += 1;
+
+            // Ensure an x-coordinate that is not on the curve is not satisfied.
+            let x_coordinate = Field::new(Mode::Private, x);
+            let sign = Boolean::new(Mode::Private, false);
+            let _affine = Group::<Circuit>::from_x_coordinate_with_sign(&x_coordinate, &sign);
+            assert!(!Circuit::is_satisfied());
+            Circuit::reset();
+        }
+        assert!(num_invalid > 0);
+    }
+}
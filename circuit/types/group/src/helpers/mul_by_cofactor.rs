@@ -72,6 +72,46 @@ mod tests {
         check_mul_by_cofactor(Mode::Private, 2, 0, 10, 10);
     }
 
+    /// This test shows that `mul_by_cofactor` maps curve points outside the prime-order subgroup into the subgroup.
+    #[test]
+    fn test_mul_by_cofactor_clears_cofactor() {
+        let mut rng = TestRng::default();
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            let mut num_checked = 0;
+            while num_checked < 25 {
+                // Sample a random curve point, which is not in the prime-order subgroup.
+                let x: console::Field<<Circuit as Environment>::Network> = Uniform::rand(&mut rng);
+                let point = match <Circuit as Environment>::Affine::from_x_coordinate(*x, true) {
+                    Some(point) if !point.is_in_correct_subgroup_assuming_on_curve() => point,
+                    _ => continue,
+                };
+
+                // Initialize the input.
+                let affine = Group::<Circuit>::from_xy_coordinates_unchecked(
+                    Field::new(mode, console::Field::new(point.to_x_coordinate())),
+                    Field::new(mode, console::Field::new(point.to_y_coordinate())),
+                );
+
+                // Computes `ScalarField::MODULUS * group` as `(ScalarField::MODULUS - 1) * group + group`.
+                let mul_by_order = |group: &Group<Circuit>| {
+                    group * Scalar::constant(-console::Scalar::<<Circuit as Environment>::Network>::one()) + group
+                };
+
+                // Ensure the input is not in the prime-order subgroup.
+                assert!(!mul_by_order(&affine).eject_value().is_zero());
+
+                // Ensure the output is in the prime-order subgroup.
+                let candidate = affine.mul_by_cofactor();
+                assert!(mul_by_order(&candidate).eject_value().is_zero());
+                assert!(Circuit::is_satisfied());
+                Circuit::reset();
+
+                num_checked += 1;
+            }
+        }
+    }
+
     /// This test shows that computing `mul_by_cofactor` using doubling is more cost-effective for our specific cofactor.
     #[test]
     fn test_mul_by_cofactor_matches() {
@@ -23,9 +23,13 @@ mod helpers;
 pub mod add;
 pub mod double;
 pub mod equal;
+pub mod fixed_base_mul;
+pub use fixed_base_mul::FixedBaseMul;
 pub mod mul;
 pub mod neg;
+pub mod select_index;
 pub mod sub;
+pub mod subgroup;
 pub mod ternary;
 
 #[cfg(test)]
@@ -180,6 +180,48 @@ impl<E: Environment> MulAssign<&[Boolean<E>]> for Group<E> {
     }
 }
 
+impl<E: Environment> Metrics<dyn Mul<Scalar<E>, Output = Group<E>>> for Group<E> {
+    type Case = (Mode, Mode);
+
+    /// Returns an upper bound on the cost of the multiplication, as the cost for a constant scalar depends on
+    /// its number of significant bits, and the cost for a variable scalar includes its decomposition into bits,
+    /// which is skipped if the bits are already cached.
+    fn count(case: &Self::Case) -> Count {
+        let num_bits = E::ScalarField::size_in_bits() as u64;
+        match case {
+            // Each bit after the most significant bit costs a DOUBLE and an ADD, and each bit is a constant.
+            (Mode::Constant, Mode::Constant) => Count::less_than(7 * (num_bits - 1) + num_bits, 0, 0, 0),
+            (_, Mode::Constant) => {
+                Count::less_than(3 * (num_bits - 1) + num_bits, 0, 11 * (num_bits - 1), 11 * (num_bits - 1))
+            }
+            // Each bit after the most significant bit costs a DOUBLE, an ADD, and a TERNARY, and each bit is private.
+            (Mode::Constant, _) => Count::less_than(
+                3 * (num_bits - 1),
+                0,
+                10 * (num_bits - 1) + num_bits,
+                10 * (num_bits - 1) + num_bits + 1,
+            ),
+            (_, _) => Count::less_than(
+                3 * (num_bits - 1),
+                0,
+                13 * (num_bits - 1) + 2 + num_bits,
+                13 * (num_bits - 1) + 2 + num_bits + 1,
+            ),
+        }
+    }
+}
+
+impl<E: Environment> OutputMode<dyn Mul<Scalar<E>, Output = Group<E>>> for Group<E> {
+    type Case = (Mode, Mode);
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        match case {
+            (Mode::Constant, Mode::Constant) => Mode::Constant,
+            (_, _) => Mode::Private,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -201,6 +243,8 @@ mod tests {
             let candidate = a * b;
             assert_eq!(*expected, candidate.eject_value(), "({} * {})", a.eject_value(), b.eject_value());
             assert_scope!(<=num_constants, num_public, num_private, num_constraints);
+            assert_count!(Mul(Group, Scalar) => Group, &(a.eject_mode(), b.eject_mode()));
+            assert_output_mode!(Mul(Group, Scalar) => Group, &(a.eject_mode(), b.eject_mode()), candidate);
         });
         Circuit::reset();
     }
@@ -220,6 +264,7 @@ mod tests {
             candidate *= b;
             assert_eq!(*expected, candidate.eject_value(), "({} * {})", a.eject_value(), b.eject_value());
             assert_scope!(<=num_constants, num_public, num_private, num_constraints);
+            assert_count!(Mul(Group, Scalar) => Group, &(a.eject_mode(), b.eject_mode()));
         });
         Circuit::reset();
     }
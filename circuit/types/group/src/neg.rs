@@ -116,6 +116,46 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_add_neg_is_zero() {
+        let mut rng = TestRng::default();
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            for i in 0..ITERATIONS {
+                // Sample a random element.
+                let point: console::Group<_> = Uniform::rand(&mut rng);
+
+                Circuit::scope(format!("ADD NEG {mode} {i}"), || {
+                    let a = Group::<Circuit>::new(mode, point);
+                    let candidate = &a + &(-&a);
+                    assert_eq!(console::Group::zero(), candidate.eject_value());
+                });
+                Circuit::reset();
+            }
+        }
+    }
+
+    #[test]
+    fn test_double_neg() {
+        let mut rng = TestRng::default();
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            for i in 0..ITERATIONS {
+                // Sample a random element.
+                let point: console::Group<_> = Uniform::rand(&mut rng);
+
+                let a = Group::<Circuit>::new(mode, point);
+
+                Circuit::scope(format!("NEG NEG {mode} {i}"), || {
+                    let candidate = -(-a);
+                    assert_eq!(point, candidate.eject_value());
+                    assert_scope!(0, 0, 0, 0);
+                });
+                Circuit::reset();
+            }
+        }
+    }
+
     #[test]
     fn test_zero() {
         let expected = console::Group::<<Circuit as Environment>::Network>::zero();
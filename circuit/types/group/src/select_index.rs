@@ -0,0 +1,180 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> SelectIndex for Group<E> {
+    type Boolean = Boolean<E>;
+
+    /// Returns `table[index]`, where `index` is given by its little-endian bits.
+    /// The selection is computed as a balanced tree of `table.len() - 1` ternaries,
+    /// so the number of constraints is independent of the index.
+    fn select_index(index_bits: &[Self::Boolean], table: &[Self]) -> Self {
+        // Ensure the table contains exactly one element for each index.
+        match 1usize.checked_shl(index_bits.len() as u32) {
+            Some(num_entries) if num_entries == table.len() => (),
+            _ => E::halt(format!("The table must contain 2^{} elements, found {}", index_bits.len(), table.len())),
+        }
+
+        // Reduce the table by one level for each index bit, starting from the least significant bit.
+        let mut level = table.to_vec();
+        for bit in index_bits {
+            level = level.chunks(2).map(|pair| Self::ternary(bit, &pair[1], &pair[0])).collect();
+        }
+
+        // Return the selected element.
+        match level.pop() {
+            Some(element) => element,
+            // Note: `E::halt` should never be invoked as the table is non-empty.
+            None => E::halt("Malformed table detected during index selection"),
+        }
+    }
+}
+
+impl<E: Environment> Metrics<dyn SelectIndex<Boolean = Boolean<E>>> for Group<E> {
+    type Case = (Vec<Mode>, Vec<Mode>);
+
+    fn count(case: &Self::Case) -> Count {
+        let (index_modes, table_modes) = case;
+
+        // Compute the cost of each ternary in the tree, level by level.
+        let (_, count) =
+            index_modes.iter().fold((table_modes.clone(), Count::zero()), |(level, cumulative), bit_mode| {
+                let next_level = level
+                    .chunks(2)
+                    .map(|pair| {
+                        let count = count!(
+                            Group<E>,
+                            Ternary<Boolean = Boolean<E>, Output = Group<E>>,
+                            &(*bit_mode, pair[1], pair[0])
+                        );
+                        // Note: A constant condition selects one of the two inputs, so their modes are combined
+                        // instead of using the `output_mode` macro, as the index is not required to be known.
+                        let mode = match bit_mode.is_constant() {
+                            true => Mode::combine(pair[0], [pair[1]]),
+                            false => Mode::Private,
+                        };
+                        (mode, count)
+                    })
+                    .collect::<Vec<_>>();
+                let level_count = next_level.iter().fold(Count::zero(), |cumulative, (_, count)| cumulative + *count);
+                (next_level.into_iter().map(|(mode, _)| mode).collect(), cumulative + level_count)
+            });
+
+        count
+    }
+}
+
+impl<E: Environment> OutputMode<dyn SelectIndex<Boolean = Boolean<E>>> for Group<E> {
+    type Case = (Vec<Mode>, Vec<Mode>);
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        let (index_modes, table_modes) = case;
+        match index_modes.iter().all(|mode| mode.is_constant()) {
+            true => Mode::combine(Mode::Constant, table_modes.iter().copied()),
+            false => Mode::Private,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    fn check_select_index(num_index_bits: usize, mode_index: Mode, mode_table: Mode) {
+        let mut rng = TestRng::default();
+
+        // Sample a random table.
+        let table = (0..1 << num_index_bits).map(|_| Uniform::rand(&mut rng)).collect::<Vec<console::Group<_>>>();
+        let circuit_table = table.iter().map(|value| Group::<Circuit>::new(mode_table, *value)).collect::<Vec<_>>();
+
+        for (index, expected) in table.iter().enumerate() {
+            // Initialize the index bits.
+            let index_bits = (0..num_index_bits)
+                .map(|i| Boolean::<Circuit>::new(mode_index, (index >> i) & 1 == 1))
+                .collect::<Vec<_>>();
+
+            Circuit::scope(format!("SelectIndex {mode_index} {mode_table} {index}"), || {
+                let candidate = Group::select_index(&index_bits, &circuit_table);
+                assert_eq!(*expected, candidate.eject_value());
+
+                let case = (vec![mode_index; num_index_bits], vec![mode_table; table.len()]);
+                assert_count!(Group<Circuit>, SelectIndex<Boolean = Boolean<Circuit>>, &case);
+                assert_output_mode!(Group<Circuit>, SelectIndex<Boolean = Boolean<Circuit>>, &case, candidate);
+            });
+        }
+        Circuit::reset();
+    }
+
+    fn run_test(mode_index: Mode, mode_table: Mode) {
+        check_select_index(2, mode_index, mode_table);
+        check_select_index(3, mode_index, mode_table);
+    }
+
+    #[test]
+    fn test_select_index_constant_constant() {
+        run_test(Mode::Constant, Mode::Constant);
+    }
+
+    #[test]
+    fn test_select_index_constant_public() {
+        run_test(Mode::Constant, Mode::Public);
+    }
+
+    #[test]
+    fn test_select_index_constant_private() {
+        run_test(Mode::Constant, Mode::Private);
+    }
+
+    #[test]
+    fn test_select_index_public_constant() {
+        run_test(Mode::Public, Mode::Constant);
+    }
+
+    #[test]
+    fn test_select_index_public_public() {
+        run_test(Mode::Public, Mode::Public);
+    }
+
+    #[test]
+    fn test_select_index_public_private() {
+        run_test(Mode::Public, Mode::Private);
+    }
+
+    #[test]
+    fn test_select_index_private_constant() {
+        run_test(Mode::Private, Mode::Constant);
+    }
+
+    #[test]
+    fn test_select_index_private_public() {
+        run_test(Mode::Private, Mode::Public);
+    }
+
+    #[test]
+    fn test_select_index_private_private() {
+        run_test(Mode::Private, Mode::Private);
+    }
+
+    #[test]
+    fn test_select_index_fails_on_mismatched_table() {
+        let index_bits = vec![Boolean::<Circuit>::new(Mode::Private, true); 2];
+        let table = vec![Group::<Circuit>::new(Mode::Private, Uniform::rand(&mut TestRng::default())); 3];
+        let result = std::panic::catch_unwind(|| Group::select_index(&index_bits, &table));
+        assert!(result.is_err());
+    }
+}
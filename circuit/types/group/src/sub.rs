@@ -57,7 +57,7 @@ impl<E: Environment> Metrics<dyn Sub<Group<E>, Output = Group<E>>> for Group<E>
 
     fn count(case: &Self::Case) -> Count {
         match (case.0, case.1) {
-            (Mode::Constant, Mode::Constant) => Count::is(4, 0, 0, 0),
+            (Mode::Constant, Mode::Constant) => Count::less_than(4, 0, 0, 0),
             (Mode::Constant, _) | (_, Mode::Constant) => Count::is(2, 0, 3, 3),
             (_, _) => Count::is(2, 0, 6, 6),
         }
@@ -282,6 +282,23 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_sub_self_is_zero() {
+        let mut rng = TestRng::default();
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            for i in 0..ITERATIONS {
+                let a = Group::<Circuit>::new(mode, Uniform::rand(&mut rng));
+                check_sub(&format!("Sub: a - a {mode} {i}"), &console::Group::zero(), &a, &a);
+            }
+
+            // Ensure the identity minus itself is the identity.
+            let zero = Group::<Circuit>::new(mode, console::Group::zero());
+            check_sub(&format!("Sub: 0 - 0 {mode}"), &console::Group::zero(), &zero, &zero);
+            Circuit::reset();
+        }
+    }
+
     #[test]
     fn test_sub_matches() {
         let mut rng = TestRng::default();
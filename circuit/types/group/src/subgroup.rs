@@ -0,0 +1,197 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use console::ProjectiveCurve;
+
+impl<E: Environment> Subgroup for Group<E> {
+    type Boolean = Boolean<E>;
+
+    /// Returns `true` if `self` is in the prime-order subgroup.
+    ///
+    /// This is computed as `(ScalarField::MODULUS - 1) * self == -self`, which is equivalent to
+    /// `ScalarField::MODULUS * self == 0`. Unlike `assert_in_subgroup`, the result is fully
+    /// determined by `self`, so it costs a full scalar multiplication by a constant.
+    fn is_in_subgroup(&self) -> Self::Boolean {
+        // Case 1: Constant
+        if self.is_constant() {
+            Boolean::constant(self.eject_value().to_affine().is_in_correct_subgroup_assuming_on_curve())
+        }
+        // Case 2: Variable
+        else {
+            // (For advanced users) ScalarField::MODULUS - 1 is equivalent to -1 in the scalar field.
+            let modulus_minus_one = Scalar::constant(-console::Scalar::one());
+            (self * modulus_minus_one).is_equal(&-self)
+        }
+    }
+
+    /// Asserts that `self` is in the prime-order subgroup, halting if `self` is a constant that is not.
+    ///
+    /// As the subgroup is the image of the multiplication by the cofactor, this witnesses
+    /// `point_inv = self / COFACTOR` on the curve, and enforces `point_inv * COFACTOR == self`.
+    fn assert_in_subgroup(&self) {
+        // Case 1: Constant
+        if self.is_constant() {
+            if !self.eject_value().to_affine().is_in_correct_subgroup_assuming_on_curve() {
+                E::halt("The group element is not in the prime-order subgroup")
+            }
+        }
+        // Case 2: Variable
+        else {
+            // Note: `Group::new` injects the point as `point_inv * COFACTOR`, where `point_inv` is enforced to be on the curve.
+            let point: Group<E> = witness!(|self| self);
+            // Ensure `self` is `point_inv * COFACTOR`.
+            E::assert_eq(self, point);
+        }
+    }
+}
+
+impl<E: Environment> Metrics<dyn Subgroup<Boolean = Boolean<E>>> for Group<E> {
+    type Case = Mode;
+
+    fn count(case: &Self::Case) -> Count {
+        match case.is_constant() {
+            true => Count::is(0, 0, 0, 0),
+            false => {
+                // Inject the constant scalar, which is a single field element.
+                let scalar_count = Count::is(1, 0, 0, 0);
+                // Compute `self * (ScalarField::MODULUS - 1)`.
+                let mul_count = count!(Group<E>, Mul<Scalar<E>, Output = Group<E>>, &(*case, Mode::Constant));
+                let mul_mode = output_mode!(Group<E>, Mul<Scalar<E>, Output = Group<E>>, &(*case, Mode::Constant));
+                // Compute `-self`.
+                let neg_count = count!(Group<E>, Neg<Output = Group<E>>, case);
+                let neg_mode = output_mode!(Group<E>, Neg<Output = Group<E>>, case);
+                // Compare the product to `-self`.
+                let equal_count = count!(Group<E>, Equal<Group<E>, Output = Boolean<E>>, &(mul_mode, neg_mode));
+
+                scalar_count + mul_count + neg_count + equal_count
+            }
+        }
+    }
+}
+
+impl<E: Environment> OutputMode<dyn Subgroup<Boolean = Boolean<E>>> for Group<E> {
+    type Case = Mode;
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        match case.is_constant() {
+            true => Mode::Constant,
+            false => Mode::Private,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 25;
+
+    /// Returns a random curve point that is not in the prime-order subgroup.
+    fn sample_non_subgroup_point(mode: Mode, rng: &mut TestRng) -> Group<Circuit> {
+        loop {
+            let x: console::Field<<Circuit as Environment>::Network> = Uniform::rand(rng);
+            if let Some(point) = <Circuit as Environment>::Affine::from_x_coordinate(*x, true) {
+                if !point.is_in_correct_subgroup_assuming_on_curve() {
+                    return Group::from_xy_coordinates_unchecked(
+                        Field::new(mode, console::Field::new(point.to_x_coordinate())),
+                        Field::new(mode, console::Field::new(point.to_y_coordinate())),
+                    );
+                }
+            }
+        }
+    }
+
+    fn check_in_subgroup(mode: Mode, num_constants: u64, num_public: u64, num_private: u64, num_constraints: u64) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample a random element.
+            let point = Group::<Circuit>::new(mode, Uniform::rand(&mut rng));
+
+            Circuit::scope(format!("{mode} {i}"), || {
+                let candidate = point.is_in_subgroup();
+                assert!(candidate.eject_value());
+                assert_count!(Group<Circuit>, Subgroup<Boolean = Boolean<Circuit>>, &mode);
+                assert_output_mode!(Group<Circuit>, Subgroup<Boolean = Boolean<Circuit>>, &mode, candidate);
+            });
+
+            Circuit::scope(format!("Assert {mode} {i}"), || {
+                point.assert_in_subgroup();
+                assert_scope!(num_constants, num_public, num_private, num_constraints);
+            });
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+    }
+
+    fn check_not_in_subgroup(mode: Mode) {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample a random element, outside the subgroup.
+            let point = sample_non_subgroup_point(mode, &mut rng);
+
+            let candidate = point.is_in_subgroup();
+            assert!(!candidate.eject_value());
+            assert!(Circuit::is_satisfied());
+
+            point.assert_in_subgroup();
+            assert!(!Circuit::is_satisfied());
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_in_subgroup_constant() {
+        check_in_subgroup(Mode::Constant, 0, 0, 0, 0);
+    }
+
+    #[test]
+    fn test_in_subgroup_public() {
+        check_in_subgroup(Mode::Public, 4, 0, 14, 14);
+    }
+
+    #[test]
+    fn test_in_subgroup_private() {
+        check_in_subgroup(Mode::Private, 4, 0, 14, 14);
+    }
+
+    #[test]
+    fn test_not_in_subgroup_public() {
+        check_not_in_subgroup(Mode::Public);
+    }
+
+    #[test]
+    fn test_not_in_subgroup_private() {
+        check_not_in_subgroup(Mode::Private);
+    }
+
+    #[test]
+    fn test_not_in_subgroup_constant() {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample a random element, outside the subgroup.
+            let point = sample_non_subgroup_point(Mode::Constant, &mut rng);
+            assert!(!point.is_in_subgroup().eject_value());
+
+            let result = std::panic::catch_unwind(|| point.assert_in_subgroup());
+            assert!(result.is_err());
+        }
+    }
+}
@@ -0,0 +1,204 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, U: IntegerType> AddSignedChecked<Integer<E, U::Dual>> for Integer<E, U> {
+    type Output = Self;
+
+    /// Returns the sum of `self` and the signed `delta`.
+    /// Note: This operation halts for constants, and is unsatisfiable otherwise,
+    /// if the sum is less than zero or greater than `U::MAX`.
+    #[inline]
+    fn add_signed_checked(&self, delta: &Integer<E, U::Dual>) -> Self::Output {
+        // Ensure `self` is unsigned, and consequently, that `delta` is signed.
+        if U::is_signed() {
+            E::halt("Attempted to add a signed delta to a signed integer")
+        }
+
+        // Reinterpret the bits of `delta` as an unsigned integer, which is `delta + 2^BITS` if `delta` is negative.
+        let unsigned_delta = Integer::<E, U>::from_bits_le(&delta.bits_le);
+
+        // Determine the variable mode.
+        if self.is_constant() && delta.is_constant() {
+            // Compute the sum and return the new constant.
+            let value = *self.eject_value();
+            let unsigned_delta = *unsigned_delta.eject_value();
+            let sum = match delta.msb().eject_value() {
+                // If `delta` is negative, subtract its magnitude, which is `2^BITS - unsigned_delta`.
+                true => value.checked_sub(&U::zero().wrapping_sub(&unsigned_delta)),
+                false => value.checked_add(&unsigned_delta),
+            };
+            match sum {
+                Some(sum) => Integer::constant(console::Integer::new(sum)),
+                None => E::halt("Integer overflow or underflow on signed addition of two constants"),
+            }
+        } else {
+            // Instead of adding the bits of `self` and `delta` directly, the integers are
+            // converted into a field elements, and summed, before converting back to integers.
+            // Note: This is safe as the field is larger than the maximum integer type supported.
+            let sum = self.to_field() + unsigned_delta.to_field();
+
+            // Extract the integer bits from the field element, with a carry bit.
+            let (sum, carry) = match sum.to_lower_bits_le(U::BITS as usize + 1).split_last() {
+                Some((carry, bits_le)) => (Integer::from_bits_le(bits_le), carry.clone()),
+                // Note: `E::halt` should never be invoked as `U::BITS as usize + 1` is greater than zero.
+                None => E::halt("Malformed sum detected during signed integer addition"),
+            };
+
+            // Check for overflow and underflow, which occur if and only if the carry bit differs from the sign of `delta`.
+            //   - If `delta >= 0`, the sum is `self + delta`, which overflows if the carry bit is set.
+            //   - If `delta < 0`, the sum is `self + delta + 2^BITS`, which underflows if the carry bit is not set.
+            E::assert_eq(carry, delta.msb());
+
+            // Return the sum of `self` and `delta`.
+            sum
+        }
+    }
+}
+
+impl<E: Environment, U: IntegerType> Metrics<dyn AddSignedChecked<Integer<E, U::Dual>, Output = Integer<E, U>>>
+    for Integer<E, U>
+{
+    type Case = (Mode, Mode);
+
+    fn count(case: &Self::Case) -> Count {
+        match (case.0, case.1) {
+            (Mode::Constant, Mode::Constant) => Count::is(U::BITS, 0, 0, 0),
+            (_, _) => Count::is(0, 0, U::BITS + 1, U::BITS + 3),
+        }
+    }
+}
+
+impl<E: Environment, U: IntegerType> OutputMode<dyn AddSignedChecked<Integer<E, U::Dual>, Output = Integer<E, U>>>
+    for Integer<E, U>
+{
+    type Case = (Mode, Mode);
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        match (case.0, case.1) {
+            (Mode::Constant, Mode::Constant) => Mode::Constant,
+            (_, _) => Mode::Private,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_integer_binary;
+    use snarkvm_circuit_environment::Circuit;
+
+    use test_utilities::*;
+
+    use core::panic::RefUnwindSafe;
+
+    const ITERATIONS: u64 = 128;
+
+    /// A helper trait exposing the native `checked_add_signed` for unsigned integers.
+    trait CheckedAddSigned: IntegerType {
+        fn native_checked_add_signed(self, delta: Self::Dual) -> Option<Self>;
+    }
+
+    macro_rules! impl_checked_add_signed {
+        ($($unsigned:ty),*) => {
+            $(impl CheckedAddSigned for $unsigned {
+                fn native_checked_add_signed(self, delta: Self::Dual) -> Option<Self> {
+                    self.checked_add_signed(delta)
+                }
+            })*
+        };
+    }
+
+    impl_checked_add_signed!(u8, u16, u32, u64, u128);
+
+    fn check_add_signed<U: CheckedAddSigned + RefUnwindSafe>(
+        name: &str,
+        first: console::Integer<<Circuit as Environment>::Network, U>,
+        second: console::Integer<<Circuit as Environment>::Network, U::Dual>,
+        mode_a: Mode,
+        mode_b: Mode,
+    ) where
+        U::Dual: RefUnwindSafe,
+    {
+        let a = Integer::<Circuit, U>::new(mode_a, first);
+        let b = Integer::<Circuit, U::Dual>::new(mode_b, second);
+        match (*first).native_checked_add_signed(*second) {
+            Some(expected) => Circuit::scope(name, || {
+                let candidate = a.add_signed_checked(&b);
+                assert_eq!(expected, *candidate.eject_value());
+                assert_count!(
+                    Integer<Circuit, U>,
+                    AddSignedChecked<Integer<Circuit, U::Dual>, Output = Integer<Circuit, U>>,
+                    &(mode_a, mode_b)
+                );
+                assert_output_mode!(
+                    Integer<Circuit, U>,
+                    AddSignedChecked<Integer<Circuit, U::Dual>, Output = Integer<Circuit, U>>,
+                    &(mode_a, mode_b),
+                    candidate
+                );
+            }),
+            None => match mode_a.is_constant() && mode_b.is_constant() {
+                true => check_operation_halts(&a, &b, Integer::add_signed_checked),
+                false => Circuit::scope(name, || {
+                    let _candidate = a.add_signed_checked(&b);
+                    assert_count_fails!(
+                        Integer<Circuit, U>,
+                        AddSignedChecked<Integer<Circuit, U::Dual>, Output = Integer<Circuit, U>>,
+                        &(mode_a, mode_b)
+                    );
+                }),
+            },
+        }
+        Circuit::reset();
+    }
+
+    fn run_test<U: CheckedAddSigned + RefUnwindSafe>(mode_a: Mode, mode_b: Mode)
+    where
+        U::Dual: RefUnwindSafe,
+    {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let first = Uniform::rand(&mut rng);
+            let second = Uniform::rand(&mut rng);
+
+            let name = format!("AddSigned: {mode_a} + {mode_b} {i}");
+            check_add_signed::<U>(&name, first, second, mode_a, mode_b);
+        }
+
+        // Overflow
+        check_add_signed::<U>("MAX + 1", console::Integer::MAX, console::Integer::one(), mode_a, mode_b);
+        check_add_signed::<U>("MAX + MAX", console::Integer::MAX, console::Integer::MAX, mode_a, mode_b);
+
+        // Underflow
+        check_add_signed::<U>("0 + (-1)", console::Integer::zero(), -console::Integer::one(), mode_a, mode_b);
+        check_add_signed::<U>("0 + MIN", console::Integer::zero(), console::Integer::MIN, mode_a, mode_b);
+
+        // Boundaries
+        check_add_signed::<U>("MAX + (-1)", console::Integer::MAX, -console::Integer::one(), mode_a, mode_b);
+        check_add_signed::<U>("MAX + MIN", console::Integer::MAX, console::Integer::MIN, mode_a, mode_b);
+        check_add_signed::<U>("0 + MAX", console::Integer::zero(), console::Integer::MAX, mode_a, mode_b);
+        check_add_signed::<U>("1 + (-1)", console::Integer::one(), -console::Integer::one(), mode_a, mode_b);
+    }
+
+    test_integer_binary!(run_test, u8, plus_signed);
+    test_integer_binary!(run_test, u16, plus_signed);
+    test_integer_binary!(run_test, u32, plus_signed);
+    test_integer_binary!(run_test, u64, plus_signed);
+    test_integer_binary!(run_test, u128, plus_signed);
+}
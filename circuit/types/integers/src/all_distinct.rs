@@ -0,0 +1,165 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> AllDistinct for Integer<E, I> {
+    type Boolean = Boolean<E>;
+
+    /// Returns `true` if no two values in `items` are equal.
+    /// Note: This method compares all `n * (n - 1) / 2` pairs of items,
+    /// so its cost is quadratic in the number of items, and it is only intended for small `n`.
+    fn all_distinct(items: &[Self]) -> Self::Boolean {
+        let mut output = Boolean::constant(true);
+        for (i, first) in items.iter().enumerate() {
+            for second in &items[i + 1..] {
+                output &= first.is_not_equal(second);
+            }
+        }
+        output
+    }
+}
+
+impl<E: Environment, I: IntegerType> Metrics<dyn AllDistinct<Boolean = Boolean<E>>> for Integer<E, I> {
+    type Case = Vec<CircuitType<Integer<E, I>>>;
+
+    fn count(case: &Self::Case) -> Count {
+        // Track the accumulated output, which is `Some(value)` if it is constant, and `None` otherwise.
+        let mut output = Some(true);
+        let mut count = Count::zero();
+        for (i, first) in case.iter().enumerate() {
+            for second in &case[i + 1..] {
+                // Compute the cost of the pairwise comparison.
+                count = count
+                    + count!(Integer<E, I>, Equal<Integer<E, I>, Output = Boolean<E>>, &(first.mode(), second.mode()));
+                let is_not_equal = match (first, second) {
+                    (CircuitType::Constant(a), CircuitType::Constant(b)) => Some(a.eject_value() != b.eject_value()),
+                    _ => None,
+                };
+
+                // Compute the cost of the conjunction, which is only incurred for two variables.
+                output = match (output, is_not_equal) {
+                    (Some(true), other) | (other, Some(true)) => other,
+                    (Some(false), _) | (_, Some(false)) => Some(false),
+                    (None, None) => {
+                        count = count + Count::is(0, 0, 1, 1);
+                        None
+                    }
+                };
+            }
+        }
+        count
+    }
+}
+
+impl<E: Environment, I: IntegerType> OutputMode<dyn AllDistinct<Boolean = Boolean<E>>> for Integer<E, I> {
+    type Case = Vec<CircuitType<Integer<E, I>>>;
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        // The output is constant if there are no pairs, if all items are constant, or if any two constant items are equal.
+        let constants = case
+            .iter()
+            .filter_map(|item| match item {
+                CircuitType::Constant(constant) => Some(constant.eject_value()),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        let has_constant_duplicate =
+            constants.iter().enumerate().any(|(i, first)| constants[i + 1..].iter().any(|second| first == second));
+        match case.len() < 2 || constants.len() == case.len() || has_constant_duplicate {
+            true => Mode::Constant,
+            false => Mode::Private,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_integer_unary;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 16;
+    const NUM_ITEMS: usize = 5;
+
+    fn check_all_distinct<I: IntegerType>(name: &str, values: &[I], modes: &[Mode]) {
+        let items = values
+            .iter()
+            .zip_eq(modes)
+            .map(|(value, mode)| Integer::<Circuit, I>::new(*mode, console::Integer::new(*value)))
+            .collect::<Vec<_>>();
+        let expected = values.iter().enumerate().all(|(i, first)| values[i + 1..].iter().all(|second| first != second));
+
+        Circuit::scope(name, || {
+            let candidate = Integer::all_distinct(&items);
+            assert_eq!(expected, candidate.eject_value());
+
+            let case = items.iter().map(CircuitType::from).collect::<Vec<_>>();
+            assert_count!(Integer<Circuit, I>, AllDistinct<Boolean = Boolean<Circuit>>, &case);
+            assert_output_mode!(Integer<Circuit, I>, AllDistinct<Boolean = Boolean<Circuit>>, &case, candidate);
+        });
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType>(mode: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample distinct values.
+            let mut values = Vec::with_capacity(NUM_ITEMS);
+            while values.len() < NUM_ITEMS {
+                let value = *console::Integer::<<Circuit as Environment>::Network, I>::rand(&mut rng);
+                if !values.contains(&value) {
+                    values.push(value);
+                }
+            }
+
+            // Alternate the mode of the items with a constant mode, to check mixed inputs.
+            let uniform_modes = vec![mode; NUM_ITEMS];
+            let mixed_modes =
+                (0..NUM_ITEMS).map(|j| if j % 2 == 0 { mode } else { Mode::Constant }).collect::<Vec<_>>();
+
+            for modes in [&uniform_modes, &mixed_modes] {
+                // Check fully distinct values.
+                check_all_distinct::<I>(&format!("Distinct {mode} {i}"), &values, modes);
+
+                // Check values with one duplicate pair.
+                let mut duplicated = values.clone();
+                duplicated[i as usize % NUM_ITEMS] = duplicated[(i as usize + 1) % NUM_ITEMS];
+                check_all_distinct::<I>(&format!("Duplicate {mode} {i}"), &duplicated, modes);
+
+                // Check all-equal values.
+                check_all_distinct::<I>(&format!("Equal {mode} {i}"), &[values[0]; NUM_ITEMS], modes);
+            }
+        }
+
+        // Check the trivially distinct inputs.
+        check_all_distinct::<I>("Single", &[I::one()], &[mode]);
+        check_all_distinct::<I>("Empty", &[], &[]);
+    }
+
+    test_integer_unary!(run_test, i8, all_distinct);
+    test_integer_unary!(run_test, i16, all_distinct);
+    test_integer_unary!(run_test, i32, all_distinct);
+    test_integer_unary!(run_test, i64, all_distinct);
+    test_integer_unary!(run_test, i128, all_distinct);
+
+    test_integer_unary!(run_test, u8, all_distinct);
+    test_integer_unary!(run_test, u16, all_distinct);
+    test_integer_unary!(run_test, u32, all_distinct);
+    test_integer_unary!(run_test, u64, all_distinct);
+    test_integer_unary!(run_test, u128, all_distinct);
+}
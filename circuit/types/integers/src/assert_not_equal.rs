@@ -0,0 +1,124 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> AssertNotEqual<Self> for Integer<E, I> {
+    /// Enforces that `self` and `other` are *not* equal.
+    /// Note: This operation halts for constants, and is unsatisfiable otherwise, if `self` and `other` are equal.
+    fn assert_not_equal(&self, other: &Self) {
+        // Determine if this operation is constant or variable.
+        match self.is_constant() && other.is_constant() {
+            true => {
+                if self.eject_value() == other.eject_value() {
+                    E::halt("Attempted to assert that two equal constants are distinct")
+                }
+            }
+            // Instead of comparing the bits of `self` and `other` directly, the integers are
+            // converted into field elements, and their difference is enforced to be nonzero,
+            // by enforcing that the difference has a multiplicative inverse.
+            // Note: This is safe as the field is larger than the maximum integer type supported.
+            false => {
+                let _ = (self.to_field() - other.to_field()).inverse();
+            }
+        }
+    }
+}
+
+impl<E: Environment, I: IntegerType> Metrics<dyn AssertNotEqual<Integer<E, I>>> for Integer<E, I> {
+    type Case = (Mode, Mode);
+
+    fn count(case: &Self::Case) -> Count {
+        match case.0.is_constant() && case.1.is_constant() {
+            true => Count::is(0, 0, 0, 0),
+            false => Count::is(0, 0, 1, 1),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_integer_binary;
+    use snarkvm_circuit_environment::Circuit;
+
+    use test_utilities::*;
+
+    use core::panic::RefUnwindSafe;
+
+    const ITERATIONS: u64 = 100;
+
+    fn check_assert_not_equal<I: IntegerType + RefUnwindSafe>(
+        name: &str,
+        first: console::Integer<<Circuit as Environment>::Network, I>,
+        second: console::Integer<<Circuit as Environment>::Network, I>,
+        mode_a: Mode,
+        mode_b: Mode,
+    ) {
+        let a = Integer::<Circuit, I>::new(mode_a, first);
+        let b = Integer::<Circuit, I>::new(mode_b, second);
+
+        // Check that `is_not_equal` agrees with the native comparison.
+        Circuit::scope(name, || assert_eq!(first != second, a.is_not_equal(&b).eject_value()));
+        Circuit::reset();
+
+        match first != second {
+            true => Circuit::scope(name, || {
+                a.assert_not_equal(&b);
+                assert_count!(Integer<Circuit, I>, AssertNotEqual<Integer<Circuit, I>>, &(mode_a, mode_b));
+            }),
+            false => match mode_a.is_constant() && mode_b.is_constant() {
+                true => check_operation_halts(&a, &b, Integer::assert_not_equal),
+                false => Circuit::scope(name, || {
+                    a.assert_not_equal(&b);
+                    assert_count_fails!(Integer<Circuit, I>, AssertNotEqual<Integer<Circuit, I>>, &(mode_a, mode_b));
+                }),
+            },
+        }
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType + RefUnwindSafe>(mode_a: Mode, mode_b: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let first = Uniform::rand(&mut rng);
+            let second = Uniform::rand(&mut rng);
+
+            let name = format!("AssertNotEqual: {mode_a} != {mode_b} {i}");
+            check_assert_not_equal::<I>(&name, first, second, mode_a, mode_b);
+
+            let name = format!("AssertNotEqual: {mode_a} == {mode_b} {i}");
+            check_assert_not_equal::<I>(&name, first, first, mode_a, mode_b);
+        }
+
+        // Check the boundary values.
+        check_assert_not_equal::<I>("MAX != MIN", console::Integer::MAX, console::Integer::MIN, mode_a, mode_b);
+        check_assert_not_equal::<I>("0 != 1", console::Integer::zero(), console::Integer::one(), mode_a, mode_b);
+    }
+
+    test_integer_binary!(run_test, i8, assert_not_equal);
+    test_integer_binary!(run_test, i16, assert_not_equal);
+    test_integer_binary!(run_test, i32, assert_not_equal);
+    test_integer_binary!(run_test, i64, assert_not_equal);
+    test_integer_binary!(run_test, i128, assert_not_equal);
+
+    test_integer_binary!(run_test, u8, assert_not_equal);
+    test_integer_binary!(run_test, u16, assert_not_equal);
+    test_integer_binary!(run_test, u32, assert_not_equal);
+    test_integer_binary!(run_test, u64, assert_not_equal);
+    test_integer_binary!(run_test, u128, assert_not_equal);
+}
@@ -0,0 +1,146 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> AssertSorted for Integer<E, I> {
+    /// Enforces that `items` is sorted in ascending order if `ascending` is `true`, or in descending order otherwise.
+    /// Note: This operation halts for constants, and is unsatisfiable otherwise, if `items` are out of order.
+    fn assert_sorted(items: &[Self], ascending: bool) {
+        for pair in items.windows(2) {
+            // Check the ordering of the adjacent pair.
+            let (first, second) = (&pair[0], &pair[1]);
+            let is_ordered = match ascending {
+                true => first.is_less_than_or_equal(second),
+                false => first.is_greater_than_or_equal(second),
+            };
+
+            // Ensure the adjacent pair is ordered.
+            match is_ordered.is_constant() {
+                true => {
+                    if !is_ordered.eject_value() {
+                        E::halt("Attempted to assert that an unsorted sequence of constants is sorted")
+                    }
+                }
+                false => E::assert(is_ordered),
+            }
+        }
+    }
+}
+
+impl<E: Environment, I: IntegerType> Metrics<dyn AssertSorted> for Integer<E, I> {
+    type Case = Vec<Mode>;
+
+    fn count(case: &Self::Case) -> Count {
+        case.windows(2).fold(Count::zero(), |count, modes| {
+            // Compute the cost of comparing the adjacent pair.
+            let compare_count =
+                count!(Integer<E, I>, Compare<Integer<E, I>, Output = Boolean<E>>, &(modes[1], modes[0]));
+
+            // Compute the cost of enforcing the comparison.
+            let assert_count = match modes[0].is_constant() && modes[1].is_constant() {
+                true => Count::zero(),
+                false => Count::is(0, 0, 0, 1),
+            };
+
+            count + compare_count + assert_count
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_integer_unary;
+    use snarkvm_circuit_environment::Circuit;
+
+    use test_utilities::*;
+
+    use core::panic::RefUnwindSafe;
+
+    const ITERATIONS: u64 = 16;
+    const NUM_ITEMS: usize = 8;
+
+    fn check_assert_sorted<I: IntegerType + RefUnwindSafe>(name: &str, values: &[I], ascending: bool, mode: Mode) {
+        let items = values
+            .iter()
+            .map(|value| Integer::<Circuit, I>::new(mode, console::Integer::new(*value)))
+            .collect::<Vec<_>>();
+        let modes = vec![mode; items.len()];
+
+        let is_sorted = values.windows(2).all(|pair| match ascending {
+            true => pair[0] <= pair[1],
+            false => pair[0] >= pair[1],
+        });
+        match is_sorted {
+            true => Circuit::scope(name, || {
+                Integer::assert_sorted(&items, ascending);
+                assert_count!(Integer<Circuit, I>, AssertSorted, &modes);
+            }),
+            false => match mode.is_constant() {
+                true => check_unary_operation_halts(&items, |items| Integer::assert_sorted(items, ascending)),
+                false => Circuit::scope(name, || {
+                    Integer::assert_sorted(&items, ascending);
+                    assert_count_fails!(Integer<Circuit, I>, AssertSorted, &modes);
+                }),
+            },
+        }
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType + RefUnwindSafe>(mode: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let mut values = (0..NUM_ITEMS)
+                .map(|_| *console::Integer::<<Circuit as Environment>::Network, I>::rand(&mut rng))
+                .collect::<Vec<_>>();
+
+            // Check a sorted sequence, in both orders.
+            values.sort();
+            check_assert_sorted::<I>(&format!("Ascending {mode} {i}"), &values, true, mode);
+            check_assert_sorted::<I>(&format!("Ascending (reversed) {mode} {i}"), &values, false, mode);
+
+            // Check a reverse-sorted sequence, in both orders.
+            values.reverse();
+            check_assert_sorted::<I>(&format!("Descending {mode} {i}"), &values, false, mode);
+            check_assert_sorted::<I>(&format!("Descending (reversed) {mode} {i}"), &values, true, mode);
+
+            // Check a sorted sequence with a single swap out of order.
+            values.reverse();
+            let index = i as usize % (NUM_ITEMS - 1);
+            values.swap(index, index + 1);
+            check_assert_sorted::<I>(&format!("Swapped {mode} {i}"), &values, true, mode);
+        }
+
+        // Check a sequence with duplicates, and the trivially sorted sequences.
+        check_assert_sorted::<I>("Duplicates", &[I::zero(), I::zero(), I::one(), I::one()], true, mode);
+        check_assert_sorted::<I>("Single", &[I::one()], true, mode);
+        check_assert_sorted::<I>("Empty", &[], false, mode);
+    }
+
+    test_integer_unary!(run_test, i8, assert_sorted);
+    test_integer_unary!(run_test, i16, assert_sorted);
+    test_integer_unary!(run_test, i32, assert_sorted);
+    test_integer_unary!(run_test, i64, assert_sorted);
+    test_integer_unary!(run_test, i128, assert_sorted);
+
+    test_integer_unary!(run_test, u8, assert_sorted);
+    test_integer_unary!(run_test, u16, assert_sorted);
+    test_integer_unary!(run_test, u32, assert_sorted);
+    test_integer_unary!(run_test, u64, assert_sorted);
+    test_integer_unary!(run_test, u128, assert_sorted);
+}
@@ -0,0 +1,180 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> ConditionalIncrement for Integer<E, I> {
+    type Boolean = Boolean<E>;
+    type Output = Self;
+
+    /// Returns `self + 1` if `condition` is `true`, otherwise returns `self`.
+    ///
+    /// Note: The increment wraps, so `I::MAX.conditional_add_one(true)` returns `I::MIN`.
+    fn conditional_add_one(&self, condition: &Self::Boolean) -> Self::Output {
+        // Compute `ternary(condition, 1, 0)` as the integer whose only set bit is `condition`, which is free.
+        self.add_wrapped(&Self::from_bits_le(core::slice::from_ref(condition)))
+    }
+}
+
+impl<E: Environment, I: IntegerType> ConditionalDecrement for Integer<E, I> {
+    type Boolean = Boolean<E>;
+    type Output = Self;
+
+    /// Returns `self - 1` if `condition` is `true`, otherwise returns `self`.
+    ///
+    /// Note: The decrement wraps, so `I::MIN.conditional_sub_one(true)` returns `I::MAX`.
+    fn conditional_sub_one(&self, condition: &Self::Boolean) -> Self::Output {
+        // Compute `ternary(condition, 1, 0)` as the integer whose only set bit is `condition`, which is free.
+        self.sub_wrapped(&Self::from_bits_le(core::slice::from_ref(condition)))
+    }
+}
+
+impl<E: Environment, I: IntegerType> Metrics<dyn ConditionalIncrement<Boolean = Boolean<E>, Output = Integer<E, I>>>
+    for Integer<E, I>
+{
+    type Case = (Mode, Mode);
+
+    fn count(case: &Self::Case) -> Count {
+        let (mode_condition, mode_input) = *case;
+        count!(Self, AddWrapped<Self, Output = Self>, &(mode_input, mode_condition))
+    }
+}
+
+impl<E: Environment, I: IntegerType> OutputMode<dyn ConditionalIncrement<Boolean = Boolean<E>, Output = Integer<E, I>>>
+    for Integer<E, I>
+{
+    type Case = (Mode, Mode);
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        let (mode_condition, mode_input) = *case;
+        output_mode!(Self, AddWrapped<Self, Output = Self>, &(mode_input, mode_condition))
+    }
+}
+
+impl<E: Environment, I: IntegerType> Metrics<dyn ConditionalDecrement<Boolean = Boolean<E>, Output = Integer<E, I>>>
+    for Integer<E, I>
+{
+    type Case = (Mode, Mode);
+
+    fn count(case: &Self::Case) -> Count {
+        let (mode_condition, mode_input) = *case;
+        count!(Self, SubWrapped<Self, Output = Self>, &(mode_input, mode_condition))
+    }
+}
+
+impl<E: Environment, I: IntegerType> OutputMode<dyn ConditionalDecrement<Boolean = Boolean<E>, Output = Integer<E, I>>>
+    for Integer<E, I>
+{
+    type Case = (Mode, Mode);
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        let (mode_condition, mode_input) = *case;
+        output_mode!(Self, SubWrapped<Self, Output = Self>, &(mode_input, mode_condition))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 32;
+
+    fn check_conditional_increment<I: IntegerType>(
+        name: &str,
+        flag: bool,
+        value: console::Integer<<Circuit as Environment>::Network, I>,
+        mode_condition: Mode,
+        mode_input: Mode,
+    ) {
+        let condition = Boolean::<Circuit>::new(mode_condition, flag);
+        let input = Integer::<Circuit, I>::new(mode_input, value);
+
+        // Check the increment.
+        let expected = match flag {
+            true => value.wrapping_add(&I::one()),
+            false => *value,
+        };
+        Circuit::scope(format!("Increment {name}"), || {
+            let candidate = input.conditional_add_one(&condition);
+            assert_eq!(expected, *candidate.eject_value());
+            assert_count!(
+                Integer<Circuit, I>,
+                ConditionalIncrement<Boolean = Boolean<Circuit>, Output = Integer<Circuit, I>>,
+                &(mode_condition, mode_input)
+            );
+            assert_output_mode!(
+                Integer<Circuit, I>,
+                ConditionalIncrement<Boolean = Boolean<Circuit>, Output = Integer<Circuit, I>>,
+                &(mode_condition, mode_input),
+                candidate
+            );
+        });
+
+        // Check the decrement.
+        let expected = match flag {
+            true => value.wrapping_sub(&I::one()),
+            false => *value,
+        };
+        Circuit::scope(format!("Decrement {name}"), || {
+            let candidate = input.conditional_sub_one(&condition);
+            assert_eq!(expected, *candidate.eject_value());
+            assert_count!(
+                Integer<Circuit, I>,
+                ConditionalDecrement<Boolean = Boolean<Circuit>, Output = Integer<Circuit, I>>,
+                &(mode_condition, mode_input)
+            );
+            assert_output_mode!(
+                Integer<Circuit, I>,
+                ConditionalDecrement<Boolean = Boolean<Circuit>, Output = Integer<Circuit, I>>,
+                &(mode_condition, mode_input),
+                candidate
+            );
+        });
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType>(mode_condition: Mode, mode_input: Mode) {
+        let mut rng = TestRng::default();
+
+        for flag in [true, false] {
+            for i in 0..ITERATIONS {
+                let value = Uniform::rand(&mut rng);
+                let name = format!("({flag}): {mode_condition} {mode_input} {i}");
+                check_conditional_increment::<I>(&name, flag, value, mode_condition, mode_input);
+            }
+
+            // Check the edge cases, including the wrapping at `I::MAX` and `I::MIN`.
+            for value in [I::zero(), I::one(), I::MAX, I::MIN] {
+                let name = format!("({flag}): {mode_condition} {mode_input} {value}");
+                check_conditional_increment::<I>(&name, flag, console::Integer::new(value), mode_condition, mode_input);
+            }
+        }
+    }
+
+    test_integer_binary!(run_test, i8, conditional_increment);
+    test_integer_binary!(run_test, i16, conditional_increment);
+    test_integer_binary!(run_test, i32, conditional_increment);
+    test_integer_binary!(run_test, i64, conditional_increment);
+    test_integer_binary!(run_test, i128, conditional_increment);
+
+    test_integer_binary!(run_test, u8, conditional_increment);
+    test_integer_binary!(run_test, u16, conditional_increment);
+    test_integer_binary!(run_test, u32, conditional_increment);
+    test_integer_binary!(run_test, u64, conditional_increment);
+    test_integer_binary!(run_test, u128, conditional_increment);
+}
@@ -0,0 +1,159 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> ConditionalNeg for Integer<E, I> {
+    type Boolean = Boolean<E>;
+    type Output = Self;
+
+    /// Returns `-self` if `condition` is `true`, otherwise returns `self`.
+    ///
+    /// Note: The negation wraps, so `I::MIN.conditional_neg(true)` returns `I::MIN`.
+    fn conditional_neg(&self, condition: &Self::Boolean) -> Self::Output {
+        match I::is_signed() {
+            // Compute the two's complement negation as `!self + 1`, and select it if `condition` is `true`.
+            true => Self::ternary(condition, &(!self).add_wrapped(&Self::one()), self),
+            // Note: `halt` is necessary since negation is not defined for unsigned integers.
+            false => E::halt("Attempted to negate an unsigned integer"),
+        }
+    }
+}
+
+impl<E: Environment, I: IntegerType> Metrics<dyn ConditionalNeg<Boolean = Boolean<E>, Output = Integer<E, I>>>
+    for Integer<E, I>
+{
+    type Case = (Mode, Mode);
+
+    fn count(case: &Self::Case) -> Count {
+        let (mode_condition, mode_input) = *case;
+        match I::is_signed() {
+            false => E::halt("Unsigned integers cannot be negated"),
+            true => {
+                let mode_not = output_mode!(Self, Not<Output = Self>, &mode_input);
+                let mode_negated = output_mode!(Self, AddWrapped<Self, Output = Self>, &(mode_not, Mode::Constant));
+
+                // Count the constant one, the negation, and the selection.
+                Count::is(I::BITS, 0, 0, 0)
+                    + count!(Self, Not<Output = Self>, &mode_input)
+                    + count!(Self, AddWrapped<Self, Output = Self>, &(mode_not, Mode::Constant))
+                    + count!(
+                        Self,
+                        Ternary<Boolean = Boolean<E>, Output = Self>,
+                        &(mode_condition, mode_negated, mode_input)
+                    )
+            }
+        }
+    }
+}
+
+impl<E: Environment, I: IntegerType> OutputMode<dyn ConditionalNeg<Boolean = Boolean<E>, Output = Integer<E, I>>>
+    for Integer<E, I>
+{
+    type Case = (CircuitType<Boolean<E>>, Mode);
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        let (condition, mode_input) = case;
+        let mode_not = output_mode!(Self, Not<Output = Self>, mode_input);
+        let mode_negated = output_mode!(Self, AddWrapped<Self, Output = Self>, &(mode_not, Mode::Constant));
+        output_mode!(
+            Self,
+            Ternary<Boolean = Boolean<E>, Output = Self>,
+            &(condition.clone(), mode_negated, *mode_input)
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    use test_utilities::*;
+
+    const ITERATIONS: u64 = 32;
+
+    fn check_conditional_neg<I: IntegerType>(
+        name: &str,
+        flag: bool,
+        value: console::Integer<<Circuit as Environment>::Network, I>,
+        mode_condition: Mode,
+        mode_input: Mode,
+    ) {
+        let expected = match flag {
+            true => value.wrapping_neg(),
+            false => *value,
+        };
+
+        let condition = Boolean::<Circuit>::new(mode_condition, flag);
+        let input = Integer::<Circuit, I>::new(mode_input, value);
+
+        Circuit::scope(name, || {
+            let candidate = input.conditional_neg(&condition);
+            assert_eq!(expected, *candidate.eject_value());
+            assert_count!(
+                Integer<Circuit, I>,
+                ConditionalNeg<Boolean = Boolean<Circuit>, Output = Integer<Circuit, I>>,
+                &(mode_condition, mode_input)
+            );
+            // Note: For a variable condition and a constant input, the output bits are constants or (negated) copies
+            // of the condition, so the output mode depends on the value of the input, as in `Integer::ternary`.
+            if mode_condition.is_constant() || !mode_input.is_constant() {
+                assert_output_mode!(
+                    Integer<Circuit, I>,
+                    ConditionalNeg<Boolean = Boolean<Circuit>, Output = Integer<Circuit, I>>,
+                    &(CircuitType::from(&condition), mode_input),
+                    candidate
+                );
+            }
+        });
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType>(mode_condition: Mode, mode_input: Mode) {
+        let mut rng = TestRng::default();
+
+        for flag in [true, false] {
+            for i in 0..ITERATIONS {
+                let value = Uniform::rand(&mut rng);
+                let name = format!("ConditionalNeg({flag}): {mode_condition} {mode_input} {i}");
+                check_conditional_neg::<I>(&name, flag, value, mode_condition, mode_input);
+            }
+
+            // Check the edge cases, including the wrapping negation of `I::MIN`.
+            for value in [I::zero(), I::one(), I::MAX, I::MIN] {
+                let name = format!("ConditionalNeg({flag}): {mode_condition} {mode_input} {value}");
+                check_conditional_neg::<I>(&name, flag, console::Integer::new(value), mode_condition, mode_input);
+            }
+        }
+    }
+
+    fn assert_unsigned_halts<I: IntegerType + core::panic::UnwindSafe>(mode_condition: Mode, mode_input: Mode) {
+        let condition = Boolean::<Circuit>::new(mode_condition, true);
+        let input = Integer::<Circuit, I>::new(mode_input, console::Integer::new(I::one()));
+        check_unary_operation_halts(input, |input: Integer<Circuit, I>| input.conditional_neg(&condition));
+    }
+
+    test_integer_binary!(run_test, i8, conditional_neg);
+    test_integer_binary!(run_test, i16, conditional_neg);
+    test_integer_binary!(run_test, i32, conditional_neg);
+    test_integer_binary!(run_test, i64, conditional_neg);
+    test_integer_binary!(run_test, i128, conditional_neg);
+
+    test_integer_binary!(assert_unsigned_halts, u8, conditional_neg);
+    test_integer_binary!(assert_unsigned_halts, u32, conditional_neg);
+    test_integer_binary!(assert_unsigned_halts, u128, conditional_neg);
+}
@@ -0,0 +1,110 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> ConditionalSwap for Integer<E, I> {
+    type Boolean = Boolean<E>;
+    type Output = Self;
+
+    /// Returns `(second, first)` if `condition` is `true`, otherwise returns `(first, second)`.
+    fn conditional_swap(condition: &Self::Boolean, first: &Self, second: &Self) -> (Self::Output, Self::Output) {
+        // Note: If `condition` is a constant, each ternary returns one of the inputs, without any constraints.
+        let swapped_first = Self::ternary(condition, second, first);
+        let swapped_second = Self::ternary(condition, first, second);
+        (swapped_first, swapped_second)
+    }
+}
+
+impl<E: Environment, I: IntegerType> Metrics<dyn ConditionalSwap<Boolean = Boolean<E>, Output = Integer<E, I>>>
+    for Integer<E, I>
+{
+    type Case = (Mode, Mode, Mode);
+
+    fn count(case: &Self::Case) -> Count {
+        let (mode_condition, mode_a, mode_b) = *case;
+        count!(Self, Ternary<Boolean = Boolean<E>, Output = Integer<E, I>>, &(mode_condition, mode_b, mode_a))
+            + count!(Self, Ternary<Boolean = Boolean<E>, Output = Integer<E, I>>, &(mode_condition, mode_a, mode_b))
+    }
+}
+
+impl<E: Environment, I: IntegerType> OutputMode<dyn ConditionalSwap<Boolean = Boolean<E>, Output = Integer<E, I>>>
+    for Integer<E, I>
+{
+    type Case = (CircuitType<Boolean<E>>, Mode, Mode);
+
+    /// Returns the mode of the first output.
+    /// The mode of the second output is given by the case `(condition, mode_b, mode_a)`.
+    fn output_mode(case: &Self::Case) -> Mode {
+        let (condition, mode_a, mode_b) = case;
+        // Note: The first output is `ternary(condition, second, first)`.
+        let case = (condition.clone(), *mode_b, *mode_a);
+        output_mode!(Self, Ternary<Boolean = Boolean<E>, Output = Integer<E, I>>, &case)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    fn run_test<I: IntegerType>(mode_condition: Mode, mode_a: Mode, mode_b: Mode) {
+        let mut rng = TestRng::default();
+
+        for flag in [true, false] {
+            let first = Uniform::rand(&mut rng);
+            let second = Uniform::rand(&mut rng);
+            let expected = if flag { (second, first) } else { (first, second) };
+
+            let condition = Boolean::<Circuit>::new(mode_condition, flag);
+            let a = Integer::<Circuit, I>::new(mode_a, first);
+            let b = Integer::new(mode_b, second);
+
+            let name = format!("ConditionalSwap({flag}): if ({mode_condition}) then ({mode_b}, {mode_a})");
+            Circuit::scope(name, || {
+                let (candidate_a, candidate_b) = Integer::conditional_swap(&condition, &a, &b);
+                assert_eq!(expected, (candidate_a.eject_value(), candidate_b.eject_value()));
+                assert_count!(
+                    ConditionalSwap(Boolean, Integer<I>, Integer<I>) => Integer<I>,
+                    &(mode_condition, mode_a, mode_b)
+                );
+                assert_output_mode!(
+                    ConditionalSwap(Boolean, Integer<I>, Integer<I>) => Integer<I>,
+                    &(CircuitType::from(&condition), mode_a, mode_b),
+                    candidate_a
+                );
+                assert_output_mode!(
+                    ConditionalSwap(Boolean, Integer<I>, Integer<I>) => Integer<I>,
+                    &(CircuitType::from(&condition), mode_b, mode_a),
+                    candidate_b
+                );
+            });
+            Circuit::reset();
+        }
+    }
+
+    test_integer_ternary!(run_test, i8, if, swap, with);
+    test_integer_ternary!(run_test, i16, if, swap, with);
+    test_integer_ternary!(run_test, i32, if, swap, with);
+    test_integer_ternary!(run_test, i64, if, swap, with);
+    test_integer_ternary!(run_test, i128, if, swap, with);
+
+    test_integer_ternary!(run_test, u8, if, swap, with);
+    test_integer_ternary!(run_test, u16, if, swap, with);
+    test_integer_ternary!(run_test, u32, if, swap, with);
+    test_integer_ternary!(run_test, u64, if, swap, with);
+    test_integer_ternary!(run_test, u128, if, swap, with);
+}
@@ -0,0 +1,174 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> CountTrue for U32<E> {
+    type Boolean = Boolean<E>;
+
+    /// Returns the number of `true` values in `bits`.
+    ///
+    /// The booleans are summed as a linear combination of field elements,
+    /// and the sum is decomposed into the bits of a `U32`.
+    fn count_true(bits: &[Self::Boolean]) -> Self {
+        // Ensure the count fits within a `U32`.
+        if bits.len() > u32::MAX as usize {
+            E::halt(format!("Cannot count more than {} booleans, found {}", u32::MAX, bits.len()))
+        }
+
+        // Sum the booleans as field elements.
+        let sum = bits.iter().fold(Field::zero(), |sum, bit| sum + Field::from_boolean(bit));
+
+        // Decompose the sum into a `U32`.
+        U32::from_field(sum)
+    }
+}
+
+impl<E: Environment> AtLeast for U32<E> {
+    type Boolean = Boolean<E>;
+
+    /// Returns `true` if at least `k` values in `bits` are `true`.
+    fn at_least(bits: &[Self::Boolean], k: u32) -> Self::Boolean {
+        Self::count_true(bits).is_greater_than_or_equal(&U32::constant(console::U32::new(k)))
+    }
+}
+
+impl<E: Environment> Metrics<dyn CountTrue<Boolean = Boolean<E>>> for U32<E> {
+    type Case = Vec<Mode>;
+
+    fn count(case: &Self::Case) -> Count {
+        match case.iter().all(|mode| mode.is_constant()) {
+            true => Count::is(u32::BITS as u64, 0, 0, 0),
+            false => Count::is(0, 0, u32::BITS as u64, u32::BITS as u64 + 1),
+        }
+    }
+}
+
+impl<E: Environment> OutputMode<dyn CountTrue<Boolean = Boolean<E>>> for U32<E> {
+    type Case = Vec<Mode>;
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        match case.iter().all(|mode| mode.is_constant()) {
+            true => Mode::Constant,
+            false => Mode::Private,
+        }
+    }
+}
+
+impl<E: Environment> Metrics<dyn AtLeast<Boolean = Boolean<E>>> for U32<E> {
+    type Case = Vec<Mode>;
+
+    fn count(case: &Self::Case) -> Count {
+        let count_mode = output_mode!(U32<E>, CountTrue<Boolean = Boolean<E>>, case);
+
+        // Compute the cost of counting the booleans, and of comparing the count to the constant `k`.
+        count!(U32<E>, CountTrue<Boolean = Boolean<E>>, case)
+            + Count::is(u32::BITS as u64, 0, 0, 0)
+            + count!(U32<E>, Compare<U32<E>, Output = Boolean<E>>, &(count_mode, Mode::Constant))
+    }
+}
+
+impl<E: Environment> OutputMode<dyn AtLeast<Boolean = Boolean<E>>> for U32<E> {
+    type Case = Vec<Mode>;
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        let count_mode = output_mode!(U32<E>, CountTrue<Boolean = Boolean<E>>, case);
+        output_mode!(U32<E>, Compare<U32<E>, Output = Boolean<E>>, &(count_mode, Mode::Constant))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 32;
+    const NUM_BITS: usize = 16;
+
+    fn check_count_true(name: &str, bits: &[bool], mode: Mode) {
+        let circuit_bits = bits.iter().map(|bit| Boolean::<Circuit>::new(mode, *bit)).collect::<Vec<_>>();
+        let expected = bits.iter().filter(|bit| **bit).count() as u32;
+
+        Circuit::scope(name, || {
+            let candidate = U32::count_true(&circuit_bits);
+            assert_eq!(expected, *candidate.eject_value());
+
+            let modes = vec![mode; bits.len()];
+            assert_count!(U32<Circuit>, CountTrue<Boolean = Boolean<Circuit>>, &modes);
+            assert_output_mode!(U32<Circuit>, CountTrue<Boolean = Boolean<Circuit>>, &modes, candidate);
+        });
+        Circuit::reset();
+    }
+
+    fn check_at_least(name: &str, bits: &[bool], k: u32, mode: Mode) {
+        let circuit_bits = bits.iter().map(|bit| Boolean::<Circuit>::new(mode, *bit)).collect::<Vec<_>>();
+        let expected = bits.iter().filter(|bit| **bit).count() as u32 >= k;
+
+        Circuit::scope(name, || {
+            let candidate = U32::at_least(&circuit_bits, k);
+            assert_eq!(expected, candidate.eject_value());
+
+            let modes = vec![mode; bits.len()];
+            assert_count!(U32<Circuit>, AtLeast<Boolean = Boolean<Circuit>>, &modes);
+            assert_output_mode!(U32<Circuit>, AtLeast<Boolean = Boolean<Circuit>>, &modes, candidate);
+        });
+        Circuit::reset();
+    }
+
+    fn run_test(mode: Mode) {
+        let mut rng = TestRng::default();
+
+        // Check the empty sequence.
+        check_count_true(&format!("CountTrue {mode} empty"), &[], mode);
+        check_at_least(&format!("AtLeast {mode} empty 0"), &[], 0, mode);
+        check_at_least(&format!("AtLeast {mode} empty 1"), &[], 1, mode);
+
+        for i in 0..ITERATIONS {
+            let bits = (0..NUM_BITS).map(|_| bool::rand(&mut rng)).collect::<Vec<_>>();
+            let num_true = bits.iter().filter(|bit| **bit).count() as u32;
+
+            check_count_true(&format!("CountTrue {mode} {i}"), &bits, mode);
+            // Check the exactly-k, below-k, and above-k cases.
+            check_at_least(&format!("AtLeast {mode} {i} exact"), &bits, num_true, mode);
+            check_at_least(&format!("AtLeast {mode} {i} below"), &bits, num_true + 1, mode);
+            check_at_least(&format!("AtLeast {mode} {i} above"), &bits, num_true.saturating_sub(1), mode);
+        }
+    }
+
+    #[test]
+    fn test_count_true_constant() {
+        run_test(Mode::Constant);
+    }
+
+    #[test]
+    fn test_count_true_public() {
+        run_test(Mode::Public);
+    }
+
+    #[test]
+    fn test_count_true_private() {
+        run_test(Mode::Private);
+    }
+
+    #[test]
+    fn test_count_true_all() {
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            check_count_true(&format!("CountTrue {mode} all"), &[true; NUM_BITS], mode);
+            check_at_least(&format!("AtLeast {mode} all"), &[true; NUM_BITS], NUM_BITS as u32, mode);
+            check_at_least(&format!("AtLeast {mode} more than all"), &[true; NUM_BITS], NUM_BITS as u32 + 1, mode);
+        }
+    }
+}
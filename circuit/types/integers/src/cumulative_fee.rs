@@ -0,0 +1,198 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> CumulativeFee for Integer<E, u64> {
+    type Boolean = Boolean<E>;
+
+    /// Returns `true` if the sum of `fees` does not exceed `max_total`, and enforces that this holds.
+    /// Note: The fees are summed with overflow checking, so an overflowing sequence of fees
+    /// halts for constants, and is unsatisfiable otherwise.
+    fn assert_cumulative_fee(fees: &[Self], max_total: &Self) -> Self::Boolean {
+        // Sum the fees, checking for overflow.
+        let total = match fees.split_first() {
+            Some((first, rest)) => rest.iter().fold(first.clone(), |total, fee| total.add_checked(fee)),
+            None => Integer::zero(),
+        };
+
+        // Ensure the total does not exceed the maximum.
+        let is_within_max = total.is_less_than_or_equal(max_total);
+        E::assert(&is_within_max);
+        is_within_max
+    }
+}
+
+impl<E: Environment> Metrics<dyn CumulativeFee<Boolean = Boolean<E>>> for Integer<E, u64> {
+    type Case = (Vec<Mode>, Mode);
+
+    fn count(case: &Self::Case) -> Count {
+        let (fee_modes, max_total_mode) = case;
+
+        // Compute the cost of summing the fees.
+        let (total_mode, total_count) = match fee_modes.split_first() {
+            Some((first, rest)) => rest.iter().fold((*first, Count::zero()), |(prev_mode, cumulative), curr_mode| {
+                let mode = output_mode!(Integer<E, u64>, AddChecked<Integer<E, u64>, Output = Integer<E, u64>>, &(prev_mode, *curr_mode));
+                let count = count!(Integer<E, u64>, AddChecked<Integer<E, u64>, Output = Integer<E, u64>>, &(prev_mode, *curr_mode));
+                (mode, cumulative + count)
+            }),
+            None => (Mode::Constant, count!(Integer<E, u64>, Zero<Boolean = Boolean<E>>, &())),
+        };
+
+        // Compute the cost of comparing the total to the maximum.
+        // Note: `total <= max_total` is computed as `!(max_total < total)`.
+        let compare_count =
+            count!(Integer<E, u64>, Compare<Integer<E, u64>, Output = Boolean<E>>, &(*max_total_mode, total_mode));
+
+        // Compute the cost of enforcing the comparison.
+        let assert_count = match max_total_mode.is_constant() && total_mode.is_constant() {
+            true => Count::zero(),
+            false => Count::is(0, 0, 0, 1),
+        };
+
+        total_count + compare_count + assert_count
+    }
+}
+
+impl<E: Environment> OutputMode<dyn CumulativeFee<Boolean = Boolean<E>>> for Integer<E, u64> {
+    type Case = (Vec<Mode>, Mode);
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        let (fee_modes, max_total_mode) = case;
+        match max_total_mode.is_constant() && fee_modes.iter().all(|mode| mode.is_constant()) {
+            true => Mode::Constant,
+            false => Mode::Private,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    use test_utilities::*;
+
+    const ITERATIONS: u64 = 32;
+    const NUM_FEES: usize = 4;
+
+    fn check_assert_cumulative_fee(name: &str, fees: &[u64], max_total: u64, mode_a: Mode, mode_b: Mode) {
+        let circuit_fees =
+            fees.iter().map(|fee| U64::<Circuit>::new(mode_a, console::U64::new(*fee))).collect::<Vec<_>>();
+        let circuit_max_total = U64::<Circuit>::new(mode_b, console::U64::new(max_total));
+
+        let fee_modes = vec![mode_a; fees.len()];
+        let total = fees.iter().try_fold(0u64, |total, fee| total.checked_add(*fee));
+        match total {
+            Some(total) if total <= max_total => Circuit::scope(name, || {
+                let candidate = U64::assert_cumulative_fee(&circuit_fees, &circuit_max_total);
+                assert!(candidate.eject_value());
+                assert_count!(U64<Circuit>, CumulativeFee<Boolean = Boolean<Circuit>>, &(fee_modes.clone(), mode_b));
+                assert_output_mode!(
+                    U64<Circuit>,
+                    CumulativeFee<Boolean = Boolean<Circuit>>,
+                    &(fee_modes, mode_b),
+                    candidate
+                );
+            }),
+            // If constant fees overflow, or a constant total exceeds a constant maximum, the operation must halt.
+            None if mode_a.is_constant() => check_operation_halts(
+                &circuit_fees,
+                &circuit_max_total,
+                |fees: &Vec<U64<Circuit>>, max_total: &U64<Circuit>| U64::assert_cumulative_fee(fees, max_total),
+            ),
+            _ if mode_a.is_constant() && mode_b.is_constant() => check_operation_halts(
+                &circuit_fees,
+                &circuit_max_total,
+                |fees: &Vec<U64<Circuit>>, max_total: &U64<Circuit>| U64::assert_cumulative_fee(fees, max_total),
+            ),
+            // If the total overflows or exceeds the maximum, the circuit must not be satisfied.
+            _ => Circuit::scope(name, || {
+                let _candidate = U64::assert_cumulative_fee(&circuit_fees, &circuit_max_total);
+                assert_count_fails!(U64<Circuit>, CumulativeFee<Boolean = Boolean<Circuit>>, &(fee_modes, mode_b));
+            }),
+        }
+        Circuit::reset();
+    }
+
+    fn run_test(mode_a: Mode, mode_b: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample fees that cannot overflow.
+            let fees = (0..NUM_FEES).map(|_| u32::rand(&mut rng) as u64).collect::<Vec<_>>();
+            let total = fees.iter().sum::<u64>();
+
+            // Check a total at the maximum.
+            check_assert_cumulative_fee(&format!("At {i}"), &fees, total, mode_a, mode_b);
+            // Check a total below the maximum.
+            check_assert_cumulative_fee(&format!("Below {i}"), &fees, total + 1, mode_a, mode_b);
+            // Check a total above the maximum.
+            check_assert_cumulative_fee(&format!("Above {i}"), &fees, total - 1, mode_a, mode_b);
+        }
+
+        // Check a sequence of fees that overflows.
+        check_assert_cumulative_fee("Overflow", &[u64::MAX, 1], u64::MAX, mode_a, mode_b);
+        check_assert_cumulative_fee("Overflow", &[u64::MAX / 2, u64::MAX / 2, 2], u64::MAX, mode_a, mode_b);
+        // Check an empty sequence of fees.
+        check_assert_cumulative_fee("Empty", &[], 0, mode_a, mode_b);
+    }
+
+    #[test]
+    fn test_constant_assert_cumulative_fee_constant() {
+        run_test(Mode::Constant, Mode::Constant);
+    }
+
+    #[test]
+    fn test_constant_assert_cumulative_fee_public() {
+        run_test(Mode::Constant, Mode::Public);
+    }
+
+    #[test]
+    fn test_constant_assert_cumulative_fee_private() {
+        run_test(Mode::Constant, Mode::Private);
+    }
+
+    #[test]
+    fn test_public_assert_cumulative_fee_constant() {
+        run_test(Mode::Public, Mode::Constant);
+    }
+
+    #[test]
+    fn test_public_assert_cumulative_fee_public() {
+        run_test(Mode::Public, Mode::Public);
+    }
+
+    #[test]
+    fn test_public_assert_cumulative_fee_private() {
+        run_test(Mode::Public, Mode::Private);
+    }
+
+    #[test]
+    fn test_private_assert_cumulative_fee_constant() {
+        run_test(Mode::Private, Mode::Constant);
+    }
+
+    #[test]
+    fn test_private_assert_cumulative_fee_public() {
+        run_test(Mode::Private, Mode::Public);
+    }
+
+    #[test]
+    fn test_private_assert_cumulative_fee_private() {
+        run_test(Mode::Private, Mode::Private);
+    }
+}
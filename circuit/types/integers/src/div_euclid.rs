@@ -0,0 +1,189 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> DivEuclid<Self> for Integer<E, I> {
+    type Output = Self;
+
+    /// Returns the Euclidean quotient of `self` and `other`, for signed integers.
+    /// The quotient is rounded such that the remainder `self - quotient * other` is non-negative.
+    ///
+    /// This method halts if `other` is a constant zero, or if both operands are constants and the quotient overflows.
+    /// Otherwise, the circuit is unsatisfiable if `other` is zero, or if `self` is `I::MIN` and `other` is `-1`.
+    fn div_euclid(&self, other: &Integer<E, I>) -> Self::Output {
+        // Ensure the integer type is signed.
+        if !I::is_signed() {
+            E::halt("Euclidean division is only supported for signed integers.")
+        }
+
+        let (quotient, remainder) = self.div_rem_truncated(other);
+
+        // If the remainder is negative, round the quotient away from zero,
+        // by subtracting one if `other` is positive, or adding one if `other` is negative.
+        let adjustment = Integer::ternary(other.msb(), &Integer::one(), &Integer::constant(-console::Integer::one()));
+        let adjustment = Integer::ternary(remainder.msb(), &adjustment, &Integer::zero());
+
+        // Note: This addition never overflows, as the adjustment is only non-zero if `|other| > 1`.
+        quotient.add_wrapped(&adjustment)
+    }
+}
+
+impl<E: Environment, I: IntegerType> Integer<E, I> {
+    /// Returns the truncated quotient and remainder of `self` and `other`.
+    pub(crate) fn div_rem_truncated(&self, other: &Integer<E, I>) -> (Self, Self) {
+        // Note: `div_checked` ensures that `other` is non-zero, and that the quotient does not overflow.
+        let quotient = self.div_checked(other);
+        let remainder = self.sub_wrapped(&quotient.mul_wrapped(other));
+        (quotient, remainder)
+    }
+}
+
+impl<E: Environment, I: IntegerType> Metrics<dyn DivEuclid<Integer<E, I>, Output = Integer<E, I>>> for Integer<E, I> {
+    type Case = (Mode, Mode);
+
+    fn count(case: &Self::Case) -> Count {
+        // Note: If `2 * I::BITS` exceeds the field capacity, `div_checked` falls back to long division,
+        // which incurs up to an additional `I::BITS^2` private variables and constraints.
+        let long_division = match 2 * I::BITS < E::BaseField::size_in_data_bits() as u64 {
+            true => Count::zero(),
+            false => Count::less_than(0, 0, I::BITS * I::BITS, I::BITS * I::BITS),
+        };
+
+        match (case.0, case.1) {
+            (Mode::Constant, Mode::Constant) => Count::is(7 * I::BITS, 0, 0, 0),
+            (Mode::Constant, _) => {
+                Count::less_than(11 * I::BITS, 0, (23 * I::BITS / 2) + 12, (23 * I::BITS / 2) + 22) + long_division
+            }
+            (_, Mode::Constant) => {
+                Count::less_than((11 * I::BITS) + 1, 0, (21 * I::BITS / 2) + 8, (21 * I::BITS / 2) + 18) + long_division
+            }
+            (_, _) => Count::is(10 * I::BITS, 0, (27 * I::BITS / 2) + 17, (27 * I::BITS / 2) + 29) + long_division,
+        }
+    }
+}
+
+impl<E: Environment, I: IntegerType> OutputMode<dyn DivEuclid<Integer<E, I>, Output = Integer<E, I>>>
+    for Integer<E, I>
+{
+    type Case = (Mode, Mode);
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        match (case.0, case.1) {
+            (Mode::Constant, Mode::Constant) => Mode::Constant,
+            (_, _) => Mode::Private,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    use test_utilities::*;
+
+    use core::panic::RefUnwindSafe;
+
+    const ITERATIONS: u64 = 32;
+
+    fn check_div_euclid<I: IntegerType + RefUnwindSafe>(
+        name: &str,
+        first: console::Integer<<Circuit as Environment>::Network, I>,
+        second: console::Integer<<Circuit as Environment>::Network, I>,
+        mode_a: Mode,
+        mode_b: Mode,
+    ) {
+        let a = Integer::<Circuit, I>::new(mode_a, first);
+        let b = Integer::<Circuit, I>::new(mode_b, second);
+
+        // Compute the expected value natively, returning `None` on division by zero or overflow.
+        let expected = first
+            .to_i128()
+            .unwrap()
+            .checked_div_euclid(second.to_i128().unwrap())
+            .and_then(|value| I::from(value))
+            .filter(|_| first.checked_div(&second).is_some());
+
+        match expected {
+            Some(expected) => Circuit::scope(name, || {
+                let candidate = a.div_euclid(&b);
+                assert_eq!(console::Integer::new(expected), candidate.eject_value());
+                assert_count!(DivEuclid(Integer<I>, Integer<I>) => Integer<I>, &(mode_a, mode_b));
+                assert_output_mode!(DivEuclid(Integer<I>, Integer<I>) => Integer<I>, &(mode_a, mode_b), candidate);
+            }),
+            None => match (mode_a, mode_b) {
+                (_, Mode::Constant) if second.is_zero() => check_operation_halts(&a, &b, Integer::div_euclid),
+                (Mode::Constant, Mode::Constant) => check_operation_halts(&a, &b, Integer::div_euclid),
+                _ => Circuit::scope(name, || {
+                    let _candidate = a.div_euclid(&b);
+                    assert!(!Circuit::is_satisfied_in_scope(), "(!is_satisfied_in_scope)");
+                }),
+            },
+        }
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType + RefUnwindSafe>(mode_a: Mode, mode_b: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let first = Uniform::rand(&mut rng);
+            let second = Uniform::rand(&mut rng);
+
+            check_div_euclid::<I>(&format!("DivEuclid: {i}"), first, second, mode_a, mode_b);
+            check_div_euclid::<I>(
+                &format!("DivEuclid by negative: {i}"),
+                first,
+                console::Integer::new(second.abs_wrapped().wrapping_neg()),
+                mode_a,
+                mode_b,
+            );
+            check_div_euclid::<I>(&format!("DivEuclid by one: {i}"), first, console::Integer::one(), mode_a, mode_b);
+            check_div_euclid::<I>(&format!("DivEuclid by zero: {i}"), first, console::Integer::zero(), mode_a, mode_b);
+        }
+
+        let one = console::Integer::one();
+        let two = one + one;
+        let seven = console::Integer::new(I::from(7).unwrap());
+
+        // Check that the remainder is non-negative for all combinations of signs.
+        check_div_euclid::<I>("7 / 2", seven, two, mode_a, mode_b);
+        check_div_euclid::<I>("-7 / 2", -seven, two, mode_a, mode_b);
+        check_div_euclid::<I>("7 / -2", seven, -two, mode_a, mode_b);
+        check_div_euclid::<I>("-7 / -2", -seven, -two, mode_a, mode_b);
+
+        // Check the corner cases.
+        check_div_euclid::<I>("MIN / MAX", console::Integer::MIN, console::Integer::MAX, mode_a, mode_b);
+        check_div_euclid::<I>("MAX / MIN", console::Integer::MAX, console::Integer::MIN, mode_a, mode_b);
+        check_div_euclid::<I>("-1 / MIN", -one, console::Integer::MIN, mode_a, mode_b);
+        check_div_euclid::<I>("MIN / -1", console::Integer::MIN, -one, mode_a, mode_b);
+        check_div_euclid::<I>("MIN / 0", console::Integer::MIN, console::Integer::zero(), mode_a, mode_b);
+    }
+
+    test_integer_binary!(run_test, i8, div_euclid);
+    test_integer_binary!(run_test, i16, div_euclid);
+    test_integer_binary!(run_test, i32, div_euclid);
+    test_integer_binary!(run_test, i64, div_euclid);
+    test_integer_binary!(run_test, i128, div_euclid);
+
+    #[test]
+    fn test_div_euclid_unsigned_halts() {
+        let a = Integer::<Circuit, u8>::new(Mode::Private, console::Integer::one());
+        let b = Integer::<Circuit, u8>::new(Mode::Private, console::Integer::one());
+        check_operation_halts(&a, &b, Integer::div_euclid);
+    }
+}
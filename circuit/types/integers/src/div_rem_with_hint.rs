@@ -0,0 +1,164 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> Integer<E, I> {
+    /// Returns the quotient and remainder of `self` divided by `divisor`, for unsigned integers,
+    /// by constraining the given `(quotient, remainder)` hint instead of deriving it from the ejected values.
+    ///
+    /// The circuit is satisfied if and only if `self == quotient * divisor + remainder` and `remainder < divisor`,
+    /// which also ensures that `divisor` is non-zero.
+    /// This method is only supported when the product of two integers fits in the base field.
+    pub fn div_rem_with_hint(&self, divisor: &Self, hint: (Self, Self)) -> (Self, Self) {
+        Self::div_rem_field_with_hint(&self.to_field(), divisor, hint)
+    }
+
+    /// Returns the quotient and remainder of the given `dividend` divided by `divisor`, for unsigned integers,
+    /// by constraining the given `(quotient, remainder)` hint, where the dividend is a base field element,
+    /// such as the double-width product of two integers.
+    ///
+    /// The circuit is satisfied if and only if `dividend == quotient * divisor + remainder` and `remainder < divisor`.
+    pub(crate) fn div_rem_field_with_hint(dividend: &Field<E>, divisor: &Self, hint: (Self, Self)) -> (Self, Self) {
+        // Ensure the integer type is unsigned.
+        if I::is_signed() {
+            E::halt("Division with a hint is only supported for unsigned integers.")
+        }
+        // Ensure the product of two integers fits in the base field.
+        if 2 * I::BITS >= E::BaseField::size_in_data_bits() as u64 {
+            E::halt("Division with a hint is only supported for integers whose product fits in the base field.")
+        }
+
+        let (quotient, remainder) = hint;
+
+        // Ensure that Euclidean division holds for these values in the base field.
+        E::assert_eq(dividend, quotient.to_field() * divisor.to_field() + remainder.to_field());
+
+        // Ensure that the remainder is less than the divisor.
+        E::assert(remainder.is_less_than(divisor));
+
+        (quotient, remainder)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utilities::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    use core::panic::RefUnwindSafe;
+
+    const ITERATIONS: u64 = 64;
+
+    fn check_div_rem_with_hint<I: IntegerType>(
+        name: &str,
+        dividend: console::Integer<<Circuit as Environment>::Network, I>,
+        divisor: console::Integer<<Circuit as Environment>::Network, I>,
+        hint: (
+            console::Integer<<Circuit as Environment>::Network, I>,
+            console::Integer<<Circuit as Environment>::Network, I>,
+        ),
+        mode_a: Mode,
+        mode_b: Mode,
+    ) {
+        let a = Integer::<Circuit, I>::new(mode_a, dividend);
+        let b = Integer::<Circuit, I>::new(mode_b, divisor);
+
+        // Check whether the hint is the correct quotient and remainder.
+        let is_correct = match divisor.is_zero() {
+            true => false,
+            false => (*hint.0, *hint.1) == (dividend.wrapping_div(&divisor), dividend.wrapping_rem(&divisor)),
+        };
+
+        Circuit::scope(name, || {
+            let hint = (Integer::new(Mode::Private, hint.0), Integer::new(Mode::Private, hint.1));
+            let (quotient, remainder) = a.div_rem_with_hint(&b, hint);
+            if is_correct {
+                assert_eq!(dividend.wrapping_div(&divisor), *quotient.eject_value());
+                assert_eq!(dividend.wrapping_rem(&divisor), *remainder.eject_value());
+            }
+            assert_eq!(is_correct, Circuit::is_satisfied_in_scope(), "(is_satisfied_in_scope)");
+        });
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType>(mode_a: Mode, mode_b: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let dividend: console::Integer<_, I> = Uniform::rand(&mut rng);
+            let divisor: console::Integer<_, I> = Uniform::rand(&mut rng);
+            if divisor.is_zero() {
+                continue;
+            }
+            let quotient = console::Integer::new(dividend.wrapping_div(&divisor));
+            let remainder = console::Integer::new(dividend.wrapping_rem(&divisor));
+
+            // Check that a correct hint is satisfied.
+            let name = format!("DivRemWithHint: {mode_a} / {mode_b} correct {i}");
+            check_div_rem_with_hint::<I>(&name, dividend, divisor, (quotient, remainder), mode_a, mode_b);
+
+            // Check that an incorrect quotient is not satisfied.
+            let wrong_quotient = console::Integer::new(quotient.wrapping_add(&I::one()));
+            let name = format!("DivRemWithHint: {mode_a} / {mode_b} wrong quotient {i}");
+            check_div_rem_with_hint::<I>(&name, dividend, divisor, (wrong_quotient, remainder), mode_a, mode_b);
+
+            // Check that a remainder that is not less than the divisor is not satisfied,
+            // even if the Euclidean division holds.
+            if !quotient.is_zero() {
+                let smaller_quotient = console::Integer::new(quotient.wrapping_sub(&I::one()));
+                let larger_remainder = console::Integer::new(remainder.wrapping_add(&divisor));
+                let name = format!("DivRemWithHint: {mode_a} / {mode_b} wrong remainder {i}");
+                check_div_rem_with_hint::<I>(
+                    &name,
+                    dividend,
+                    divisor,
+                    (smaller_quotient, larger_remainder),
+                    mode_a,
+                    mode_b,
+                );
+            }
+        }
+
+        // Check that a zero divisor is not satisfied.
+        let dividend = Uniform::rand(&mut rng);
+        let name = format!("DivRemWithHint: {mode_a} / {mode_b} zero");
+        let hint = (console::Integer::zero(), dividend);
+        check_div_rem_with_hint::<I>(&name, dividend, console::Integer::zero(), hint, mode_a, mode_b);
+    }
+
+    test_integer_binary!(run_test, u8, div_rem_with_hint);
+    test_integer_binary!(run_test, u16, div_rem_with_hint);
+    test_integer_binary!(run_test, u32, div_rem_with_hint);
+    test_integer_binary!(run_test, u64, div_rem_with_hint);
+
+    fn check_div_rem_with_hint_halts<I: IntegerType + RefUnwindSafe>() {
+        let one = Integer::<Circuit, I>::new(Mode::Private, console::Integer::one());
+        check_unary_operation_halts(&one, |one: &Integer<Circuit, I>| {
+            one.div_rem_with_hint(one, (one.clone(), Integer::zero()))
+        });
+    }
+
+    #[test]
+    fn test_div_rem_with_hint_halts() {
+        // Signed integers are not supported.
+        check_div_rem_with_hint_halts::<i8>();
+        check_div_rem_with_hint_halts::<i64>();
+        // The product of two 128-bit integers does not fit in the base field.
+        check_div_rem_with_hint_halts::<u128>();
+    }
+}
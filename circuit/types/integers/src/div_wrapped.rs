@@ -76,14 +76,8 @@ impl<E: Environment, I: IntegerType> Integer<E, I> {
         let quotient = Integer::new(Mode::Private, console::Integer::new(dividend_value.wrapping_div(&divisor_value)));
         let remainder = Integer::new(Mode::Private, console::Integer::new(dividend_value.wrapping_rem(&divisor_value)));
 
-        // Ensure that Euclidean division holds for these values in the base field.
-        E::assert_eq(self.to_field(), quotient.to_field() * other.to_field() + remainder.to_field());
-
-        // Ensure that the remainder is less than the divisor.
-        E::assert(remainder.is_less_than(other));
-
-        // Return the quotient of `self` and `other`.
-        (quotient, remainder)
+        // Ensure that Euclidean division holds for these values, and return the quotient and remainder.
+        self.div_rem_with_hint(other, (quotient, remainder))
     }
 
     /// Divides `self` by `other`, using binary long division returning the quotient and remainder
@@ -0,0 +1,164 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> DotProduct for Integer<E, I> {
+    /// Returns the sum of the pairwise products of `a` and `b`, wrapping around at the boundary of the type.
+    /// This method halts if `a` and `b` differ in length, and returns zero if they are empty.
+    fn dot(a: &[Self], b: &[Self]) -> Self {
+        // Ensure the vectors are of equal length.
+        if a.len() != b.len() {
+            E::halt(format!(
+                "Cannot compute the dot product of vectors of different lengths ({} and {})",
+                a.len(),
+                b.len()
+            ))
+        }
+
+        // Determine the variable mode.
+        if a.iter().chain(b).all(|value| value.is_constant()) {
+            // Compute the dot product and return the new constant.
+            let sum = a
+                .iter()
+                .zip_eq(b)
+                .fold(I::zero(), |sum, (a, b)| sum.wrapping_add(&(*a.eject_value()).wrapping_mul(&b.eject_value())));
+            Integer::constant(console::Integer::new(sum))
+        } else if Self::fits_in_field(a.len()) {
+            // Instead of multiplying and adding the integers directly, the integers are converted into
+            // field elements, and the dot product is computed in the field, as each product costs one constraint.
+            // Note: The values are interpreted as unsigned, which preserves wrapping semantics.
+            let sum = a.iter().zip_eq(b).fold(Field::zero(), |sum, (a, b)| sum + a.to_field() * b.to_field());
+
+            // Extract the integer bits from the field element, and remove any carry bits.
+            let mut bits_le = sum.to_lower_bits_le(Self::num_sum_bits(a.len()));
+            bits_le.truncate(I::BITS as usize);
+
+            // Return the dot product.
+            Integer { bits_le, phantom: Default::default() }
+        } else {
+            // Otherwise, the dot product may overflow the field, so it is computed with wrapping integer operations.
+            a.iter().zip_eq(b).fold(Integer::zero(), |sum, (a, b)| sum.add_wrapped(&a.mul_wrapped(b)))
+        }
+    }
+}
+
+impl<E: Environment, I: IntegerType> Metrics<dyn DotProduct> for Integer<E, I> {
+    type Case = Vec<(Mode, Mode)>;
+
+    fn count(case: &Self::Case) -> Count {
+        if case.iter().all(|(a, b)| a.is_constant() && b.is_constant()) {
+            Count::is(I::BITS, 0, 0, 0)
+        } else if Self::fits_in_field(case.len()) {
+            // The cost of each product of variables, and of extracting the bits of the dot product.
+            let num_products = case.iter().filter(|(a, b)| !a.is_constant() && !b.is_constant()).count() as u64;
+            let num_bits = Self::num_sum_bits(case.len()) as u64;
+            Count::is(0, 0, num_products + num_bits, num_products + num_bits + 1)
+        } else {
+            // The cost of multiplying each pair, and accumulating the products.
+            let (_, count) = case.iter().fold((Mode::Constant, Count::is(I::BITS, 0, 0, 0)), |(sum_mode, count), modes| {
+                let product_count = count!(Integer<E, I>, MulWrapped<Integer<E, I>, Output = Integer<E, I>>, modes);
+                let product_mode = output_mode!(Integer<E, I>, MulWrapped<Integer<E, I>, Output = Integer<E, I>>, modes);
+                let sum_count =
+                    count!(Integer<E, I>, AddWrapped<Integer<E, I>, Output = Integer<E, I>>, &(sum_mode, product_mode));
+                let sum_mode =
+                    output_mode!(Integer<E, I>, AddWrapped<Integer<E, I>, Output = Integer<E, I>>, &(sum_mode, product_mode));
+                (sum_mode, count + product_count + sum_count)
+            });
+            count
+        }
+    }
+}
+
+impl<E: Environment, I: IntegerType> OutputMode<dyn DotProduct> for Integer<E, I> {
+    type Case = Vec<(Mode, Mode)>;
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        match case.iter().all(|(a, b)| a.is_constant() && b.is_constant()) {
+            true => Mode::Constant,
+            false => Mode::Private,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    use test_utilities::*;
+
+    const ITERATIONS: u64 = 16;
+
+    type NativeInteger<I> = console::Integer<<Circuit as Environment>::Network, I>;
+
+    fn check_dot<I: IntegerType>(name: &str, a: &[I], b: &[I], mode_a: Mode, mode_b: Mode) {
+        // Compute the expected dot product natively.
+        let expected = a.iter().zip_eq(b).fold(I::zero(), |sum, (a, b)| sum.wrapping_add(&a.wrapping_mul(b)));
+
+        let a = a.iter().map(|a| Integer::<Circuit, I>::new(mode_a, console::Integer::new(*a))).collect::<Vec<_>>();
+        let b = b.iter().map(|b| Integer::<Circuit, I>::new(mode_b, console::Integer::new(*b))).collect::<Vec<_>>();
+        let modes = vec![(mode_a, mode_b); a.len()];
+
+        Circuit::scope(name, || {
+            let candidate = Integer::dot(&a, &b);
+            assert_eq!(expected, *candidate.eject_value());
+            assert_count!(Integer<Circuit, I>, DotProduct, &modes);
+            assert_output_mode!(Integer<Circuit, I>, DotProduct, &modes, candidate);
+        });
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType>(mode_a: Mode, mode_b: Mode) {
+        let mut rng = TestRng::default();
+
+        for num_terms in 0..=4 {
+            for i in 0..ITERATIONS {
+                let a = (0..num_terms).map(|_| *NativeInteger::<I>::rand(&mut rng)).collect::<Vec<_>>();
+                let b = (0..num_terms).map(|_| *NativeInteger::<I>::rand(&mut rng)).collect::<Vec<_>>();
+                check_dot::<I>(&format!("Dot: {mode_a} {mode_b} {num_terms} {i}"), &a, &b, mode_a, mode_b);
+            }
+        }
+
+        // Check the boundary values, which wrap around.
+        let (min, max, one) = (I::min_value(), I::max_value(), I::one());
+        check_dot::<I>("MAX * MAX", &[max], &[max], mode_a, mode_b);
+        check_dot::<I>("MIN * MAX + MAX * MIN", &[min, max], &[max, min], mode_a, mode_b);
+        check_dot::<I>("MAX + 1", &[one, max], &[one, one], mode_a, mode_b);
+    }
+
+    fn assert_length_mismatch_halts<I: IntegerType + core::panic::UnwindSafe>(mode_a: Mode, mode_b: Mode) {
+        let a = vec![Integer::<Circuit, I>::new(mode_a, console::Integer::new(I::one())); 2];
+        let b = vec![Integer::<Circuit, I>::new(mode_b, console::Integer::new(I::one())); 3];
+        check_operation_halts(a, b, |a: Vec<Integer<Circuit, I>>, b: Vec<Integer<Circuit, I>>| Integer::dot(&a, &b));
+    }
+
+    test_integer_binary!(run_test, i8, dot);
+    test_integer_binary!(run_test, i16, dot);
+    test_integer_binary!(run_test, i32, dot);
+    test_integer_binary!(run_test, i64, dot);
+    test_integer_binary!(run_test, i128, dot);
+
+    test_integer_binary!(run_test, u8, dot);
+    test_integer_binary!(run_test, u16, dot);
+    test_integer_binary!(run_test, u32, dot);
+    test_integer_binary!(run_test, u64, dot);
+    test_integer_binary!(run_test, u128, dot);
+
+    test_integer_binary!(assert_length_mismatch_halts, u8, dot_length_mismatch);
+    test_integer_binary!(assert_length_mismatch_halts, i64, dot_length_mismatch);
+}
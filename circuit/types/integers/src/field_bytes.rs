@@ -0,0 +1,128 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use snarkvm_circuit_environment::Circuit;
+    use snarkvm_utilities::ToBytes as _;
+
+    const ITERATIONS: u64 = 32;
+
+    fn check_round_trip(mode: Mode, rng: &mut TestRng) {
+        for i in 0..ITERATIONS {
+            let expected: console::Field<<Circuit as Environment>::Network> = Uniform::rand(rng);
+            let candidate = Field::<Circuit>::new(mode, expected);
+
+            Circuit::scope(format!("ToFieldBytes {mode} {i}"), || {
+                let bytes: Vec<U8<Circuit>> = candidate.to_bytes_le();
+                assert_eq!(32, bytes.len());
+                assert_eq!(
+                    expected.to_bytes_le().unwrap(),
+                    bytes.iter().map(|byte| *byte.eject_value()).collect::<Vec<_>>()
+                );
+                assert_count!(Field<Circuit>, ToFieldBytes<U8<Circuit>>, &mode);
+                assert_output_mode!(Field<Circuit>, ToFieldBytes<U8<Circuit>>, &mode, bytes[0]);
+            });
+            Circuit::reset();
+
+            let bytes = expected
+                .to_bytes_le()
+                .unwrap()
+                .into_iter()
+                .map(|byte| U8::<Circuit>::new(mode, console::Integer::new(byte)))
+                .collect::<Vec<_>>();
+            let modes = bytes.iter().map(|byte| byte.eject_mode()).collect::<Vec<_>>();
+
+            Circuit::scope(format!("FromFieldBytes {mode} {i}"), || {
+                let candidate = Field::from_bytes_le(&bytes);
+                assert_eq!(expected, candidate.eject_value());
+                assert_count!(Field<Circuit>, FromFieldBytes<U8<Circuit>>, &modes);
+                assert_output_mode!(Field<Circuit>, FromFieldBytes<U8<Circuit>>, &modes, candidate);
+            });
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+    }
+
+    fn check_non_canonical(mode: Mode) {
+        // Construct the bytes of the field modulus, which is not a canonical field element.
+        let mut bytes_le = <Circuit as Environment>::BaseField::characteristic()
+            .iter()
+            .flat_map(|limb| limb.to_le_bytes())
+            .collect::<Vec<_>>();
+        bytes_le.truncate(32);
+
+        let bytes =
+            bytes_le.into_iter().map(|byte| U8::<Circuit>::new(mode, console::Integer::new(byte))).collect::<Vec<_>>();
+        match mode.is_constant() {
+            true => {
+                let result = std::panic::catch_unwind(|| Field::from_bytes_le(&bytes));
+                assert!(result.is_err());
+            }
+            false => {
+                let _candidate = Field::from_bytes_le(&bytes);
+                assert!(!Circuit::is_satisfied());
+            }
+        }
+        Circuit::reset();
+
+        // Construct bytes with a nonzero excess bit, beyond the field size in bits.
+        let mut bytes_le = vec![0u8; 32];
+        bytes_le[31] = 0x80;
+        let bytes =
+            bytes_le.into_iter().map(|byte| U8::<Circuit>::new(mode, console::Integer::new(byte))).collect::<Vec<_>>();
+        match mode.is_constant() {
+            true => {
+                let result = std::panic::catch_unwind(|| Field::from_bytes_le(&bytes));
+                assert!(result.is_err());
+            }
+            false => {
+                let _candidate = Field::from_bytes_le(&bytes);
+                assert!(!Circuit::is_satisfied());
+            }
+        }
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_field_bytes_constant() {
+        let mut rng = TestRng::default();
+        check_round_trip(Mode::Constant, &mut rng);
+        check_non_canonical(Mode::Constant);
+    }
+
+    #[test]
+    fn test_field_bytes_public() {
+        let mut rng = TestRng::default();
+        check_round_trip(Mode::Public, &mut rng);
+        check_non_canonical(Mode::Public);
+    }
+
+    #[test]
+    fn test_field_bytes_private() {
+        let mut rng = TestRng::default();
+        check_round_trip(Mode::Private, &mut rng);
+        check_non_canonical(Mode::Private);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_from_bytes_le_wrong_length() {
+        let bytes = vec![U8::<Circuit>::new(Mode::Private, console::Integer::new(0u8)); 31];
+        let _candidate = Field::from_bytes_le(&bytes);
+    }
+}
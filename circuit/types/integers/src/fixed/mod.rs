@@ -0,0 +1,114 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+mod add;
+mod mul;
+mod sub;
+
+use super::*;
+
+/// A fixed-point decimal with `SCALE` decimal places, represented by the integer `value * 10^SCALE`.
+///
+/// Operations on fixed-point decimals are checked for overflow, and any fractional digits
+/// beyond `SCALE` decimal places are truncated (i.e. rounded towards zero).
+#[derive(Clone)]
+pub struct Fixed<E: Environment, I: IntegerType, const SCALE: u32> {
+    /// The fixed-point decimal, scaled by `10^SCALE`.
+    integer: Integer<E, I>,
+}
+
+impl<E: Environment, I: IntegerType, const SCALE: u32> Fixed<E, I, SCALE> {
+    /// Initializes a new fixed-point decimal from its scaled integer representation.
+    pub fn from_integer(integer: Integer<E, I>) -> Self {
+        Self { integer }
+    }
+
+    /// Returns the scaled integer representation of the fixed-point decimal.
+    pub fn integer(&self) -> &Integer<E, I> {
+        &self.integer
+    }
+
+    /// Returns the scaling factor `10^SCALE`.
+    /// Halts if the scaling factor does not fit in the integer type.
+    pub fn scale() -> I {
+        match I::from(10u8).and_then(|ten| ten.checked_pow(&SCALE)) {
+            Some(scale) => scale,
+            None => E::halt(format!("The scale 10^{SCALE} does not fit in the integer type.")),
+        }
+    }
+}
+
+#[cfg(console)]
+impl<E: Environment, I: IntegerType, const SCALE: u32> Inject for Fixed<E, I, SCALE> {
+    type Primitive = console::Integer<E::Network, I>;
+
+    /// Initializes a new fixed-point decimal from its scaled integer representation.
+    fn new(mode: Mode, value: Self::Primitive) -> Self {
+        Self::from_integer(Integer::new(mode, value))
+    }
+}
+
+#[cfg(console)]
+impl<E: Environment, I: IntegerType, const SCALE: u32> Eject for Fixed<E, I, SCALE> {
+    type Primitive = console::Integer<E::Network, I>;
+
+    /// Ejects the mode of the fixed-point decimal.
+    fn eject_mode(&self) -> Mode {
+        self.integer.eject_mode()
+    }
+
+    /// Ejects the scaled integer representation of the fixed-point decimal.
+    fn eject_value(&self) -> Self::Primitive {
+        self.integer.eject_value()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 128;
+
+    #[test]
+    fn test_scale() {
+        assert_eq!(1, Fixed::<Circuit, u8, 0>::scale());
+        assert_eq!(100, Fixed::<Circuit, u8, 2>::scale());
+        assert_eq!(10_000, Fixed::<Circuit, u32, 4>::scale());
+        assert_eq!(1_000_000, Fixed::<Circuit, u64, 6>::scale());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_scale_overflow() {
+        let _ = Fixed::<Circuit, u8, 3>::scale();
+    }
+
+    #[test]
+    fn test_new() {
+        let mut rng = TestRng::default();
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            for _ in 0..ITERATIONS {
+                let expected = Uniform::rand(&mut rng);
+                let candidate = Fixed::<Circuit, u64, 6>::new(mode, expected);
+                assert_eq!(expected, candidate.eject_value());
+                assert_eq!(mode, candidate.eject_mode());
+                assert_eq!(expected, candidate.integer().eject_value());
+            }
+        }
+    }
+}
@@ -0,0 +1,206 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType, const SCALE: u32> Mul<Fixed<E, I, SCALE>> for Fixed<E, I, SCALE> {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self::Output {
+        &self * &other
+    }
+}
+
+impl<E: Environment, I: IntegerType, const SCALE: u32> Mul<Fixed<E, I, SCALE>> for &Fixed<E, I, SCALE> {
+    type Output = Fixed<E, I, SCALE>;
+
+    fn mul(self, other: Fixed<E, I, SCALE>) -> Self::Output {
+        self * &other
+    }
+}
+
+impl<E: Environment, I: IntegerType, const SCALE: u32> Mul<&Fixed<E, I, SCALE>> for Fixed<E, I, SCALE> {
+    type Output = Self;
+
+    fn mul(self, other: &Self) -> Self::Output {
+        &self * other
+    }
+}
+
+impl<E: Environment, I: IntegerType, const SCALE: u32> Mul<&Fixed<E, I, SCALE>> for &Fixed<E, I, SCALE> {
+    type Output = Fixed<E, I, SCALE>;
+
+    /// Returns the product of `self` and `other`, checking for overflow.
+    /// The product is divided by `10^SCALE` with truncation, i.e. the fractional digits beyond
+    /// `SCALE` decimal places are discarded.
+    ///
+    /// This operation is only supported for unsigned integers, where the product of two integers
+    /// fits in the base field (i.e. `u8`, `u16`, `u32`, and `u64`).
+    fn mul(self, other: &Fixed<E, I, SCALE>) -> Self::Output {
+        // Ensure the integer type is unsigned, and the product fits in the base field.
+        if I::is_signed() || 2 * I::BITS >= E::BaseField::size_in_data_bits() as u64 {
+            E::halt(format!("Fixed-point multiplication is not supported for {}", I::type_name()))
+        }
+
+        // Compute the quotient and remainder of the product divided by the scale.
+        let scale = Fixed::<E, I, SCALE>::scale();
+        let (a, b) = (&self.integer, &other.integer);
+        let product =
+            (*a.eject_value()).to_u128().unwrap_or_default() * (*b.eject_value()).to_u128().unwrap_or_default();
+        let divisor = scale.to_u128().unwrap_or(1);
+        // Note: If the quotient overflows, it is replaced with zero, which does not satisfy the constraint below.
+        let quotient = console::Integer::new(I::from(product / divisor).unwrap_or_else(I::zero));
+        let remainder = console::Integer::new(I::from(product % divisor).unwrap_or_else(I::zero));
+
+        if a.is_constant() && b.is_constant() {
+            // Ensure the product does not overflow.
+            if I::from(product / divisor).is_none() {
+                E::halt("Integer overflow on fixed-point multiplication of two constants")
+            }
+            return Fixed::from_integer(Integer::constant(quotient));
+        }
+
+        // Inject the quotient and remainder. Note: This also ensures they are within the integer range.
+        let hint = (Integer::<E, I>::new(Mode::Private, quotient), Integer::<E, I>::new(Mode::Private, remainder));
+
+        // Ensure that `a * b == quotient * scale + remainder` and `remainder < scale`.
+        // Note: This is safe as the product of two integers is less than the base field modulus.
+        let product = a.to_field() * b.to_field();
+        let scale = Integer::<E, I>::constant(console::Integer::new(scale));
+        let (quotient, _) = Integer::div_rem_field_with_hint(&product, &scale, hint);
+
+        Fixed::from_integer(quotient)
+    }
+}
+
+impl<E: Environment, I: IntegerType, const SCALE: u32> Metrics<dyn Mul<Fixed<E, I, SCALE>, Output = Fixed<E, I, SCALE>>>
+    for Fixed<E, I, SCALE>
+{
+    type Case = (Mode, Mode);
+
+    fn count(case: &Self::Case) -> Count {
+        match (case.0, case.1) {
+            (Mode::Constant, Mode::Constant) => Count::is(I::BITS, 0, 0, 0),
+            (_, _) => {
+                // Compute the cost of the product, of the quotient and remainder, and of checking the division.
+                let product_count = count!(Field<E>, Mul<Field<E>, Output = Field<E>>, case);
+                let remainder_count = count!(Integer<E, I>, Compare<Integer<E, I>, Output = Boolean<E>>, &(Mode::Private, Mode::Constant));
+                Count::is(I::BITS, 0, 2 * I::BITS, 2 * I::BITS + 2) + product_count + remainder_count
+            }
+        }
+    }
+}
+
+impl<E: Environment, I: IntegerType, const SCALE: u32>
+    OutputMode<dyn Mul<Fixed<E, I, SCALE>, Output = Fixed<E, I, SCALE>>> for Fixed<E, I, SCALE>
+{
+    type Case = (Mode, Mode);
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        match (case.0, case.1) {
+            (Mode::Constant, Mode::Constant) => Mode::Constant,
+            (_, _) => Mode::Private,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 64;
+
+    fn check_mul<I: IntegerType, const SCALE: u32>(name: &str, first: u128, second: u128, mode_a: Mode, mode_b: Mode) {
+        let scale = Fixed::<Circuit, I, SCALE>::scale().to_u128().unwrap();
+        let to_integer = |value: u128| console::Integer::new(I::from(value).unwrap());
+
+        let a = Fixed::<Circuit, I, SCALE>::new(mode_a, to_integer(first));
+        let b = Fixed::<Circuit, I, SCALE>::new(mode_b, to_integer(second));
+        // Compute the expected product, truncating the fractional digits beyond `SCALE` decimal places.
+        let expected = to_integer(first * second / scale);
+        Circuit::scope(name, || {
+            let candidate = &a * &b;
+            assert_eq!(expected, candidate.eject_value());
+            assert_count!(Fixed<Circuit, I, SCALE>, Mul<Fixed<Circuit, I, SCALE>, Output = Fixed<Circuit, I, SCALE>>, &(mode_a, mode_b));
+            assert_output_mode!(
+                Fixed<Circuit, I, SCALE>,
+                Mul<Fixed<Circuit, I, SCALE>, Output = Fixed<Circuit, I, SCALE>>,
+                &(mode_a, mode_b),
+                candidate
+            );
+        });
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType, const SCALE: u32>(mode_a: Mode, mode_b: Mode) {
+        let mut rng = TestRng::default();
+
+        // Sample operands whose product fits in the integer type.
+        let bound = 1u128 << (I::BITS / 2);
+        for i in 0..ITERATIONS {
+            let first = u128::rand(&mut rng) % bound;
+            let second = u128::rand(&mut rng) % bound;
+            check_mul::<I, SCALE>(&format!("Mul: {mode_a} {mode_b} {i}"), first, second, mode_a, mode_b);
+        }
+    }
+
+    #[test]
+    fn test_mul_examples() {
+        for (mode_a, mode_b) in
+            [(Mode::Constant, Mode::Constant), (Mode::Private, Mode::Constant), (Mode::Public, Mode::Private)]
+        {
+            // 1.50 * 2.25 = 3.375, which truncates to 3.37.
+            check_mul::<u32, 2>("Mul: 150 * 225", 150, 225, mode_a, mode_b);
+            // 0.999999 * 0.999999 = 0.999998000001, which truncates to 0.999998.
+            check_mul::<u64, 6>("Mul: 999999 * 999999", 999_999, 999_999, mode_a, mode_b);
+            // 12.3 * 0.1 = 1.23, which truncates to 1.2.
+            check_mul::<u8, 1>("Mul: 123 * 1", 123, 1, mode_a, mode_b);
+        }
+    }
+
+    #[test]
+    fn test_mul() {
+        for (mode_a, mode_b) in [
+            (Mode::Constant, Mode::Constant),
+            (Mode::Constant, Mode::Public),
+            (Mode::Constant, Mode::Private),
+            (Mode::Public, Mode::Constant),
+            (Mode::Public, Mode::Public),
+            (Mode::Public, Mode::Private),
+            (Mode::Private, Mode::Constant),
+            (Mode::Private, Mode::Public),
+            (Mode::Private, Mode::Private),
+        ] {
+            run_test::<u8, 1>(mode_a, mode_b);
+            run_test::<u16, 2>(mode_a, mode_b);
+            run_test::<u32, 4>(mode_a, mode_b);
+            run_test::<u64, 6>(mode_a, mode_b);
+        }
+    }
+
+    #[test]
+    fn test_mul_overflow_fails() {
+        // 25.5 * 25.5 = 650.25, which does not fit in a `u8` with one decimal place.
+        let a = Fixed::<Circuit, u8, 1>::new(Mode::Private, console::Integer::new(255));
+        let b = Fixed::<Circuit, u8, 1>::new(Mode::Private, console::Integer::new(255));
+        Circuit::scope("Mul: overflow", || {
+            let _candidate = &a * &b;
+            assert!(!Circuit::is_satisfied_in_scope());
+        });
+        Circuit::reset();
+    }
+}
@@ -0,0 +1,130 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType, const SCALE: u32> Sub<Fixed<E, I, SCALE>> for Fixed<E, I, SCALE> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self::Output {
+        &self - &other
+    }
+}
+
+impl<E: Environment, I: IntegerType, const SCALE: u32> Sub<Fixed<E, I, SCALE>> for &Fixed<E, I, SCALE> {
+    type Output = Fixed<E, I, SCALE>;
+
+    fn sub(self, other: Fixed<E, I, SCALE>) -> Self::Output {
+        self - &other
+    }
+}
+
+impl<E: Environment, I: IntegerType, const SCALE: u32> Sub<&Fixed<E, I, SCALE>> for Fixed<E, I, SCALE> {
+    type Output = Self;
+
+    fn sub(self, other: &Self) -> Self::Output {
+        &self - other
+    }
+}
+
+impl<E: Environment, I: IntegerType, const SCALE: u32> Sub<&Fixed<E, I, SCALE>> for &Fixed<E, I, SCALE> {
+    type Output = Fixed<E, I, SCALE>;
+
+    /// Returns the difference of `self` and `other`, checking for overflow.
+    /// Note: As both operands have the same scale, the difference is exact.
+    fn sub(self, other: &Fixed<E, I, SCALE>) -> Self::Output {
+        Fixed::from_integer(self.integer.sub_checked(&other.integer))
+    }
+}
+
+impl<E: Environment, I: IntegerType, const SCALE: u32> Metrics<dyn Sub<Fixed<E, I, SCALE>, Output = Fixed<E, I, SCALE>>>
+    for Fixed<E, I, SCALE>
+{
+    type Case = (Mode, Mode);
+
+    fn count(case: &Self::Case) -> Count {
+        count!(Integer<E, I>, SubChecked<Integer<E, I>, Output = Integer<E, I>>, case)
+    }
+}
+
+impl<E: Environment, I: IntegerType, const SCALE: u32>
+    OutputMode<dyn Sub<Fixed<E, I, SCALE>, Output = Fixed<E, I, SCALE>>> for Fixed<E, I, SCALE>
+{
+    type Case = (Mode, Mode);
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        output_mode!(Integer<E, I>, SubChecked<Integer<E, I>, Output = Integer<E, I>>, case)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 64;
+
+    fn check_sub<I: IntegerType, const SCALE: u32>(name: &str, first: u128, second: u128, mode_a: Mode, mode_b: Mode) {
+        let to_integer = |value: u128| console::Integer::new(I::from(value).unwrap());
+
+        let a = Fixed::<Circuit, I, SCALE>::new(mode_a, to_integer(first));
+        let b = Fixed::<Circuit, I, SCALE>::new(mode_b, to_integer(second));
+        let expected = to_integer(first - second);
+        Circuit::scope(name, || {
+            let candidate = &a - &b;
+            assert_eq!(expected, candidate.eject_value());
+            assert_count!(Fixed<Circuit, I, SCALE>, Sub<Fixed<Circuit, I, SCALE>, Output = Fixed<Circuit, I, SCALE>>, &(mode_a, mode_b));
+            assert_output_mode!(
+                Fixed<Circuit, I, SCALE>,
+                Sub<Fixed<Circuit, I, SCALE>, Output = Fixed<Circuit, I, SCALE>>,
+                &(mode_a, mode_b),
+                candidate
+            );
+        });
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType, const SCALE: u32>(mode_a: Mode, mode_b: Mode) {
+        let mut rng = TestRng::default();
+
+        let bound = I::MAX.to_u128().unwrap();
+        for i in 0..ITERATIONS {
+            // Sample operands whose difference is non-negative.
+            let first = u128::rand(&mut rng) % bound;
+            let second = u128::rand(&mut rng) % (first + 1);
+            check_sub::<I, SCALE>(&format!("Sub: {mode_a} {mode_b} {i}"), first, second, mode_a, mode_b);
+        }
+    }
+
+    #[test]
+    fn test_sub() {
+        for (mode_a, mode_b) in [
+            (Mode::Constant, Mode::Constant),
+            (Mode::Constant, Mode::Public),
+            (Mode::Constant, Mode::Private),
+            (Mode::Public, Mode::Constant),
+            (Mode::Public, Mode::Public),
+            (Mode::Public, Mode::Private),
+            (Mode::Private, Mode::Constant),
+            (Mode::Private, Mode::Public),
+            (Mode::Private, Mode::Private),
+        ] {
+            run_test::<u8, 1>(mode_a, mode_b);
+            run_test::<u32, 4>(mode_a, mode_b);
+            run_test::<u64, 6>(mode_a, mode_b);
+        }
+    }
+}
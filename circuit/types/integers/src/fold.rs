@@ -0,0 +1,96 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> Integer<E, I> {
+    /// Returns the result of applying `f` to the accumulator and each item in order, starting from `init`.
+    ///
+    /// This only threads the accumulator through the items, so the constraint cost is the sum of
+    /// the costs of each application of `f`. If `items` is empty, `init` is returned.
+    pub fn fold<F>(init: Self, items: &[Self], f: F) -> Self
+    where
+        F: Fn(Self, &Self) -> Self,
+    {
+        items.iter().fold(init, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_integer_unary;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 16;
+
+    fn check_fold_max<I: IntegerType>(mode: Mode, values: &[I]) {
+        // Compute the expected maximum natively.
+        let init = I::MIN;
+        let expected = values.iter().fold(init, |max, value| if max < *value { *value } else { max });
+
+        let init = Integer::<Circuit, I>::constant(console::Integer::new(init));
+        let items = values.iter().map(|value| Integer::new(mode, console::Integer::new(*value))).collect::<Vec<_>>();
+
+        let max = |max: Integer<Circuit, I>, item: &Integer<Circuit, I>| {
+            Integer::ternary(&max.is_less_than(item), item, &max)
+        };
+
+        // Compute the cost of applying the closure to each item directly.
+        let expected_count = Circuit::scope(format!("Direct max: {mode} {}", values.len()), || {
+            let mut candidate = init.clone();
+            for item in &items {
+                candidate = max(candidate, item);
+            }
+            assert_eq!(expected, *candidate.eject_value());
+            Circuit::count_in_scope()
+        });
+
+        Circuit::scope(format!("Fold max: {mode} {}", values.len()), || {
+            let candidate = Integer::fold(init, &items, max);
+            assert_eq!(expected, *candidate.eject_value());
+            // Ensure the cost is the sum of the costs of each application of the closure.
+            assert_eq!(expected_count, Circuit::count_in_scope());
+        });
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType>(mode: Mode) {
+        let mut rng = TestRng::default();
+
+        for num_items in 0..=4 {
+            for _ in 0..ITERATIONS {
+                let values = (0..num_items)
+                    .map(|_| *console::Integer::<<Circuit as Environment>::Network, I>::rand(&mut rng))
+                    .collect::<Vec<_>>();
+                check_fold_max::<I>(mode, &values);
+            }
+        }
+
+        // Check the boundary values.
+        check_fold_max::<I>(mode, &[I::MIN, I::MIN]);
+        check_fold_max::<I>(mode, &[I::MAX, I::MIN, I::zero()]);
+    }
+
+    test_integer_unary!(run_test, i8, fold_max);
+    test_integer_unary!(run_test, i32, fold_max);
+    test_integer_unary!(run_test, i128, fold_max);
+
+    test_integer_unary!(run_test, u8, fold_max);
+    test_integer_unary!(run_test, u32, fold_max);
+    test_integer_unary!(run_test, u128, fold_max);
+}
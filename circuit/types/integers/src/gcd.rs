@@ -0,0 +1,239 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> Gcd<Self> for Integer<E, I> {
+    type Output = Self;
+
+    /// Returns the greatest common divisor of `self` and `other`, for unsigned integers.
+    /// By convention, `gcd(0, x) = x` and `gcd(0, 0) = 0`.
+    ///
+    /// This method implements the binary GCD algorithm with a fixed number of `2 * I::BITS` iterations,
+    /// where each iteration performs a masked update, so the circuit is the same regardless of the inputs.
+    fn gcd(&self, other: &Integer<E, I>) -> Self::Output {
+        // Ensure the integer type is unsigned.
+        if I::is_signed() {
+            E::halt("The greatest common divisor is only supported for unsigned integers.")
+        }
+
+        // If `self` and `other` are constants, directly return the greatest common divisor.
+        if self.is_constant() && other.is_constant() {
+            let (mut a, mut b) = (*self.eject_value(), *other.eject_value());
+            while !b.is_zero() {
+                (a, b) = (b, a.wrapping_rem(&b));
+            }
+            return Integer::constant(console::Integer::new(a));
+        }
+
+        // Initialize the operands, and the common power of two.
+        let (mut a, mut b) = (self.clone(), other.clone());
+        let mut power_of_two = Integer::<E, I>::one();
+
+        for _ in 0..(2 * I::BITS) {
+            // The iteration is only active while both operands are non-zero.
+            let is_active = !a.is_zero() & !b.is_zero();
+
+            let is_a_odd = &a.bits_le[0];
+            let is_b_odd = &b.bits_le[0];
+
+            // If both operands are odd, replace the larger operand with half of their difference.
+            let is_a_greater_than_or_equal = a.is_greater_than_or_equal(&b);
+            let larger = Integer::ternary(&is_a_greater_than_or_equal, &a, &b);
+            let smaller = Integer::ternary(&is_a_greater_than_or_equal, &b, &a);
+            let half_difference = larger.sub_wrapped(&smaller).shr_const(1);
+
+            // Otherwise, halve each operand that is even.
+            let is_both_odd = is_a_odd & is_b_odd;
+            let next_a = Integer::ternary(
+                &is_both_odd,
+                &Integer::ternary(&is_a_greater_than_or_equal, &half_difference, &a),
+                &Integer::ternary(is_a_odd, &a, &a.shr_const(1)),
+            );
+            let next_b = Integer::ternary(
+                &is_both_odd,
+                &Integer::ternary(&is_a_greater_than_or_equal, &b, &half_difference),
+                &Integer::ternary(is_b_odd, &b, &b.shr_const(1)),
+            );
+
+            // If both operands are even, record a common factor of two.
+            let is_both_even = !(is_a_odd | is_b_odd);
+            power_of_two = Integer::ternary(&(&is_active & &is_both_even), &power_of_two.shl_const(1), &power_of_two);
+
+            a = Integer::ternary(&is_active, &next_a, &a);
+            b = Integer::ternary(&is_active, &next_b, &b);
+        }
+
+        // At least one operand is now zero, so the other operand is the odd part of the greatest common divisor.
+        // Shift the odd part left by the common power of two, by multiplying the two in the field.
+        // Note: The product is the greatest common divisor, so it fits in `I::BITS` bits, and does not wrap in the field.
+        let gcd = (a | b).to_field() * power_of_two.to_field();
+        Self { bits_le: gcd.to_lower_bits_le(I::BITS as usize), phantom: Default::default() }
+    }
+}
+
+impl<E: Environment, I: IntegerType> Lcm<Self> for Integer<E, I> {
+    type Output = Self;
+
+    /// Returns the least common multiple of `self` and `other`, for unsigned integers.
+    /// By convention, `lcm(0, x) = 0`. Halts (or is unsatisfiable) if the result does not fit in `I`.
+    fn lcm(&self, other: &Integer<E, I>) -> Self::Output {
+        let gcd = self.gcd(other);
+        // If the greatest common divisor is zero, then both operands are zero, so divide by one instead.
+        let divisor = Integer::ternary(&gcd.is_zero(), &Integer::one(), &gcd);
+        self.div_wrapped(&divisor).mul_checked(other)
+    }
+}
+
+impl<E: Environment, I: IntegerType> Metrics<dyn Gcd<Integer<E, I>, Output = Integer<E, I>>> for Integer<E, I> {
+    type Case = (Mode, Mode);
+
+    fn count(case: &Self::Case) -> Count {
+        match (case.0, case.1) {
+            (Mode::Constant, Mode::Constant) => Count::is(I::BITS, 0, 0, 0),
+            // If one operand is a constant, some of the operations in the first iterations are free.
+            (Mode::Constant, _) | (_, Mode::Constant) => Count::less_than(
+                (6 * I::BITS * I::BITS) + I::BITS,
+                0,
+                (51 * I::BITS * I::BITS / 2) + (47 * I::BITS / 2) - 2,
+                (51 * I::BITS * I::BITS / 2) + (63 * I::BITS / 2) - 1,
+            ),
+            (_, _) => Count::is(
+                (6 * I::BITS * I::BITS) + I::BITS,
+                0,
+                (51 * I::BITS * I::BITS / 2) + (47 * I::BITS / 2) - 2,
+                (51 * I::BITS * I::BITS / 2) + (63 * I::BITS / 2) - 1,
+            ),
+        }
+    }
+}
+
+impl<E: Environment, I: IntegerType> OutputMode<dyn Gcd<Integer<E, I>, Output = Integer<E, I>>> for Integer<E, I> {
+    type Case = (Mode, Mode);
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        match (case.0, case.1) {
+            (Mode::Constant, Mode::Constant) => Mode::Constant,
+            (_, _) => Mode::Private,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utilities::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    use core::panic::RefUnwindSafe;
+
+    const ITERATIONS: u64 = 2;
+
+    /// Returns the greatest common divisor of `a` and `b`, using the Euclidean algorithm.
+    fn native_gcd(mut a: u128, mut b: u128) -> u128 {
+        while b != 0 {
+            (a, b) = (b, a % b);
+        }
+        a
+    }
+
+    fn check_gcd<I: IntegerType>(
+        name: &str,
+        first: console::Integer<<Circuit as Environment>::Network, I>,
+        second: console::Integer<<Circuit as Environment>::Network, I>,
+        mode_a: Mode,
+        mode_b: Mode,
+    ) {
+        let a = Integer::<Circuit, I>::new(mode_a, first);
+        let b = Integer::<Circuit, I>::new(mode_b, second);
+        let expected = native_gcd(first.to_u128().unwrap(), second.to_u128().unwrap());
+        Circuit::scope(name, || {
+            let candidate = a.gcd(&b);
+            assert_eq!(expected, candidate.eject_value().to_u128().unwrap());
+            assert_count!(Gcd(Integer<I>, Integer<I>) => Integer<I>, &(mode_a, mode_b));
+            assert_output_mode!(Gcd(Integer<I>, Integer<I>) => Integer<I>, &(mode_a, mode_b), candidate);
+        });
+        Circuit::reset();
+    }
+
+    fn check_lcm<I: IntegerType>(
+        name: &str,
+        first: console::Integer<<Circuit as Environment>::Network, I>,
+        second: console::Integer<<Circuit as Environment>::Network, I>,
+        mode_a: Mode,
+        mode_b: Mode,
+    ) {
+        let a = Integer::<Circuit, I>::new(mode_a, first);
+        let b = Integer::<Circuit, I>::new(mode_b, second);
+        let (first, second) = (first.to_u128().unwrap(), second.to_u128().unwrap());
+        let expected = match native_gcd(first, second) {
+            0 => 0,
+            gcd => first / gcd * second,
+        };
+        Circuit::scope(name, || {
+            let candidate = a.lcm(&b);
+            assert_eq!(expected, candidate.eject_value().to_u128().unwrap());
+            assert_eq!(mode_a.is_constant() && mode_b.is_constant(), candidate.is_constant());
+            assert!(Circuit::is_satisfied_in_scope());
+        });
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType>(mode_a: Mode, mode_b: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let first = Uniform::rand(&mut rng);
+            let second = Uniform::rand(&mut rng);
+            check_gcd::<I>(&format!("GCD: {mode_a} {mode_b} {i}"), first, second, mode_a, mode_b);
+
+            // Construct operands sharing a common factor.
+            let factor = I::one() << (i as usize);
+            let first = console::Integer::new(first.wrapping_shl(i as u32).max(factor));
+            let second = console::Integer::new(second.wrapping_shl(i as u32).max(factor));
+            check_gcd::<I>(&format!("GCD: {mode_a} {mode_b} {i} (common factor)"), first, second, mode_a, mode_b);
+
+            // Sample operands whose least common multiple fits in the integer type.
+            let mask = (I::one() << (I::BITS as usize / 2)) - I::one();
+            let first = console::Integer::new(*first & mask);
+            let second = console::Integer::new(*second & mask);
+            check_lcm::<I>(&format!("LCM: {mode_a} {mode_b} {i}"), first, second, mode_a, mode_b);
+        }
+
+        // Check the zero cases.
+        let value = Uniform::rand(&mut rng);
+        check_gcd::<I>("GCD: zero and value", console::Integer::zero(), value, mode_a, mode_b);
+        check_gcd::<I>("GCD: value and zero", value, console::Integer::zero(), mode_a, mode_b);
+        check_gcd::<I>("GCD: zero and zero", console::Integer::zero(), console::Integer::zero(), mode_a, mode_b);
+        check_lcm::<I>("LCM: zero and one", console::Integer::zero(), console::Integer::one(), mode_a, mode_b);
+        check_lcm::<I>("LCM: zero and zero", console::Integer::zero(), console::Integer::zero(), mode_a, mode_b);
+    }
+
+    fn run_signed_halts<I: IntegerType + RefUnwindSafe>(mode_a: Mode, mode_b: Mode) {
+        let a = Integer::<Circuit, I>::new(mode_a, console::Integer::one());
+        let b = Integer::<Circuit, I>::new(mode_b, console::Integer::one());
+        check_operation_halts(&a, &b, Integer::gcd);
+    }
+
+    test_integer_binary!(run_test, u8, gcd);
+    test_integer_binary!(run_test, u16, gcd);
+    test_integer_binary!(run_test, u32, gcd);
+    test_integer_binary!(run_test, u64, gcd);
+    test_integer_binary!(run_test, u128, gcd);
+
+    test_integer_binary!(run_signed_halts, i8, gcd_halts);
+    test_integer_binary!(run_signed_halts, i64, gcd_halts);
+}
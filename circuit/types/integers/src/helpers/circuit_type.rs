@@ -0,0 +1,211 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// A `CircuitType` for integers, which tracks the circuit type of each bit of the integer.
+#[derive(Clone)]
+pub struct IntegerCircuitType<E: Environment, I: IntegerType> {
+    bits_le: Vec<CircuitType<Boolean<E>>>,
+    phantom: PhantomData<I>,
+}
+
+impl<E: Environment, I: IntegerType> IntegerCircuitType<E, I> {
+    /// Returns the mode of the integer.
+    pub fn mode(&self) -> Mode {
+        Mode::combine(Mode::Constant, self.bits_le.iter().map(|bit| bit.mode()))
+    }
+
+    /// Returns the integer circuit, if it is a constant.
+    /// Halts if any bit of the integer is not a constant.
+    pub fn circuit(&self) -> Integer<E, I> {
+        let bits_le = self
+            .bits_le
+            .iter()
+            .map(|bit| match bit {
+                CircuitType::Constant(constant) => Boolean::constant(constant.eject_value()),
+                _ => E::halt("The constant circuit is required to reconstruct the integer."),
+            })
+            .collect::<Vec<_>>();
+        Integer { bits_le, phantom: Default::default() }
+    }
+}
+
+/// Initializes a new `IntegerCircuitType` from an integer circuit.
+impl<E: Environment, I: IntegerType> From<Integer<E, I>> for IntegerCircuitType<E, I> {
+    fn from(integer: Integer<E, I>) -> Self {
+        Self { bits_le: integer.bits_le.into_iter().map(CircuitType::from).collect(), phantom: Default::default() }
+    }
+}
+
+/// Initializes a new `IntegerCircuitType` from an integer circuit, without consuming the integer.
+impl<E: Environment, I: IntegerType> From<&Integer<E, I>> for IntegerCircuitType<E, I> {
+    fn from(integer: &Integer<E, I>) -> Self {
+        Self { bits_le: integer.bits_le.iter().map(CircuitType::from).collect(), phantom: Default::default() }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    use core::panic::RefUnwindSafe;
+
+    const ITERATIONS: u64 = 32;
+
+    fn check_circuit<I: IntegerType>() {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let expected = Uniform::rand(&mut rng);
+            let integer = Integer::<Circuit, I>::new(Mode::Constant, expected);
+
+            // Check the round-trip from an owned integer.
+            let candidate = IntegerCircuitType::from(integer.clone());
+            assert_eq!(Mode::Constant, candidate.mode());
+            assert_eq!(expected, candidate.circuit().eject_value());
+            assert_eq!(Mode::Constant, candidate.circuit().eject_mode());
+
+            // Check the round-trip from a borrowed integer.
+            let candidate = IntegerCircuitType::from(&integer);
+            assert_eq!(Mode::Constant, candidate.mode());
+            assert_eq!(integer.eject_value(), candidate.circuit().eject_value());
+        }
+    }
+
+    fn check_from_borrowed<I: IntegerType>(mode: Mode) {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let first = Uniform::rand(&mut rng);
+            let second = Uniform::rand(&mut rng);
+            let a = Integer::<Circuit, I>::new(mode, first);
+            let b = Integer::<Circuit, I>::new(mode, second);
+
+            // Construct the circuit type from a borrow.
+            let case = IntegerCircuitType::from(&a);
+            assert_eq!(mode, case.mode());
+
+            // Ensure the original integer is still usable.
+            let candidate = a.add_wrapped(&b);
+            assert_eq!(first.wrapping_add(&second), *candidate.eject_value());
+            assert_eq!(first, a.eject_value());
+            Circuit::reset();
+        }
+    }
+
+    fn check_mode<I: IntegerType>(mode: Mode) {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let integer = Integer::<Circuit, I>::new(mode, Uniform::rand(&mut rng));
+            assert_eq!(mode, IntegerCircuitType::from(&integer).mode());
+            assert_eq!(mode, IntegerCircuitType::from(integer).mode());
+        }
+    }
+
+    fn check_clone<I: IntegerType>(mode: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let first = Uniform::rand(&mut rng);
+            let second = Uniform::rand(&mut rng);
+            let a = Integer::<Circuit, I>::new(mode, first);
+            let b = Integer::<Circuit, I>::new(mode, second);
+
+            // Clone the circuit type, and use both copies in successive assertions.
+            let case = IntegerCircuitType::from(&a);
+            let cloned = case.clone();
+            assert_eq!(case.mode(), cloned.mode());
+
+            Circuit::scope(format!("Clone {mode} {i}"), || {
+                let candidate = a.add_wrapped(&b);
+                assert_count!(AddWrapped(Integer<I>, Integer<I>) => Integer<I>, &(case.mode(), b.eject_mode()));
+                assert_output_mode!(AddWrapped(Integer<I>, Integer<I>) => Integer<I>, &(cloned.mode(), b.eject_mode()), candidate);
+            });
+            Circuit::reset();
+
+            // Ensure the copies reconstruct the same constant.
+            if mode.is_constant() {
+                assert_eq!(case.circuit().eject_value(), cloned.circuit().eject_value());
+            }
+        }
+    }
+
+    fn check_circuit_halts<I: IntegerType + RefUnwindSafe>(mode: Mode) {
+        let integer = Integer::<Circuit, I>::new(mode, console::Integer::one());
+        let candidate = IntegerCircuitType::from(integer);
+        let result = std::panic::catch_unwind(|| candidate.circuit());
+        assert!(result.is_err());
+    }
+
+    test_integer_static!(check_circuit, i8, circuit_type_round_trip);
+    test_integer_static!(check_circuit, i16, circuit_type_round_trip);
+    test_integer_static!(check_circuit, i32, circuit_type_round_trip);
+    test_integer_static!(check_circuit, i64, circuit_type_round_trip);
+    test_integer_static!(check_circuit, i128, circuit_type_round_trip);
+
+    test_integer_static!(check_circuit, u8, circuit_type_round_trip);
+    test_integer_static!(check_circuit, u16, circuit_type_round_trip);
+    test_integer_static!(check_circuit, u32, circuit_type_round_trip);
+    test_integer_static!(check_circuit, u64, circuit_type_round_trip);
+    test_integer_static!(check_circuit, u128, circuit_type_round_trip);
+
+    test_integer_unary!(check_mode, i8, circuit_type_mode);
+    test_integer_unary!(check_mode, i16, circuit_type_mode);
+    test_integer_unary!(check_mode, i32, circuit_type_mode);
+    test_integer_unary!(check_mode, i64, circuit_type_mode);
+    test_integer_unary!(check_mode, i128, circuit_type_mode);
+
+    test_integer_unary!(check_mode, u8, circuit_type_mode);
+    test_integer_unary!(check_mode, u16, circuit_type_mode);
+    test_integer_unary!(check_mode, u32, circuit_type_mode);
+    test_integer_unary!(check_mode, u64, circuit_type_mode);
+    test_integer_unary!(check_mode, u128, circuit_type_mode);
+
+    test_integer_unary!(check_from_borrowed, i8, circuit_type_from_borrowed);
+    test_integer_unary!(check_from_borrowed, i16, circuit_type_from_borrowed);
+    test_integer_unary!(check_from_borrowed, i32, circuit_type_from_borrowed);
+    test_integer_unary!(check_from_borrowed, i64, circuit_type_from_borrowed);
+    test_integer_unary!(check_from_borrowed, i128, circuit_type_from_borrowed);
+
+    test_integer_unary!(check_from_borrowed, u8, circuit_type_from_borrowed);
+    test_integer_unary!(check_from_borrowed, u16, circuit_type_from_borrowed);
+    test_integer_unary!(check_from_borrowed, u32, circuit_type_from_borrowed);
+    test_integer_unary!(check_from_borrowed, u64, circuit_type_from_borrowed);
+    test_integer_unary!(check_from_borrowed, u128, circuit_type_from_borrowed);
+
+    test_integer_unary!(check_clone, i8, circuit_type_clone);
+    test_integer_unary!(check_clone, i16, circuit_type_clone);
+    test_integer_unary!(check_clone, i32, circuit_type_clone);
+    test_integer_unary!(check_clone, i64, circuit_type_clone);
+    test_integer_unary!(check_clone, i128, circuit_type_clone);
+
+    test_integer_unary!(check_clone, u8, circuit_type_clone);
+    test_integer_unary!(check_clone, u16, circuit_type_clone);
+    test_integer_unary!(check_clone, u32, circuit_type_clone);
+    test_integer_unary!(check_clone, u64, circuit_type_clone);
+    test_integer_unary!(check_clone, u128, circuit_type_clone);
+
+    #[test]
+    fn test_circuit_type_circuit_halts() {
+        check_circuit_halts::<u8>(Mode::Public);
+        check_circuit_halts::<u8>(Mode::Private);
+        check_circuit_halts::<i64>(Mode::Public);
+        check_circuit_halts::<i64>(Mode::Private);
+    }
+}
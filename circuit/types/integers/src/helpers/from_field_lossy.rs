@@ -0,0 +1,158 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> FromFieldLossy for Integer<E, I> {
+    type Field = Field<E>;
+
+    /// Casts an integer from the lower `I::BITS` bits of a base field element.
+    ///
+    /// This is a truncation, not a checked conversion: the upper bits of the field element are discarded,
+    /// so this method does not halt (or fail to be satisfied) if they are set. For a checked conversion, use `from_field`.
+    ///
+    /// The bit decomposition of the field element is enforced to be canonical (less than the modulus),
+    /// as otherwise the decomposition of `value + MODULUS` would yield a different set of lower bits.
+    fn from_field_lossy(field: &Self::Field) -> Self {
+        // Note: We are reconstituting the integer from the base field.
+        // This is safe as the number of bits in the integer is less than the base field modulus.
+        debug_assert!(I::BITS < E::BaseField::size_in_bits() as u64);
+
+        // Extract the bits of the field element.
+        let bits_le = field.to_bits_le();
+
+        // Ensure the bits are less than `BaseField::MODULUS`.
+        // Note: This is `bits_le <= BaseField::MODULUS - 1`, as in `Field::from_bits_le`.
+        if !field.is_constant() {
+            let modulus_minus_one = -E::BaseField::one();
+            let is_less_than_modulus = is_less_than_or_equal_constant(&bits_le, &modulus_minus_one.to_bits_le());
+            E::assert(is_less_than_modulus);
+        }
+
+        // Return the integer from the lower bits.
+        Integer { bits_le: bits_le[..I::BITS as usize].to_vec(), phantom: Default::default() }
+    }
+}
+
+impl<E: Environment, I: IntegerType> Metrics<dyn FromFieldLossy<Field = Field<E>>> for Integer<E, I> {
+    type Case = Mode;
+
+    fn count(case: &Self::Case) -> Count {
+        match case.is_constant() {
+            true => Count::is(253, 0, 0, 0),
+            false => Count::is(0, 0, 505, 507),
+        }
+    }
+}
+
+impl<E: Environment, I: IntegerType> OutputMode<dyn FromFieldLossy<Field = Field<E>>> for Integer<E, I> {
+    type Case = Mode;
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        match case.is_constant() {
+            true => Mode::Constant,
+            false => Mode::Private,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 128;
+
+    type NativeField = console::Field<<Circuit as Environment>::Network>;
+
+    fn check_from_field_lossy<I: IntegerType>(mode: Mode, rng: &mut TestRng) {
+        // Sample random field elements, along with the edge cases.
+        let mut values = (0..ITERATIONS).map(|_| Uniform::rand(rng)).collect::<Vec<NativeField>>();
+        values.extend([NativeField::zero(), NativeField::one(), -NativeField::one()]);
+
+        for (i, value) in values.into_iter().enumerate() {
+            // Compute the expected integer by truncating the field element to its lower bits.
+            let expected = console::Integer::<_, I>::from_bits_le(&value.to_bits_le()[..I::BITS as usize]).unwrap();
+            let field = Field::<Circuit>::new(mode, value);
+
+            Circuit::scope(format!("{mode} {value} {i}"), || {
+                let candidate = Integer::<Circuit, I>::from_field_lossy(&field);
+                assert_eq!(expected, candidate.eject_value());
+                assert_count!(Integer<Circuit, I>, FromFieldLossy<Field = Field<Circuit>>, &mode);
+                assert_output_mode!(Integer<Circuit, I>, FromFieldLossy<Field = Field<Circuit>>, &mode, candidate);
+            });
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+    }
+
+    fn run_test<I: IntegerType>() {
+        let mut rng = TestRng::default();
+        check_from_field_lossy::<I>(Mode::Constant, &mut rng);
+        check_from_field_lossy::<I>(Mode::Public, &mut rng);
+        check_from_field_lossy::<I>(Mode::Private, &mut rng);
+    }
+
+    #[test]
+    fn test_u8_from_field_lossy() {
+        run_test::<u8>();
+    }
+
+    #[test]
+    fn test_i8_from_field_lossy() {
+        run_test::<i8>();
+    }
+
+    #[test]
+    fn test_u16_from_field_lossy() {
+        run_test::<u16>();
+    }
+
+    #[test]
+    fn test_i16_from_field_lossy() {
+        run_test::<i16>();
+    }
+
+    #[test]
+    fn test_u32_from_field_lossy() {
+        run_test::<u32>();
+    }
+
+    #[test]
+    fn test_i32_from_field_lossy() {
+        run_test::<i32>();
+    }
+
+    #[test]
+    fn test_u64_from_field_lossy() {
+        run_test::<u64>();
+    }
+
+    #[test]
+    fn test_i64_from_field_lossy() {
+        run_test::<i64>();
+    }
+
+    #[test]
+    fn test_u128_from_field_lossy() {
+        run_test::<u128>();
+    }
+
+    #[test]
+    fn test_i128_from_field_lossy() {
+        run_test::<i128>();
+    }
+}
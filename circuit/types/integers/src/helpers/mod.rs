@@ -16,11 +16,15 @@
 
 use super::*;
 
+pub mod circuit_type;
 pub mod from_bits;
 pub mod from_field;
+pub mod from_field_lossy;
 pub mod msb;
 pub mod one;
+pub mod reinterpret;
 pub mod to_bits;
 pub mod to_field;
 pub mod to_fields;
+pub mod to_scalar;
 pub mod zero;
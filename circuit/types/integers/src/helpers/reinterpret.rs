@@ -0,0 +1,163 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> Integer<E, I> {
+    /// Returns `self` reinterpreted as an unsigned integer of the same width, matching `as` in Rust.
+    /// For example, an `I8` of `-1` is reinterpreted as a `U8` of `255`.
+    ///
+    /// The bits of `self` are reused as is, and therefore this method does not incur any constraints.
+    pub fn to_unsigned<U: IntegerType>(&self) -> Integer<E, U> {
+        // Ensure the target type is unsigned.
+        if U::is_signed() {
+            E::halt("Attempted to reinterpret an integer as a signed type, use `to_signed` instead")
+        }
+        self.reinterpret()
+    }
+
+    /// Returns `self` reinterpreted as a signed integer of the same width, matching `as` in Rust.
+    /// For example, a `U8` of `255` is reinterpreted as an `I8` of `-1`.
+    ///
+    /// The bits of `self` are reused as is, and therefore this method does not incur any constraints.
+    pub fn to_signed<S: IntegerType>(&self) -> Integer<E, S> {
+        // Ensure the target type is signed.
+        if !S::is_signed() {
+            E::halt("Attempted to reinterpret an integer as an unsigned type, use `to_unsigned` instead")
+        }
+        self.reinterpret()
+    }
+
+    /// Returns `self` reinterpreted as an integer of the same width.
+    fn reinterpret<J: IntegerType>(&self) -> Integer<E, J> {
+        // Ensure the target type has the same width.
+        if I::BITS != J::BITS {
+            E::halt(format!("Attempted to reinterpret a {}-bit integer as a {}-bit integer", I::BITS, J::BITS))
+        }
+        Integer { bits_le: self.bits_le.clone(), phantom: Default::default() }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    use crate::test_utilities::*;
+
+    const ITERATIONS: u64 = 128;
+
+    fn check_to_unsigned<I: IntegerType, U: IntegerType>(cast: fn(I) -> U) {
+        let mut rng = TestRng::default();
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            let values = (0..ITERATIONS).map(|_| Uniform::rand(&mut rng)).chain([
+                console::Integer::zero(),
+                console::Integer::MAX,
+                console::Integer::MIN,
+            ]);
+
+            for (i, value) in values.enumerate() {
+                let a = Integer::<Circuit, I>::new(mode, value);
+                let expected = console::Integer::new(cast(*value));
+
+                Circuit::scope(format!("ToUnsigned: {mode} {i}"), || {
+                    let candidate = a.to_unsigned::<U>();
+                    assert_eq!(expected, candidate.eject_value());
+                    assert_eq!(mode, candidate.eject_mode());
+                    assert_scope!(0, 0, 0, 0);
+                });
+                Circuit::reset();
+            }
+        }
+    }
+
+    fn check_to_signed<U: IntegerType, I: IntegerType>(cast: fn(U) -> I) {
+        let mut rng = TestRng::default();
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            let values = (0..ITERATIONS).map(|_| Uniform::rand(&mut rng)).chain([
+                console::Integer::zero(),
+                console::Integer::MAX,
+                console::Integer::MIN,
+            ]);
+
+            for (i, value) in values.enumerate() {
+                let a = Integer::<Circuit, U>::new(mode, value);
+                let expected = console::Integer::new(cast(*value));
+
+                Circuit::scope(format!("ToSigned: {mode} {i}"), || {
+                    let candidate = a.to_signed::<I>();
+                    assert_eq!(expected, candidate.eject_value());
+                    assert_eq!(mode, candidate.eject_mode());
+                    assert_scope!(0, 0, 0, 0);
+
+                    // Reinterpreting the result as unsigned returns the original value.
+                    assert_eq!(value, candidate.to_unsigned::<U>().eject_value());
+                });
+                Circuit::reset();
+            }
+        }
+    }
+
+    #[test]
+    fn test_to_unsigned() {
+        check_to_unsigned::<i8, u8>(|value| value as u8);
+        check_to_unsigned::<i16, u16>(|value| value as u16);
+        check_to_unsigned::<i32, u32>(|value| value as u32);
+        check_to_unsigned::<i64, u64>(|value| value as u64);
+        check_to_unsigned::<i128, u128>(|value| value as u128);
+    }
+
+    #[test]
+    fn test_to_signed() {
+        check_to_signed::<u8, i8>(|value| value as i8);
+        check_to_signed::<u16, i16>(|value| value as i16);
+        check_to_signed::<u32, i32>(|value| value as i32);
+        check_to_signed::<u64, i64>(|value| value as i64);
+        check_to_signed::<u128, i128>(|value| value as i128);
+    }
+
+    #[test]
+    fn test_negative_to_unsigned() {
+        let expected = [(-1i8, u8::MAX), (i8::MIN, 128u8), (-2i8, 254u8)];
+        for (value, expected) in expected {
+            let a = Integer::<Circuit, i8>::new(Mode::Private, console::Integer::new(value));
+            assert_eq!(expected, *a.to_unsigned::<u8>().eject_value());
+        }
+
+        let expected = [(-1i64, u64::MAX), (i64::MIN, 1u64 << 63), (-2i64, u64::MAX - 1)];
+        for (value, expected) in expected {
+            let a = Integer::<Circuit, i64>::new(Mode::Private, console::Integer::new(value));
+            assert_eq!(expected, *a.to_unsigned::<u64>().eject_value());
+        }
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_reinterpret_halts() {
+        let signed = Integer::<Circuit, i8>::new(Mode::Private, console::Integer::one());
+        let unsigned = Integer::<Circuit, u64>::new(Mode::Private, console::Integer::one());
+
+        // Mismatched widths.
+        check_unary_operation_halts(&signed, |a: &Integer<Circuit, i8>| a.to_unsigned::<u16>());
+        check_unary_operation_halts(&unsigned, |a: &Integer<Circuit, u64>| a.to_signed::<i32>());
+        // Mismatched signedness.
+        check_unary_operation_halts(&signed, |a: &Integer<Circuit, i8>| a.to_unsigned::<i8>());
+        check_unary_operation_halts(&unsigned, |a: &Integer<Circuit, u64>| a.to_signed::<u64>());
+        Circuit::reset();
+    }
+}
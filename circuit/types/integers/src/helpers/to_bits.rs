@@ -46,6 +46,13 @@ impl<E: Environment, I: IntegerType> ToBits for &Integer<E, I> {
     }
 }
 
+impl<E: Environment, I: IntegerType> Integer<E, I> {
+    /// Outputs the little-endian bit representation of `self` *with* trailing zeros, without cloning the bits.
+    pub fn into_bits_le(self) -> Vec<Boolean<E>> {
+        self.bits_le
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -111,6 +118,41 @@ mod tests {
         }
     }
 
+    fn check_into_bits_le<I: IntegerType>() {
+        let mut rng = TestRng::default();
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            for i in 0..ITERATIONS {
+                // Sample a random element.
+                let expected = Uniform::rand(&mut rng);
+                let candidate = Integer::<Circuit, I>::new(mode, expected);
+
+                Circuit::scope(format!("{mode} {i}"), || {
+                    let candidate = candidate.into_bits_le();
+                    assert_eq!(I::BITS, candidate.len() as u64);
+
+                    // Ensure the bits recompose to the original value.
+                    assert_eq!(expected, Integer::<Circuit, I>::from_bits_le(&candidate).eject_value());
+                });
+                Circuit::reset();
+            }
+        }
+    }
+
+    #[test]
+    fn test_into_bits_le() {
+        check_into_bits_le::<u8>();
+        check_into_bits_le::<i8>();
+        check_into_bits_le::<u16>();
+        check_into_bits_le::<i16>();
+        check_into_bits_le::<u32>();
+        check_into_bits_le::<i32>();
+        check_into_bits_le::<u64>();
+        check_into_bits_le::<i64>();
+        check_into_bits_le::<u128>();
+        check_into_bits_le::<i128>();
+    }
+
     /// Checks that the field element, when converted to little-endian bits, is well-formed.
     fn check_individual_bits_le<I: IntegerType>(candidate: Integer<Circuit, I>) {
         for (i, bit) in candidate.to_bits_le().iter().enumerate() {
@@ -0,0 +1,162 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> ToScalar for Integer<E, I> {
+    type Scalar = Scalar<E>;
+
+    /// Casts an unsigned integer into a scalar field element.
+    /// This method halts for signed integers, as their two's complement bits do not encode their value.
+    fn to_scalar(&self) -> Self::Scalar {
+        // Ensure the integer type is unsigned.
+        if I::is_signed() {
+            E::halt("Only unsigned integers can be cast into a scalar field element.")
+        }
+
+        // Note: We are zero-extending the integer into a scalar field element.
+        // This is safe as the number of bits in the integer is less than the scalar field data bits,
+        // and thus the value is always less than the scalar field modulus.
+        debug_assert!(I::BITS < E::ScalarField::size_in_data_bits() as u64);
+
+        // Reconstruct the bits as a scalar field element.
+        Scalar::from_bits_le(&self.bits_le)
+    }
+}
+
+impl<E: Environment, I: IntegerType> Metrics<dyn ToScalar<Scalar = Scalar<E>>> for Integer<E, I> {
+    type Case = Mode;
+
+    fn count(_case: &Self::Case) -> Count {
+        Count::is(0, 0, 0, 0)
+    }
+}
+
+impl<E: Environment, I: IntegerType> OutputMode<dyn ToScalar<Scalar = Scalar<E>>> for Integer<E, I> {
+    type Case = Mode;
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        match case {
+            Mode::Constant => Mode::Constant,
+            _ => Mode::Private,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+    use snarkvm_circuit_types_group::Group;
+
+    use core::panic::RefUnwindSafe;
+
+    const ITERATIONS: u64 = 32;
+
+    fn check_to_scalar<I: IntegerType>(mode: Mode, rng: &mut TestRng) {
+        for i in 0..ITERATIONS {
+            // Sample a random integer and group element.
+            let value = Uniform::rand(rng);
+            let native_group: snarkvm_console_types_group::Group<_> = Uniform::rand(rng);
+            let integer = Integer::<Circuit, I>::new(mode, value);
+            let group = Group::<Circuit>::new(mode, native_group);
+
+            // Compute the expected product, by multiplying by the native integer value.
+            let native_scalar = snarkvm_console_types_group::Scalar::from_bits_le(&value.to_bits_le()).unwrap();
+            let expected = native_group * native_scalar;
+
+            Circuit::scope(format!("{mode} {value} {i}"), || {
+                let candidate = integer.to_scalar();
+                assert_eq!(native_scalar, candidate.eject_value());
+                assert_count!(Integer<Circuit, I>, ToScalar<Scalar = Scalar<Circuit>>, &mode);
+                assert_output_mode!(Integer<Circuit, I>, ToScalar<Scalar = Scalar<Circuit>>, &mode, candidate);
+            });
+
+            // Ensure the scalar multiplies the group element by the integer value.
+            let candidate = &group * integer.to_scalar();
+            assert_eq!(expected, candidate.eject_value());
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+    }
+
+    fn check_to_scalar_fails<I: IntegerType + RefUnwindSafe>(mode: Mode, rng: &mut TestRng) {
+        let integer = Integer::<Circuit, I>::new(mode, Uniform::rand(rng));
+        let result = std::panic::catch_unwind(|| integer.to_scalar());
+        assert!(result.is_err());
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType + RefUnwindSafe>() {
+        let mut rng = TestRng::default();
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            match I::is_signed() {
+                true => check_to_scalar_fails::<I>(mode, &mut rng),
+                false => check_to_scalar::<I>(mode, &mut rng),
+            }
+        }
+    }
+
+    #[test]
+    fn test_u8_to_scalar() {
+        run_test::<u8>();
+    }
+
+    #[test]
+    fn test_i8_to_scalar() {
+        run_test::<i8>();
+    }
+
+    #[test]
+    fn test_u16_to_scalar() {
+        run_test::<u16>();
+    }
+
+    #[test]
+    fn test_i16_to_scalar() {
+        run_test::<i16>();
+    }
+
+    #[test]
+    fn test_u32_to_scalar() {
+        run_test::<u32>();
+    }
+
+    #[test]
+    fn test_i32_to_scalar() {
+        run_test::<i32>();
+    }
+
+    #[test]
+    fn test_u64_to_scalar() {
+        run_test::<u64>();
+    }
+
+    #[test]
+    fn test_i64_to_scalar() {
+        run_test::<i64>();
+    }
+
+    #[test]
+    fn test_u128_to_scalar() {
+        run_test::<u128>();
+    }
+
+    #[test]
+    fn test_i128_to_scalar() {
+        run_test::<i128>();
+    }
+}
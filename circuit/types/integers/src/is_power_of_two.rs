@@ -0,0 +1,122 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> IsPowerOfTwo for Integer<E, I> {
+    type Boolean = Boolean<E>;
+
+    /// Returns `true` if `self` is a power of two, for unsigned integers.
+    /// Note that zero is not a power of two.
+    fn is_power_of_two(&self) -> Self::Boolean {
+        // Ensure the integer type is unsigned.
+        if I::is_signed() {
+            E::halt("Checking for a power of two is only supported for unsigned integers.")
+        }
+
+        // Count the number of set bits in the base field.
+        // Note: This is free, as the sum of the bits is a linear combination.
+        let num_ones = self.bits_le.iter().fold(Field::zero(), |sum, bit| sum + Field::from_boolean(bit));
+        // Ensure exactly one bit is set.
+        num_ones.is_equal(&Field::one())
+    }
+}
+
+impl<E: Environment, I: IntegerType> Metrics<dyn IsPowerOfTwo<Boolean = Boolean<E>>> for Integer<E, I> {
+    type Case = Mode;
+
+    fn count(case: &Self::Case) -> Count {
+        count!(Field<E>, Equal<Field<E>, Output = Boolean<E>>, &(*case, Mode::Constant))
+    }
+}
+
+impl<E: Environment, I: IntegerType> OutputMode<dyn IsPowerOfTwo<Boolean = Boolean<E>>> for Integer<E, I> {
+    type Case = Mode;
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        output_mode!(Field<E>, Equal<Field<E>, Output = Boolean<E>>, &(*case, Mode::Constant))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utilities::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    use core::panic::RefUnwindSafe;
+
+    const ITERATIONS: u64 = 128;
+
+    fn check_is_power_of_two<I: IntegerType>(
+        name: &str,
+        value: console::Integer<<Circuit as Environment>::Network, I>,
+        mode: Mode,
+    ) {
+        let a = Integer::<Circuit, I>::new(mode, value);
+        let expected = value.to_u128().unwrap().is_power_of_two();
+        Circuit::scope(name, || {
+            let candidate = a.is_power_of_two();
+            assert_eq!(expected, candidate.eject_value());
+            assert_count!(IsPowerOfTwo<Boolean>() => Integer<I>, &mode);
+            assert_output_mode!(IsPowerOfTwo<Boolean>() => Integer<I>, &mode, candidate);
+        });
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType>(mode: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let value = Uniform::rand(&mut rng);
+            check_is_power_of_two::<I>(&format!("IsPowerOfTwo: {mode} {i}"), value, mode);
+        }
+
+        // Check every power of two, and its neighbours.
+        for i in 0..I::BITS as usize {
+            let power = I::one() << i;
+            check_is_power_of_two::<I>(&format!("IsPowerOfTwo: {mode} 2^{i}"), console::Integer::new(power), mode);
+            check_is_power_of_two::<I>(
+                &format!("IsPowerOfTwo: {mode} 2^{i} + 1"),
+                console::Integer::new(power.wrapping_add(&I::one())),
+                mode,
+            );
+            check_is_power_of_two::<I>(
+                &format!("IsPowerOfTwo: {mode} 2^{i} - 1"),
+                console::Integer::new(power.wrapping_sub(&I::one())),
+                mode,
+            );
+        }
+
+        // Check the zero and maximum cases.
+        check_is_power_of_two::<I>("IsPowerOfTwo: zero", console::Integer::zero(), mode);
+        check_is_power_of_two::<I>("IsPowerOfTwo: MAX", console::Integer::MAX, mode);
+    }
+
+    fn run_signed_halts<I: IntegerType + RefUnwindSafe>(mode: Mode) {
+        let a = Integer::<Circuit, I>::new(mode, console::Integer::one());
+        check_unary_operation_halts(&a, |a: &Integer<Circuit, I>| a.is_power_of_two());
+    }
+
+    test_integer_unary!(run_test, u8, is_power_of_two);
+    test_integer_unary!(run_test, u16, is_power_of_two);
+    test_integer_unary!(run_test, u32, is_power_of_two);
+    test_integer_unary!(run_test, u64, is_power_of_two);
+    test_integer_unary!(run_test, u128, is_power_of_two);
+
+    test_integer_unary!(run_signed_halts, i8, is_power_of_two_halts);
+    test_integer_unary!(run_signed_halts, i64, is_power_of_two_halts);
+}
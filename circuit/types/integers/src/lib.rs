@@ -19,35 +19,73 @@
 #![cfg_attr(test, allow(clippy::assertions_on_result_states))]
 
 mod helpers;
+pub use helpers::circuit_type::IntegerCircuitType;
+
+#[cfg(test)]
+mod field_bytes;
 
 pub mod abs_checked;
 pub mod abs_wrapped;
 pub mod add_checked;
+pub mod add_signed_checked;
 pub mod add_wrapped;
+pub mod all_distinct;
 pub mod and;
+pub mod assert_not_equal;
+pub mod assert_sorted;
 pub mod compare;
+pub mod conditional_increment;
+pub mod conditional_neg;
+pub mod conditional_swap;
+pub mod count_true;
+pub mod cumulative_fee;
 pub mod div_checked;
+pub mod div_euclid;
+pub mod div_rem_with_hint;
 pub mod div_wrapped;
+pub mod dot_product;
 pub mod equal;
+pub mod fixed;
+pub mod fold;
+pub mod gcd;
+pub mod is_power_of_two;
+pub mod linear_combination;
 pub mod modulo;
 pub mod mul_checked;
+pub mod mul_mod;
 pub mod mul_wrapped;
 pub mod neg;
+pub mod neg_checked;
+pub mod neg_flagged;
+pub mod next_power_of_two;
 pub mod not;
 pub mod or;
+pub mod pack_integers;
+pub mod parity;
 pub mod pow_checked;
 pub mod pow_wrapped;
 pub mod rem_checked;
+pub mod rem_euclid;
 pub mod rem_wrapped;
+pub mod reverse_bits;
+pub mod select_indexed;
 pub mod shl_checked;
+pub mod shl_const;
 pub mod shl_wrapped;
 pub mod shr_checked;
+pub mod shr_const;
 pub mod shr_wrapped;
+pub mod sign;
+pub mod sort;
 pub mod sub_checked;
 pub mod sub_wrapped;
+pub mod swap_bytes;
 pub mod ternary;
 pub mod xor;
 
+pub use fixed::Fixed;
+pub use pack_integers::TaggedInteger;
+
 pub type I8<E> = Integer<E, i8>;
 pub type I16<E> = Integer<E, i16>;
 pub type I32<E> = Integer<E, i32>;
@@ -73,11 +111,14 @@ use snarkvm_circuit_environment::{
 use snarkvm_utilities::{TestRng, Uniform};
 
 use snarkvm_circuit_environment::prelude::*;
-use snarkvm_circuit_types_boolean::Boolean;
+use snarkvm_circuit_types_boolean::{is_less_than_or_equal_constant, Boolean};
 use snarkvm_circuit_types_field::Field;
+use snarkvm_circuit_types_scalar::Scalar;
 
 use core::marker::PhantomData;
 
+/// Note: Cloning an integer clones the linear combination of each bit, whose variables are
+/// reference-counted, so a clone shares its variables with the original and adds no constraints.
 #[derive(Clone)]
 pub struct Integer<E: Environment, I: IntegerType> {
     bits_le: Vec<Boolean<E>>,
@@ -97,6 +138,13 @@ impl<E: Environment, I: IntegerType> Integer<E, I> {
     pub fn cast_as_dual(self) -> Integer<E, I::Dual> {
         Integer::<E, I::Dual> { bits_le: self.bits_le, phantom: Default::default() }
     }
+
+    /// Initializes a new constant integer directly from a primitive value, using constant `Boolean` bits.
+    /// Note: Unlike `Integer::constant`, this does not allocate a constant variable for each bit.
+    pub fn constant_from_primitive(value: I) -> Self {
+        let bits_le = (0..I::BITS).map(|i| Boolean::constant(value.wrapping_shr(i as u32) & I::one() == I::one()));
+        Integer { bits_le: bits_le.collect(), phantom: Default::default() }
+    }
 }
 
 #[cfg(console)]
@@ -169,6 +217,18 @@ impl<E: Environment, I: IntegerType> FromStr for Integer<E, I> {
     }
 }
 
+#[cfg(console)]
+impl<E: Environment, I: IntegerType> Integer<E, I> {
+    /// Initializes a new constant integer from a decimal string without a type suffix, i.e. `"255"` for a `U8`.
+    /// Returns an error if the string is malformed, or if the value is out of range for the integer type.
+    pub fn constant_from_str(string: &str) -> Result<Self> {
+        match I::from_str_radix(string, 10) {
+            Ok(value) => Ok(Integer::constant(console::Integer::new(value))),
+            Err(_) => bail!("Failed to parse \"{string}\" as a constant {}", I::type_name()),
+        }
+    }
+}
+
 #[cfg(console)]
 impl<E: Environment, I: IntegerType> TypeName for Integer<E, I> {
     /// Returns the type name of the circuit as a string.
@@ -526,6 +586,142 @@ mod tests {
     fn test_i128_display() {
         check_display::<i128>();
     }
+
+    // constant_from_primitive
+
+    fn check_constant_from_primitive<I: IntegerType>(rng: &mut TestRng) {
+        let values = (0..ITERATIONS)
+            .map(|_| *console::Integer::<<Circuit as Environment>::Network, I>::rand(rng))
+            .chain([I::zero(), I::MIN, I::MAX]);
+        for value in values {
+            let expected = Integer::<Circuit, I>::constant(console::Integer::new(value));
+
+            Circuit::scope("ConstantFromPrimitive", || {
+                let candidate = Integer::<Circuit, I>::constant_from_primitive(value);
+                assert_eq!(expected.eject(), candidate.eject());
+                assert!(candidate.is_constant());
+                assert_scope!(0, 0, 0, 0);
+
+                // Ensure the candidate behaves as the generic constant in an operation.
+                assert_eq!(expected.add_wrapped(&expected).eject(), candidate.add_wrapped(&candidate).eject());
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_constant_from_primitive() {
+        let mut rng = TestRng::default();
+
+        check_constant_from_primitive::<u8>(&mut rng);
+        check_constant_from_primitive::<i8>(&mut rng);
+        check_constant_from_primitive::<u16>(&mut rng);
+        check_constant_from_primitive::<i16>(&mut rng);
+        check_constant_from_primitive::<u32>(&mut rng);
+        check_constant_from_primitive::<i32>(&mut rng);
+        check_constant_from_primitive::<u64>(&mut rng);
+        check_constant_from_primitive::<i64>(&mut rng);
+        check_constant_from_primitive::<u128>(&mut rng);
+        check_constant_from_primitive::<i128>(&mut rng);
+    }
+
+    // constant_from_str
+
+    fn check_constant_from_str<I: IntegerType>(rng: &mut TestRng) {
+        for _ in 0..ITERATIONS {
+            let expected: console::Integer<_, I> = Uniform::rand(rng);
+
+            Circuit::scope("ConstantFromStr", || {
+                let candidate = Integer::<Circuit, I>::constant_from_str(&(*expected).to_string()).unwrap();
+                assert_eq!((Mode::Constant, expected), candidate.eject());
+                assert_scope!(I::BITS, 0, 0, 0);
+            })
+        }
+        // Check that the minimum and maximum integer bounds are parsed.
+        let candidate = Integer::<Circuit, I>::constant_from_str(&I::MIN.to_string()).unwrap();
+        assert_eq!(console::Integer::MIN, candidate.eject_value());
+        let candidate = Integer::<Circuit, I>::constant_from_str(&I::MAX.to_string()).unwrap();
+        assert_eq!(console::Integer::MAX, candidate.eject_value());
+
+        // Check that malformed strings fail to parse.
+        assert!(Integer::<Circuit, I>::constant_from_str("").is_err());
+        assert!(Integer::<Circuit, I>::constant_from_str("1a").is_err());
+        assert!(Integer::<Circuit, I>::constant_from_str(&format!("1{}", I::type_name())).is_err());
+        assert!(Integer::<Circuit, I>::constant_from_str("1.constant").is_err());
+    }
+
+    #[test]
+    fn test_constant_from_str() {
+        let mut rng = TestRng::default();
+
+        check_constant_from_str::<u8>(&mut rng);
+        check_constant_from_str::<i8>(&mut rng);
+        check_constant_from_str::<u16>(&mut rng);
+        check_constant_from_str::<i16>(&mut rng);
+        check_constant_from_str::<u32>(&mut rng);
+        check_constant_from_str::<i32>(&mut rng);
+        check_constant_from_str::<u64>(&mut rng);
+        check_constant_from_str::<i64>(&mut rng);
+        check_constant_from_str::<u128>(&mut rng);
+        check_constant_from_str::<i128>(&mut rng);
+    }
+
+    #[test]
+    fn test_constant_from_str_overflow() {
+        assert!(U8::<Circuit>::constant_from_str("256").is_err());
+        assert!(I8::<Circuit>::constant_from_str("128").is_err());
+        assert!(I8::<Circuit>::constant_from_str("-129").is_err());
+        assert!(U64::<Circuit>::constant_from_str("18446744073709551616").is_err());
+        assert!(U128::<Circuit>::constant_from_str("340282366920938463463374607431768211456").is_err());
+        assert!(I128::<Circuit>::constant_from_str("-170141183460469231731687303715884105729").is_err());
+    }
+
+    #[test]
+    fn test_constant_from_str_negative_into_unsigned() {
+        assert!(U8::<Circuit>::constant_from_str("-1").is_err());
+        assert!(U32::<Circuit>::constant_from_str("-42").is_err());
+        // The same strings parse into the signed counterparts.
+        assert_eq!(-1, *I8::<Circuit>::constant_from_str("-1").unwrap().eject_value());
+        assert_eq!(-42, *I32::<Circuit>::constant_from_str("-42").unwrap().eject_value());
+    }
+
+    // clone
+
+    fn check_clone<I: IntegerType>(mode: Mode, rng: &mut TestRng) {
+        for _ in 0..ITERATIONS {
+            let expected = Uniform::rand(rng);
+            let integer = Integer::<Circuit, I>::new(mode, expected);
+
+            Circuit::scope(format!("Clone {mode}"), || {
+                let candidate = integer.clone();
+                assert_eq!((mode, expected), candidate.eject());
+                // Ensure cloning does not allocate variables or constraints.
+                assert_scope!(0, 0, 0, 0);
+
+                // Ensure each cloned bit references the same variables as the original bit,
+                // in which case their difference is a constant, and again costs nothing.
+                for (bit, cloned_bit) in integer.bits_le.iter().zip_eq(&candidate.bits_le) {
+                    let difference = Field::from_boolean(bit) - Field::from_boolean(cloned_bit);
+                    assert!(difference.is_constant());
+                    assert!(difference.eject_value().is_zero());
+                }
+                assert_scope!(0, 0, 0, 0);
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_clone() {
+        let mut rng = TestRng::default();
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            check_clone::<u8>(mode, &mut rng);
+            check_clone::<i8>(mode, &mut rng);
+            check_clone::<u64>(mode, &mut rng);
+            check_clone::<i128>(mode, &mut rng);
+        }
+    }
 }
 
 #[cfg(test)]
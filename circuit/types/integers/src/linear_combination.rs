@@ -0,0 +1,176 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> LinearCombine for Integer<E, I> {
+    type Coefficient = I;
+
+    /// Returns the sum of `coefficient * value` for all given terms, wrapping around at the boundary of the type.
+    fn linear_combination(terms: &[(Self::Coefficient, Self)]) -> Self {
+        // Determine the variable mode.
+        if terms.iter().all(|(_, value)| value.is_constant()) {
+            // Compute the weighted sum and return the new constant.
+            let sum = terms.iter().fold(I::zero(), |sum, (coefficient, value)| {
+                sum.wrapping_add(&coefficient.wrapping_mul(&value.eject_value()))
+            });
+            Integer::constant(console::Integer::new(sum))
+        } else if Self::fits_in_field(terms.len()) {
+            // Instead of multiplying and adding the integers directly, the integers are converted into
+            // field elements, and the weighted sum is computed in the field, as multiplying by a constant is free.
+            // Note: The coefficients and values are interpreted as unsigned, which preserves wrapping semantics.
+            let sum = terms.iter().fold(Field::zero(), |sum, (coefficient, value)| {
+                let coefficient =
+                    match console::ToField::to_field(&console::Integer::<E::Network, I>::new(*coefficient)) {
+                        Ok(coefficient) => Field::constant(coefficient),
+                        Err(error) => {
+                            E::halt(format!("Unable to convert the coefficient into a field element: {error}"))
+                        }
+                    };
+                sum + coefficient * value.to_field()
+            });
+
+            // Extract the integer bits from the field element, and remove any carry bits.
+            let mut bits_le = sum.to_lower_bits_le(Self::num_sum_bits(terms.len()));
+            bits_le.truncate(I::BITS as usize);
+
+            // Return the weighted sum.
+            Integer { bits_le, phantom: Default::default() }
+        } else {
+            // Otherwise, the weighted sum may overflow the field, so it is computed with wrapping integer operations.
+            terms.iter().fold(Integer::zero(), |sum, (coefficient, value)| {
+                sum.add_wrapped(&value.mul_wrapped(&Integer::constant(console::Integer::new(*coefficient))))
+            })
+        }
+    }
+}
+
+impl<E: Environment, I: IntegerType> Integer<E, I> {
+    /// Returns the number of bits needed to represent a weighted sum of `num_terms` unsigned terms.
+    pub(crate) fn num_sum_bits(num_terms: usize) -> usize {
+        // Each term is less than `2^(2 * I::BITS)`, so the sum is less than `num_terms * 2^(2 * I::BITS)`.
+        2 * I::BITS as usize + (usize::BITS - num_terms.leading_zeros()) as usize
+    }
+
+    /// Returns `true` if a weighted sum of `num_terms` unsigned terms fits within the field.
+    pub(crate) fn fits_in_field(num_terms: usize) -> bool {
+        Self::num_sum_bits(num_terms) < E::BaseField::size_in_data_bits()
+    }
+}
+
+impl<E: Environment, I: IntegerType> Metrics<dyn LinearCombine<Coefficient = I>> for Integer<E, I> {
+    type Case = Vec<Mode>;
+
+    fn count(case: &Self::Case) -> Count {
+        if case.iter().all(|mode| mode.is_constant()) {
+            Count::is(I::BITS, 0, 0, 0)
+        } else if Self::fits_in_field(case.len()) {
+            // The cost of each constant coefficient, and of extracting the bits of the weighted sum.
+            let num_terms = case.len() as u64;
+            let num_bits = Self::num_sum_bits(case.len()) as u64;
+            Count::is(num_terms, 0, num_bits, num_bits + 1)
+        } else {
+            // The cost of multiplying each value by its coefficient, and accumulating the products.
+            let (_, count) = case.iter().fold((Mode::Constant, Count::is(I::BITS, 0, 0, 0)), |(sum_mode, count), mode| {
+                let product_count =
+                    count!(Integer<E, I>, MulWrapped<Integer<E, I>, Output = Integer<E, I>>, &(*mode, Mode::Constant));
+                let product_mode =
+                    output_mode!(Integer<E, I>, MulWrapped<Integer<E, I>, Output = Integer<E, I>>, &(*mode, Mode::Constant));
+                let sum_count =
+                    count!(Integer<E, I>, AddWrapped<Integer<E, I>, Output = Integer<E, I>>, &(sum_mode, product_mode));
+                let sum_mode =
+                    output_mode!(Integer<E, I>, AddWrapped<Integer<E, I>, Output = Integer<E, I>>, &(sum_mode, product_mode));
+                (sum_mode, count + Count::is(I::BITS, 0, 0, 0) + product_count + sum_count)
+            });
+            count
+        }
+    }
+}
+
+impl<E: Environment, I: IntegerType> OutputMode<dyn LinearCombine<Coefficient = I>> for Integer<E, I> {
+    type Case = Vec<Mode>;
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        match case.iter().all(|mode| mode.is_constant()) {
+            true => Mode::Constant,
+            false => Mode::Private,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_integer_unary;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 16;
+
+    type NativeInteger<I> = console::Integer<<Circuit as Environment>::Network, I>;
+
+    fn check_linear_combination<I: IntegerType>(name: &str, terms: &[(I, I)], mode: Mode) {
+        // Compute the expected weighted sum natively.
+        let expected = terms
+            .iter()
+            .fold(I::zero(), |sum, (coefficient, value)| sum.wrapping_add(&coefficient.wrapping_mul(value)));
+
+        let terms = terms
+            .iter()
+            .map(|(coefficient, value)| (*coefficient, Integer::<Circuit, I>::new(mode, console::Integer::new(*value))))
+            .collect::<Vec<_>>();
+        let modes = vec![mode; terms.len()];
+
+        Circuit::scope(name, || {
+            let candidate = Integer::linear_combination(&terms);
+            assert_eq!(expected, *candidate.eject_value());
+            assert_count!(Integer<Circuit, I>, LinearCombine<Coefficient = I>, &modes);
+            assert_output_mode!(Integer<Circuit, I>, LinearCombine<Coefficient = I>, &modes, candidate);
+        });
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType>(mode: Mode) {
+        let mut rng = TestRng::default();
+
+        for num_terms in 1..=4 {
+            for i in 0..ITERATIONS {
+                let terms = (0..num_terms)
+                    .map(|_| (*NativeInteger::<I>::rand(&mut rng), *NativeInteger::<I>::rand(&mut rng)))
+                    .collect::<Vec<_>>();
+                check_linear_combination::<I>(&format!("LinearCombination: {mode} {num_terms} {i}"), &terms, mode);
+            }
+        }
+
+        // Check the boundary values, which wrap around.
+        let (min, max, one) = (I::min_value(), I::max_value(), I::one());
+        check_linear_combination::<I>("MAX * MAX", &[(max, max)], mode);
+        check_linear_combination::<I>("MIN * MAX + MAX * MIN", &[(min, max), (max, min)], mode);
+        check_linear_combination::<I>("MAX + 1", &[(one, max), (one, one)], mode);
+        check_linear_combination::<I>("0 * MAX", &[(I::zero(), max)], mode);
+    }
+
+    test_integer_unary!(run_test, i8, linear_combination);
+    test_integer_unary!(run_test, i16, linear_combination);
+    test_integer_unary!(run_test, i32, linear_combination);
+    test_integer_unary!(run_test, i64, linear_combination);
+    test_integer_unary!(run_test, i128, linear_combination);
+
+    test_integer_unary!(run_test, u8, linear_combination);
+    test_integer_unary!(run_test, u16, linear_combination);
+    test_integer_unary!(run_test, u32, linear_combination);
+    test_integer_unary!(run_test, u64, linear_combination);
+    test_integer_unary!(run_test, u128, linear_combination);
+}
@@ -0,0 +1,226 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> MulMod<Self> for Integer<E, I> {
+    type Output = Self;
+
+    /// Returns `(self * other) mod modulus`, where the product is computed without wrapping.
+    ///
+    /// The quotient of the double-width product is witnessed as two integers `(quotient_lo, quotient_hi)`,
+    /// and the circuit is satisfied if and only if `self * other == quotient * modulus + remainder`
+    /// and `remainder < modulus`, which also ensures that `modulus` is non-zero.
+    ///
+    /// This operation is only supported for unsigned integers, where the product of three integers
+    /// fits in the base field (i.e. `u8`, `u16`, `u32`, and `u64`).
+    fn mul_mod(&self, other: &Self, modulus: &Self) -> Self::Output {
+        // Ensure the integer type is unsigned, and the product of the quotient and modulus fits in the base field.
+        if I::is_signed() || 3 * I::BITS >= E::BaseField::size_in_data_bits() as u64 {
+            E::halt(format!("Modular multiplication is not supported for {}", I::type_name()))
+        }
+        // If `modulus` is a constant and is zero, then halt.
+        if modulus.is_constant() && modulus.eject_value().is_zero() {
+            E::halt("Attempted to reduce modulo zero.")
+        }
+
+        // Compute the quotient and remainder of the double-width product divided by the modulus.
+        let to_u128 = |integer: &Self| (*integer.eject_value()).to_u128().unwrap_or_default();
+        let product = to_u128(self) * to_u128(other);
+        // Note: If the modulus is zero, the quotient and remainder are set to zero, which does not satisfy the constraints below.
+        let (quotient, remainder) = match to_u128(modulus) {
+            0 => (0, 0),
+            modulus => (product / modulus, product % modulus),
+        };
+        let quotient_lo = console::Integer::new(I::from(quotient & I::MAX.to_u128().unwrap()).unwrap_or_else(I::zero));
+        let quotient_hi = console::Integer::new(I::from(quotient >> I::BITS).unwrap_or_else(I::zero));
+        let remainder = console::Integer::new(I::from(remainder).unwrap_or_else(I::zero));
+
+        if self.is_constant() && other.is_constant() && modulus.is_constant() {
+            return Integer::constant(remainder);
+        }
+
+        // Inject the quotient and remainder. Note: This also ensures they are within the integer range.
+        let quotient_lo = Integer::<E, I>::new(Mode::Private, quotient_lo);
+        let quotient_hi = Integer::<E, I>::new(Mode::Private, quotient_hi);
+        let remainder = Integer::<E, I>::new(Mode::Private, remainder);
+
+        // Reconstruct the double-width quotient in the base field.
+        let shift = Field::constant(console::Field::from_u128(1u128 << I::BITS));
+        let quotient = quotient_lo.to_field() + quotient_hi.to_field() * shift;
+
+        // Ensure that `self * other == quotient * modulus + remainder` holds in the base field.
+        // Note: This is safe as the product of the quotient and modulus is less than the base field modulus.
+        E::assert_eq(self.to_field() * other.to_field(), quotient * modulus.to_field() + remainder.to_field());
+
+        // Ensure that the remainder is less than the modulus.
+        E::assert(remainder.is_less_than(modulus));
+
+        remainder
+    }
+}
+
+impl<E: Environment, I: IntegerType> Metrics<dyn MulMod<Integer<E, I>, Output = Integer<E, I>>> for Integer<E, I> {
+    type Case = (Mode, Mode, Mode);
+
+    fn count(case: &Self::Case) -> Count {
+        match case {
+            (Mode::Constant, Mode::Constant, Mode::Constant) => Count::is(I::BITS, 0, 0, 0),
+            (mode_a, mode_b, mode_m) => {
+                // Compute the cost of injecting the quotient and remainder, and checking the remainder.
+                let inject_count = Count::is(0, 0, 3 * I::BITS, 3 * I::BITS);
+                let remainder_count =
+                    count!(Integer<E, I>, Compare<Integer<E, I>, Output = Boolean<E>>, &(Mode::Private, *mode_m));
+                // Compute the cost of the two products, which are free if either factor is a constant.
+                let product_count = match mode_a.is_constant() || mode_b.is_constant() {
+                    true => Count::zero(),
+                    false => Count::is(0, 0, 1, 1),
+                };
+                // Note: Reconstructing the quotient allocates one constant for the shift.
+                let quotient_count = match mode_m.is_constant() {
+                    true => Count::is(1, 0, 0, 0),
+                    false => Count::is(1, 0, 1, 1),
+                };
+                // Compute the cost of asserting the division holds, and the remainder is less than the modulus.
+                let assert_count = Count::is(0, 0, 0, 2);
+                inject_count + remainder_count + product_count + quotient_count + assert_count
+            }
+        }
+    }
+}
+
+impl<E: Environment, I: IntegerType> OutputMode<dyn MulMod<Integer<E, I>, Output = Integer<E, I>>> for Integer<E, I> {
+    type Case = (Mode, Mode, Mode);
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        match case {
+            (Mode::Constant, Mode::Constant, Mode::Constant) => Mode::Constant,
+            _ => Mode::Private,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utilities::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    use core::panic::RefUnwindSafe;
+
+    const ITERATIONS: u64 = 32;
+
+    fn check_mul_mod<I: IntegerType + RefUnwindSafe>(
+        name: &str,
+        first: console::Integer<<Circuit as Environment>::Network, I>,
+        second: console::Integer<<Circuit as Environment>::Network, I>,
+        modulus: console::Integer<<Circuit as Environment>::Network, I>,
+        mode_a: Mode,
+        mode_b: Mode,
+        mode_m: Mode,
+    ) {
+        let a = Integer::<Circuit, I>::new(mode_a, first);
+        let b = Integer::<Circuit, I>::new(mode_b, second);
+        let m = Integer::<Circuit, I>::new(mode_m, modulus);
+
+        // Compute the expected value with a native double-width product.
+        let (first, second, modulus) =
+            (first.to_u128().unwrap(), second.to_u128().unwrap(), modulus.to_u128().unwrap());
+
+        match (modulus, mode_m) {
+            (0, Mode::Constant) => check_operation_halts(&a, &b, |a, b| a.mul_mod(b, &m)),
+            (0, _) => Circuit::scope(name, || {
+                let _candidate = a.mul_mod(&b, &m);
+                assert!(!Circuit::is_satisfied_in_scope(), "(!is_satisfied_in_scope)");
+            }),
+            _ => {
+                let expected = (first * second) % modulus;
+                Circuit::scope(name, || {
+                    let candidate = a.mul_mod(&b, &m);
+                    assert_eq!(expected, candidate.eject_value().to_u128().unwrap());
+                    assert_count!(
+                        Integer<Circuit, I>,
+                        MulMod<Integer<Circuit, I>, Output = Integer<Circuit, I>>,
+                        &(mode_a, mode_b, mode_m)
+                    );
+                    assert_output_mode!(
+                        Integer<Circuit, I>,
+                        MulMod<Integer<Circuit, I>, Output = Integer<Circuit, I>>,
+                        &(mode_a, mode_b, mode_m),
+                        candidate
+                    );
+                    assert!(Circuit::is_satisfied_in_scope(), "(is_satisfied_in_scope)");
+                });
+            }
+        }
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType + RefUnwindSafe>() {
+        let mut rng = TestRng::default();
+
+        for mode_a in [Mode::Constant, Mode::Public, Mode::Private] {
+            for mode_b in [Mode::Constant, Mode::Public, Mode::Private] {
+                for mode_m in [Mode::Constant, Mode::Public, Mode::Private] {
+                    for i in 0..ITERATIONS {
+                        let first = Uniform::rand(&mut rng);
+                        let second = Uniform::rand(&mut rng);
+                        let modulus = Uniform::rand(&mut rng);
+
+                        let name = format!("MulMod: {mode_a} * {mode_b} % {mode_m} {i}");
+                        check_mul_mod::<I>(&name, first, second, modulus, mode_a, mode_b, mode_m);
+                    }
+
+                    // Check the edge cases for the modulus.
+                    let (max, one) = (console::Integer::MAX, console::Integer::one());
+                    for modulus in [console::Integer::zero(), one, one + one, console::Integer::MAX] {
+                        let name = format!("MulMod: MAX * MAX % {modulus} ({mode_a}, {mode_b}, {mode_m})");
+                        check_mul_mod::<I>(&name, max, max, modulus, mode_a, mode_b, mode_m);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_u8_mul_mod() {
+        run_test::<u8>();
+    }
+
+    #[test]
+    fn test_u16_mul_mod() {
+        run_test::<u16>();
+    }
+
+    #[test]
+    fn test_u32_mul_mod() {
+        run_test::<u32>();
+    }
+
+    #[test]
+    fn test_u64_mul_mod() {
+        run_test::<u64>();
+    }
+
+    #[test]
+    fn test_mul_mod_halts_for_unsupported_types() {
+        let a = Integer::<Circuit, i32>::new(Mode::Private, console::Integer::one());
+        check_operation_halts(&a, &a.clone(), |a, b| a.mul_mod(b, b));
+
+        let a = Integer::<Circuit, u128>::new(Mode::Private, console::Integer::one());
+        check_operation_halts(&a, &a.clone(), |a, b| a.mul_mod(b, b));
+    }
+}
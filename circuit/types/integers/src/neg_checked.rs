@@ -0,0 +1,99 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> NegChecked for Integer<E, I> {
+    type Output = Integer<E, I>;
+
+    /// Returns the negation of `self`, for signed integers.
+    /// Halts if `self` is a constant `I::MIN`, and is unsatisfiable if `self` is a variable `I::MIN`.
+    fn neg_checked(&self) -> Self::Output {
+        self.neg()
+    }
+}
+
+impl<E: Environment, I: IntegerType> Metrics<dyn NegChecked<Output = Integer<E, I>>> for Integer<E, I> {
+    type Case = Mode;
+
+    fn count(case: &Self::Case) -> Count {
+        count!(Integer<E, I>, Neg<Output = Integer<E, I>>, case)
+    }
+}
+
+impl<E: Environment, I: IntegerType> OutputMode<dyn NegChecked<Output = Integer<E, I>>> for Integer<E, I> {
+    type Case = Mode;
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        output_mode!(Integer<E, I>, Neg<Output = Integer<E, I>>, case)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    use test_utilities::*;
+
+    use core::panic::RefUnwindSafe;
+
+    const ITERATIONS: u64 = 128;
+
+    fn check_neg_checked<I: IntegerType + RefUnwindSafe>(
+        name: &str,
+        value: console::Integer<<Circuit as Environment>::Network, I>,
+        mode: Mode,
+    ) {
+        let a = Integer::<Circuit, I>::new(mode, value);
+        match value.checked_neg() {
+            Some(expected) => Circuit::scope(name, || {
+                let candidate = a.neg_checked();
+                assert_eq!(console::Integer::new(expected), candidate.eject_value());
+                assert_count!(NegChecked(Integer<I>) => Integer<I>, &mode);
+                assert_output_mode!(NegChecked(Integer<I>) => Integer<I>, &mode, candidate);
+            }),
+            None => match mode {
+                Mode::Constant => check_unary_operation_halts(&a, |a: &Integer<Circuit, I>| a.neg_checked()),
+                _ => Circuit::scope(name, || {
+                    let _candidate = a.neg_checked();
+                    assert_count_fails!(NegChecked(Integer<I>) => Integer<I>, &mode);
+                }),
+            },
+        }
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType + RefUnwindSafe>(mode: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let value = Uniform::rand(&mut rng);
+            check_neg_checked::<I>(&format!("NegChecked: {mode} {i}"), value, mode);
+        }
+
+        // Check the boundary cases.
+        check_neg_checked::<I>(&format!("NegChecked: {mode} zero"), console::Integer::zero(), mode);
+        check_neg_checked::<I>(&format!("NegChecked: {mode} MAX"), console::Integer::MAX, mode);
+        check_neg_checked::<I>(&format!("NegChecked: {mode} MIN"), console::Integer::MIN, mode);
+    }
+
+    test_integer_unary!(run_test, i8, neg_checked);
+    test_integer_unary!(run_test, i16, neg_checked);
+    test_integer_unary!(run_test, i32, neg_checked);
+    test_integer_unary!(run_test, i64, neg_checked);
+    test_integer_unary!(run_test, i128, neg_checked);
+}
@@ -0,0 +1,125 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> NegFlagged for Integer<E, I> {
+    type Boolean = Boolean<E>;
+    type Output = Integer<E, I>;
+
+    /// Returns the wrapped negation of `self`, and `true` if the negation overflowed, for signed integers.
+    /// Note that the negation only overflows if `self` is `I::MIN`, in which case `I::MIN` is returned.
+    fn neg_flagged(&self) -> (Self::Output, Self::Boolean) {
+        match I::is_signed() {
+            true => {
+                let negation = Integer::one().add_wrapped(&!self);
+                // Note: `I::MIN` is the only negative value whose negation is also negative.
+                let is_overflow = self.msb() & negation.msb();
+                (negation, is_overflow)
+            }
+            // Note: `halt` is necessary since negation is not defined for unsigned integers.
+            false => E::halt("Attempted to negate an unsigned integer"),
+        }
+    }
+}
+
+impl<E: Environment, I: IntegerType> Metrics<dyn NegFlagged<Boolean = Boolean<E>, Output = Integer<E, I>>>
+    for Integer<E, I>
+{
+    type Case = Mode;
+
+    fn count(case: &Self::Case) -> Count {
+        match I::is_signed() {
+            false => E::halt("Unsigned integers cannot be negated"),
+            true => match case {
+                Mode::Constant => Count::is(2 * I::BITS, 0, 0, 0),
+                _ => Count::is(I::BITS, 0, I::BITS + 2, I::BITS + 3),
+            },
+        }
+    }
+}
+
+impl<E: Environment, I: IntegerType> OutputMode<dyn NegFlagged<Boolean = Boolean<E>, Output = Integer<E, I>>>
+    for Integer<E, I>
+{
+    type Case = Mode;
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        match case {
+            Mode::Constant => Mode::Constant,
+            _ => Mode::Private,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 128;
+
+    fn check_neg_flagged<I: IntegerType>(
+        name: &str,
+        value: console::Integer<<Circuit as Environment>::Network, I>,
+        mode: Mode,
+    ) {
+        let a = Integer::<Circuit, I>::new(mode, value);
+        // Note: `I::MIN` is the only value whose negation overflows, and it wraps to itself.
+        let (expected, expected_is_overflow) = match value.checked_neg() {
+            Some(expected) => (expected, false),
+            None => (*value, true),
+        };
+        Circuit::scope(name, || {
+            let (candidate, is_overflow) = a.neg_flagged();
+            assert_eq!(console::Integer::new(expected), candidate.eject_value());
+            assert_eq!(expected_is_overflow, is_overflow.eject_value());
+            assert_count!(
+                Integer<Circuit, I>,
+                NegFlagged<Boolean = Boolean<Circuit>, Output = Integer<Circuit, I>>,
+                &mode
+            );
+            assert_output_mode!(
+                Integer<Circuit, I>,
+                NegFlagged<Boolean = Boolean<Circuit>, Output = Integer<Circuit, I>>,
+                &mode,
+                candidate
+            );
+            assert_eq!(candidate.eject_mode(), is_overflow.eject_mode());
+        });
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType>(mode: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let value = Uniform::rand(&mut rng);
+            check_neg_flagged::<I>(&format!("NegFlagged: {mode} {i}"), value, mode);
+        }
+
+        // Check the boundary cases.
+        check_neg_flagged::<I>(&format!("NegFlagged: {mode} zero"), console::Integer::zero(), mode);
+        check_neg_flagged::<I>(&format!("NegFlagged: {mode} MAX"), console::Integer::MAX, mode);
+        check_neg_flagged::<I>(&format!("NegFlagged: {mode} MIN"), console::Integer::MIN, mode);
+    }
+
+    test_integer_unary!(run_test, i8, neg_flagged);
+    test_integer_unary!(run_test, i16, neg_flagged);
+    test_integer_unary!(run_test, i32, neg_flagged);
+    test_integer_unary!(run_test, i64, neg_flagged);
+    test_integer_unary!(run_test, i128, neg_flagged);
+}
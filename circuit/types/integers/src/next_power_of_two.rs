@@ -0,0 +1,171 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> NextPowerOfTwo for Integer<E, I> {
+    type Output = Self;
+
+    /// Returns the smallest power of two greater than or equal to `self`, for unsigned integers.
+    /// Note that `0` and `1` both map to `1`, and this method halts if the result exceeds `I::MAX`.
+    ///
+    /// The result is computed by counting the leading zeros of `self - 1`, and shifting `1`
+    /// to the left by the number of remaining bits.
+    fn next_power_of_two(&self) -> Self::Output {
+        // Ensure the integer type is unsigned.
+        if I::is_signed() {
+            E::halt("The next power of two is only supported for unsigned integers.")
+        }
+
+        // If `self` is a constant, directly return the next power of two.
+        if self.is_constant() {
+            let value = self.eject_value().to_u128().unwrap_or_else(|| E::halt("Failed to convert the integer"));
+            return match value.checked_next_power_of_two().and_then(|power| I::from(power)) {
+                Some(power) => Integer::constant(console::Integer::new(power)),
+                None => E::halt("Integer overflow on the next power of two of a constant"),
+            };
+        }
+
+        // Note: `self - 1` wraps to `I::MAX` if `self` is zero, which is handled separately below.
+        let is_zero = self.is_zero();
+        let decrement = self.sub_wrapped(&Integer::one());
+
+        // Mark every bit at or below the most significant set bit of `self - 1`.
+        // Note: The number of marked bits is the number of bits remaining after the leading zeros.
+        let mut is_marked = Vec::with_capacity(I::BITS as usize);
+        let mut is_set = Boolean::constant(false);
+        for bit in decrement.bits_le.iter().rev() {
+            is_set = &is_set | bit;
+            is_marked.push(is_set.clone());
+        }
+        is_marked.reverse();
+
+        // Ensure the result does not exceed `I::MAX`, which occurs if `self - 1` has no leading zeros.
+        // Note: `self - 1` also has no leading zeros if `self` is zero, whose next power of two is one.
+        E::assert_eq(&is_marked[I::BITS as usize - 1] & !&is_zero, E::zero());
+
+        // Set the bit immediately above the marked bits, which is `1 << (I::BITS - leading_zeros(self - 1))`.
+        let mut bits_le = Vec::with_capacity(I::BITS as usize);
+        bits_le.push(!&is_marked[0] | &is_zero);
+        bits_le.extend(is_marked.windows(2).map(|pair| &pair[0] & !&pair[1]));
+
+        Integer { bits_le, phantom: Default::default() }
+    }
+}
+
+impl<E: Environment, I: IntegerType> Metrics<dyn NextPowerOfTwo<Output = Integer<E, I>>> for Integer<E, I> {
+    type Case = Mode;
+
+    fn count(case: &Self::Case) -> Count {
+        match case {
+            Mode::Constant => Count::is(I::BITS, 0, 0, 0),
+            _ => Count::is(2 * I::BITS, 0, 3 * I::BITS + 3, 3 * I::BITS + 6),
+        }
+    }
+}
+
+impl<E: Environment, I: IntegerType> OutputMode<dyn NextPowerOfTwo<Output = Integer<E, I>>> for Integer<E, I> {
+    type Case = Mode;
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        match case {
+            Mode::Constant => Mode::Constant,
+            _ => Mode::Private,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utilities::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    use core::panic::RefUnwindSafe;
+
+    const ITERATIONS: u64 = 128;
+
+    fn check_next_power_of_two<I: IntegerType + RefUnwindSafe>(
+        name: &str,
+        value: console::Integer<<Circuit as Environment>::Network, I>,
+        mode: Mode,
+    ) {
+        let a = Integer::<Circuit, I>::new(mode, value);
+        let expected = value.to_u128().unwrap().checked_next_power_of_two().and_then(|power| I::from(power));
+        match expected {
+            Some(expected) => Circuit::scope(name, || {
+                let candidate = a.next_power_of_two();
+                assert_eq!(console::Integer::new(expected), candidate.eject_value());
+                assert_count!(NextPowerOfTwo(Integer<I>) => Integer<I>, &mode);
+                assert_output_mode!(NextPowerOfTwo(Integer<I>) => Integer<I>, &mode, candidate);
+            }),
+            None => match mode {
+                Mode::Constant => check_unary_operation_halts(&a, |a: &Integer<Circuit, I>| a.next_power_of_two()),
+                _ => Circuit::scope(name, || {
+                    let _candidate = a.next_power_of_two();
+                    assert_count_fails!(NextPowerOfTwo(Integer<I>) => Integer<I>, &mode);
+                }),
+            },
+        }
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType + RefUnwindSafe>(mode: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let value = Uniform::rand(&mut rng);
+            check_next_power_of_two::<I>(&format!("NextPowerOfTwo: {mode} {i}"), value, mode);
+        }
+
+        // Check zero and one, which both map to one.
+        check_next_power_of_two::<I>(&format!("NextPowerOfTwo: {mode} zero"), console::Integer::zero(), mode);
+        check_next_power_of_two::<I>(&format!("NextPowerOfTwo: {mode} one"), console::Integer::one(), mode);
+
+        // Check the values at and around each power of two.
+        for shift in 1..I::BITS {
+            let power = console::Integer::new(I::one().wrapping_shl(shift as u32));
+            let below = console::Integer::new(power.wrapping_sub(&I::one()));
+            let above = console::Integer::new(power.wrapping_add(&I::one()));
+            check_next_power_of_two::<I>(&format!("NextPowerOfTwo: {mode} below {shift}"), below, mode);
+            check_next_power_of_two::<I>(&format!("NextPowerOfTwo: {mode} at {shift}"), power, mode);
+            check_next_power_of_two::<I>(&format!("NextPowerOfTwo: {mode} above {shift}"), above, mode);
+        }
+
+        // Check the overflow boundary.
+        check_next_power_of_two::<I>(&format!("NextPowerOfTwo: {mode} MAX"), console::Integer::MAX, mode);
+    }
+
+    test_integer_unary!(run_test, u8, next_power_of_two);
+    test_integer_unary!(run_test, u16, next_power_of_two);
+    test_integer_unary!(run_test, u32, next_power_of_two);
+    test_integer_unary!(run_test, u64, next_power_of_two);
+    test_integer_unary!(run_test, u128, next_power_of_two);
+
+    fn check_signed_halts<I: IntegerType + RefUnwindSafe>() {
+        let a = Integer::<Circuit, I>::new(Mode::Private, console::Integer::one());
+        check_unary_operation_halts(&a, |a: &Integer<Circuit, I>| a.next_power_of_two());
+    }
+
+    #[test]
+    fn test_next_power_of_two_signed_halts() {
+        check_signed_halts::<i8>();
+        check_signed_halts::<i16>();
+        check_signed_halts::<i32>();
+        check_signed_halts::<i64>();
+        check_signed_halts::<i128>();
+    }
+}
@@ -0,0 +1,257 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// An integer tagged with its bit width, used to pack integers of mixed widths into a single field element.
+#[derive(Clone)]
+pub struct TaggedInteger<E: Environment> {
+    /// The little-endian bits of the integer, whose length is the bit width.
+    bits_le: Vec<Boolean<E>>,
+}
+
+impl<E: Environment> TaggedInteger<E> {
+    /// Returns the bit width of the integer.
+    pub fn width(&self) -> usize {
+        self.bits_le.len()
+    }
+
+    /// Returns the integer of type `I`.
+    /// This method halts if the bit width does not match the width of `I`.
+    pub fn to_integer<I: IntegerType>(&self) -> Integer<E, I> {
+        if self.width() != I::BITS as usize {
+            E::halt(format!("Cannot convert a {}-bit integer into a {}-bit integer", self.width(), I::BITS))
+        }
+        Integer::from_bits_le(&self.bits_le)
+    }
+}
+
+impl<E: Environment, I: IntegerType> From<Integer<E, I>> for TaggedInteger<E> {
+    /// Returns the given integer, tagged with its bit width.
+    fn from(integer: Integer<E, I>) -> Self {
+        Self::from(&integer)
+    }
+}
+
+impl<E: Environment, I: IntegerType> From<&Integer<E, I>> for TaggedInteger<E> {
+    /// Returns the given integer, tagged with its bit width.
+    fn from(integer: &Integer<E, I>) -> Self {
+        Self { bits_le: integer.to_bits_le() }
+    }
+}
+
+impl<E: Environment> PackIntegers for TaggedInteger<E> {
+    type Field = Field<E>;
+
+    /// Returns the concatenation of the little-endian bits of the given integers, packed into a field element.
+    /// The first integer occupies the least significant bits.
+    /// This method halts if the total number of bits does not fit within the data bits of the field.
+    fn pack_integers(items: &[Self]) -> Self::Field {
+        // Ensure the packed bits fit within the field, so that packing is injective.
+        let num_bits = items.iter().map(TaggedInteger::width).sum::<usize>();
+        if num_bits > E::BaseField::size_in_data_bits() {
+            E::halt(format!("Cannot pack {num_bits} bits into a field element"))
+        }
+
+        // Concatenate the bits, and reconstruct the field element as a linear combination.
+        let bits_le = items.iter().flat_map(|item| item.bits_le.iter().cloned()).collect::<Vec<_>>();
+        Field::from_bits_le(&bits_le)
+    }
+}
+
+impl<E: Environment> UnpackIntegers for TaggedInteger<E> {
+    type Field = Field<E>;
+
+    /// Returns one integer per given bit width, unpacked from the little-endian bits of the field element.
+    /// This method enforces that the field element fits within the total number of bits.
+    fn unpack_integers(field: &Self::Field, widths: &[usize]) -> Vec<Self> {
+        // Ensure the packed bits fit within the field.
+        let num_bits = widths.iter().sum::<usize>();
+        if num_bits > E::BaseField::size_in_data_bits() {
+            E::halt(format!("Cannot unpack {num_bits} bits from a field element"))
+        }
+
+        // Decompose the field element into its lower bits, and split them by the given widths.
+        let mut bits_le = field.to_lower_bits_le(num_bits).into_iter();
+        widths.iter().map(|width| Self { bits_le: bits_le.by_ref().take(*width).collect() }).collect()
+    }
+}
+
+impl<E: Environment> Metrics<dyn PackIntegers<Field = Field<E>>> for TaggedInteger<E> {
+    type Case = Vec<Mode>;
+
+    fn count(_case: &Self::Case) -> Count {
+        // The packed field element is a linear combination of the bits, which is free.
+        Count::is(0, 0, 0, 0)
+    }
+}
+
+impl<E: Environment> OutputMode<dyn PackIntegers<Field = Field<E>>> for TaggedInteger<E> {
+    type Case = Vec<Mode>;
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        match case.iter().all(|mode| mode.is_constant()) {
+            true => Mode::Constant,
+            false => Mode::Private,
+        }
+    }
+}
+
+impl<E: Environment> Metrics<dyn UnpackIntegers<Field = Field<E>>> for TaggedInteger<E> {
+    type Case = (Mode, Vec<usize>);
+
+    fn count(case: &Self::Case) -> Count {
+        // The cost of decomposing the field element into its lower bits.
+        let (mode, widths) = case;
+        let num_bits = widths.iter().sum::<usize>() as u64;
+        match mode.is_constant() {
+            true => Count::is(num_bits, 0, 0, 0),
+            false => Count::is(0, 0, num_bits, num_bits + 1),
+        }
+    }
+}
+
+impl<E: Environment> OutputMode<dyn UnpackIntegers<Field = Field<E>>> for TaggedInteger<E> {
+    type Case = (Mode, Vec<usize>);
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        match case.0.is_constant() {
+            true => Mode::Constant,
+            false => Mode::Private,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 8;
+
+    /// Samples a U8 if `is_u8`, and a U16 otherwise, returning the integer and its bits.
+    fn sample(mode: Mode, is_u8: bool, rng: &mut TestRng) -> (TaggedInteger<Circuit>, Vec<bool>) {
+        match is_u8 {
+            true => {
+                let value = console::Integer::<<Circuit as Environment>::Network, u8>::rand(rng);
+                (U8::<Circuit>::new(mode, value).into(), value.to_bits_le())
+            }
+            false => {
+                let value = console::Integer::<<Circuit as Environment>::Network, u16>::rand(rng);
+                (U16::<Circuit>::new(mode, value).into(), value.to_bits_le())
+            }
+        }
+    }
+
+    fn check_round_trip(mode: Mode, layout: &[bool], rng: &mut TestRng) {
+        let widths = layout.iter().map(|is_u8| if *is_u8 { 8 } else { 16 }).collect::<Vec<_>>();
+
+        for i in 0..ITERATIONS {
+            let (items, bits): (Vec<_>, Vec<_>) = layout.iter().map(|is_u8| sample(mode, *is_u8, rng)).unzip();
+            let modes = vec![mode; layout.len()];
+
+            // Compute the expected field element natively.
+            let expected = console::Field::from_bits_le(&bits.concat()).unwrap();
+
+            Circuit::scope(format!("PackIntegers {mode} {widths:?} {i}"), || {
+                let candidate = TaggedInteger::pack_integers(&items);
+                assert_eq!(expected, candidate.eject_value());
+                assert_count!(TaggedInteger<Circuit>, PackIntegers<Field = Field<Circuit>>, &modes);
+                assert_output_mode!(TaggedInteger<Circuit>, PackIntegers<Field = Field<Circuit>>, &modes, candidate);
+            });
+
+            let packed = TaggedInteger::pack_integers(&items);
+            let mode = packed.eject_mode();
+            let case = (mode, widths.clone());
+            Circuit::scope(format!("UnpackIntegers {mode} {widths:?} {i}"), || {
+                let candidate = TaggedInteger::unpack_integers(&packed, &widths);
+                assert_count!(TaggedInteger<Circuit>, UnpackIntegers<Field = Field<Circuit>>, &case);
+
+                // Ensure each integer is recovered with its original width and value.
+                for ((item, is_u8), expected) in candidate.iter().zip_eq(layout).zip_eq(&bits) {
+                    let candidate = match is_u8 {
+                        true => item.to_integer::<u8>().to_bits_le(),
+                        false => item.to_integer::<u16>().to_bits_le(),
+                    };
+                    assert_eq!(*expected, candidate.eject_value());
+                    assert_output_mode!(
+                        TaggedInteger<Circuit>,
+                        UnpackIntegers<Field = Field<Circuit>>,
+                        &case,
+                        candidate[0]
+                    );
+                }
+            });
+            Circuit::reset();
+        }
+    }
+
+    fn run_test(mode: Mode) {
+        let mut rng = TestRng::default();
+
+        check_round_trip(mode, &[], &mut rng);
+        check_round_trip(mode, &[true], &mut rng);
+        check_round_trip(mode, &[false], &mut rng);
+        check_round_trip(mode, &[true, false], &mut rng);
+        check_round_trip(mode, &[false, true, true, false, true], &mut rng);
+        check_round_trip(mode, &[true, true, false, false, true, false, false, true], &mut rng);
+        // 13 * 16 + 5 * 8 = 248 bits, which fits within the field.
+        let layout = [vec![false; 13], vec![true; 5]].concat();
+        check_round_trip(mode, &layout, &mut rng);
+    }
+
+    #[test]
+    fn test_pack_integers_constant() {
+        run_test(Mode::Constant);
+    }
+
+    #[test]
+    fn test_pack_integers_public() {
+        run_test(Mode::Public);
+    }
+
+    #[test]
+    fn test_pack_integers_private() {
+        run_test(Mode::Private);
+    }
+
+    #[test]
+    fn test_pack_integers_overflow() {
+        // Ensure packing more bits than fit in the field halts.
+        let items: Vec<TaggedInteger<Circuit>> = (0..16)
+            .map(|_| U16::<Circuit>::new(Mode::Private, console::Integer::new(1u16)).into())
+            .chain([U8::<Circuit>::new(Mode::Private, console::Integer::new(1u8)).into()])
+            .collect();
+        let result = std::panic::catch_unwind(|| TaggedInteger::pack_integers(&items));
+        assert!(result.is_err());
+        Circuit::reset();
+
+        // Ensure unpacking a field element into fewer bits than it occupies is unsatisfied.
+        let field = Field::<Circuit>::new(Mode::Private, console::Field::from_u64(1 << 24));
+        let _candidate = TaggedInteger::unpack_integers(&field, &[8, 16]);
+        assert!(!Circuit::is_satisfied());
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_to_integer_width_mismatch() {
+        // Ensure converting to an integer of a different width halts.
+        let item: TaggedInteger<Circuit> = U8::<Circuit>::new(Mode::Private, console::Integer::new(1u8)).into();
+        let result = std::panic::catch_unwind(|| item.to_integer::<u16>());
+        assert!(result.is_err());
+        Circuit::reset();
+    }
+}
@@ -0,0 +1,118 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+use super::*;
+
+impl<E: Environment, I: IntegerType> Parity for Integer<E, I> {
+    type Boolean = Boolean<E>;
+
+    /// Returns the XOR of all bits of `self`, i.e. `true` if an odd number of bits are set.
+    /// The bits are combined as a balanced tree of `I::BITS - 1` XORs.
+    fn parity(&self) -> Self::Boolean {
+        // Reduce the bits by one level at a time, carrying over the last bit of an odd-length level.
+        let mut level = self.bits_le.clone();
+        while level.len() > 1 {
+            level = level
+                .chunks(2)
+                .map(|pair| match pair {
+                    [a, b] => a ^ b,
+                    [a] => a.clone(),
+                    // Note: `chunks(2)` never yields an empty or larger chunk.
+                    _ => E::halt("Malformed chunk detected while computing the parity"),
+                })
+                .collect();
+        }
+
+        // Return the parity.
+        match level.pop() {
+            Some(parity) => parity,
+            // Note: `E::halt` should never be invoked as an integer has at least one bit.
+            None => E::halt("Malformed integer detected while computing the parity"),
+        }
+    }
+}
+
+impl<E: Environment, I: IntegerType> Metrics<dyn Parity<Boolean = Boolean<E>>> for Integer<E, I> {
+    type Case = Mode;
+
+    fn count(case: &Self::Case) -> Count {
+        match case.is_constant() {
+            true => Count::is(0, 0, 0, 0),
+            false => Count::is(0, 0, I::BITS - 1, I::BITS - 1),
+        }
+    }
+}
+
+impl<E: Environment, I: IntegerType> OutputMode<dyn Parity<Boolean = Boolean<E>>> for Integer<E, I> {
+    type Case = Mode;
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        match case.is_constant() {
+            true => Mode::Constant,
+            false => Mode::Private,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 128;
+
+    fn check_parity<I: IntegerType>(
+        name: &str,
+        value: console::Integer<<Circuit as Environment>::Network, I>,
+        mode: Mode,
+    ) {
+        let a = Integer::<Circuit, I>::new(mode, value);
+        let expected = value.to_bits_le().iter().filter(|bit| **bit).count() % 2 == 1;
+        Circuit::scope(name, || {
+            let candidate = a.parity();
+            assert_eq!(expected, candidate.eject_value());
+            assert_count!(Parity<Boolean>() => Integer<I>, &mode);
+            assert_output_mode!(Parity<Boolean>() => Integer<I>, &mode, candidate);
+        });
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType>(mode: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let value = Uniform::rand(&mut rng);
+            check_parity::<I>(&format!("Parity: {mode} {i}"), value, mode);
+        }
+
+        // Check the zero, one, minimum, and maximum cases.
+        check_parity::<I>(&format!("Parity: {mode} zero"), console::Integer::zero(), mode);
+        check_parity::<I>(&format!("Parity: {mode} one"), console::Integer::one(), mode);
+        check_parity::<I>(&format!("Parity: {mode} MIN"), console::Integer::MIN, mode);
+        check_parity::<I>(&format!("Parity: {mode} MAX"), console::Integer::MAX, mode);
+    }
+
+    test_integer_unary!(run_test, i8, parity);
+    test_integer_unary!(run_test, i16, parity);
+    test_integer_unary!(run_test, i32, parity);
+    test_integer_unary!(run_test, i64, parity);
+    test_integer_unary!(run_test, i128, parity);
+
+    test_integer_unary!(run_test, u8, parity);
+    test_integer_unary!(run_test, u16, parity);
+    test_integer_unary!(run_test, u32, parity);
+    test_integer_unary!(run_test, u64, parity);
+    test_integer_unary!(run_test, u128, parity);
+}
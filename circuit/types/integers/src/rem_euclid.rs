@@ -0,0 +1,175 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> RemEuclid<Self> for Integer<E, I> {
+    type Output = Self;
+
+    /// Returns the Euclidean remainder of `self` and `other`, for signed integers.
+    /// The remainder is always non-negative, and less than `|other|`.
+    ///
+    /// This method halts if `other` is a constant zero, or if both operands are constants and the quotient overflows.
+    /// Otherwise, the circuit is unsatisfiable if `other` is zero, or if `self` is `I::MIN` and `other` is `-1`.
+    fn rem_euclid(&self, other: &Integer<E, I>) -> Self::Output {
+        // Ensure the integer type is signed.
+        if !I::is_signed() {
+            E::halt("Euclidean remainder is only supported for signed integers.")
+        }
+
+        let (_, remainder) = self.div_rem_truncated(other);
+
+        // If the remainder is negative, add `|other|` to it.
+        // Note: It is safe to use `abs_wrapped` and `add_wrapped` here, as `|other| - |remainder|` always fits in `I`.
+        Integer::ternary(remainder.msb(), &remainder.add_wrapped(&other.abs_wrapped()), &remainder)
+    }
+}
+
+impl<E: Environment, I: IntegerType> Metrics<dyn RemEuclid<Integer<E, I>, Output = Integer<E, I>>> for Integer<E, I> {
+    type Case = (Mode, Mode);
+
+    fn count(case: &Self::Case) -> Count {
+        // Note: If `2 * I::BITS` exceeds the field capacity, `div_checked` falls back to long division,
+        // which incurs up to an additional `I::BITS^2` private variables and constraints.
+        let long_division = match 2 * I::BITS < E::BaseField::size_in_data_bits() as u64 {
+            true => Count::zero(),
+            false => Count::less_than(0, 0, I::BITS * I::BITS, I::BITS * I::BITS),
+        };
+
+        match (case.0, case.1) {
+            (Mode::Constant, Mode::Constant) => Count::is(6 * I::BITS, 0, 0, 0),
+            (Mode::Constant, _) => {
+                Count::less_than(9 * I::BITS, 0, (27 * I::BITS / 2) + 14, (27 * I::BITS / 2) + 25) + long_division
+            }
+            (_, Mode::Constant) => {
+                Count::less_than((10 * I::BITS) + 1, 0, (23 * I::BITS / 2) + 8, (23 * I::BITS / 2) + 18) + long_division
+            }
+            (_, _) => Count::is(8 * I::BITS, 0, (31 * I::BITS / 2) + 19, (31 * I::BITS / 2) + 32) + long_division,
+        }
+    }
+}
+
+impl<E: Environment, I: IntegerType> OutputMode<dyn RemEuclid<Integer<E, I>, Output = Integer<E, I>>>
+    for Integer<E, I>
+{
+    type Case = (Mode, Mode);
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        match (case.0, case.1) {
+            (Mode::Constant, Mode::Constant) => Mode::Constant,
+            (_, _) => Mode::Private,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    use test_utilities::*;
+
+    use core::panic::RefUnwindSafe;
+
+    const ITERATIONS: u64 = 32;
+
+    fn check_rem_euclid<I: IntegerType + RefUnwindSafe>(
+        name: &str,
+        first: console::Integer<<Circuit as Environment>::Network, I>,
+        second: console::Integer<<Circuit as Environment>::Network, I>,
+        mode_a: Mode,
+        mode_b: Mode,
+    ) {
+        let a = Integer::<Circuit, I>::new(mode_a, first);
+        let b = Integer::<Circuit, I>::new(mode_b, second);
+
+        // Compute the expected value natively, returning `None` on division by zero or overflow.
+        let expected = first
+            .to_i128()
+            .unwrap()
+            .checked_rem_euclid(second.to_i128().unwrap())
+            .and_then(|value| I::from(value))
+            .filter(|_| first.checked_div(&second).is_some());
+
+        match expected {
+            Some(expected) => Circuit::scope(name, || {
+                let candidate = a.rem_euclid(&b);
+                assert_eq!(console::Integer::new(expected), candidate.eject_value());
+                assert_count!(RemEuclid(Integer<I>, Integer<I>) => Integer<I>, &(mode_a, mode_b));
+                assert_output_mode!(RemEuclid(Integer<I>, Integer<I>) => Integer<I>, &(mode_a, mode_b), candidate);
+            }),
+            None => match (mode_a, mode_b) {
+                (_, Mode::Constant) if second.is_zero() => check_operation_halts(&a, &b, Integer::rem_euclid),
+                (Mode::Constant, Mode::Constant) => check_operation_halts(&a, &b, Integer::rem_euclid),
+                _ => Circuit::scope(name, || {
+                    let _candidate = a.rem_euclid(&b);
+                    assert!(!Circuit::is_satisfied_in_scope(), "(!is_satisfied_in_scope)");
+                }),
+            },
+        }
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType + RefUnwindSafe>(mode_a: Mode, mode_b: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let first = Uniform::rand(&mut rng);
+            let second = Uniform::rand(&mut rng);
+
+            check_rem_euclid::<I>(&format!("RemEuclid: {i}"), first, second, mode_a, mode_b);
+            check_rem_euclid::<I>(
+                &format!("RemEuclid by negative: {i}"),
+                first,
+                console::Integer::new(second.abs_wrapped().wrapping_neg()),
+                mode_a,
+                mode_b,
+            );
+            check_rem_euclid::<I>(&format!("RemEuclid by one: {i}"), first, console::Integer::one(), mode_a, mode_b);
+            check_rem_euclid::<I>(&format!("RemEuclid by zero: {i}"), first, console::Integer::zero(), mode_a, mode_b);
+        }
+
+        let one = console::Integer::one();
+        let two = one + one;
+        let seven = console::Integer::new(I::from(7).unwrap());
+
+        // Check that the remainder is non-negative for all combinations of signs.
+        check_rem_euclid::<I>("7 % 2", seven, two, mode_a, mode_b);
+        check_rem_euclid::<I>("-7 % 2", -seven, two, mode_a, mode_b);
+        check_rem_euclid::<I>("7 % -2", seven, -two, mode_a, mode_b);
+        check_rem_euclid::<I>("-7 % -2", -seven, -two, mode_a, mode_b);
+
+        // Check the corner cases.
+        check_rem_euclid::<I>("MIN % MAX", console::Integer::MIN, console::Integer::MAX, mode_a, mode_b);
+        check_rem_euclid::<I>("MAX % MIN", console::Integer::MAX, console::Integer::MIN, mode_a, mode_b);
+        check_rem_euclid::<I>("-1 % MIN", -one, console::Integer::MIN, mode_a, mode_b);
+        check_rem_euclid::<I>("MIN % -1", console::Integer::MIN, -one, mode_a, mode_b);
+        check_rem_euclid::<I>("MIN % 0", console::Integer::MIN, console::Integer::zero(), mode_a, mode_b);
+    }
+
+    test_integer_binary!(run_test, i8, rem_euclid);
+    test_integer_binary!(run_test, i16, rem_euclid);
+    test_integer_binary!(run_test, i32, rem_euclid);
+    test_integer_binary!(run_test, i64, rem_euclid);
+    test_integer_binary!(run_test, i128, rem_euclid);
+
+    #[test]
+    fn test_rem_euclid_unsigned_halts() {
+        let a = Integer::<Circuit, u8>::new(Mode::Private, console::Integer::one());
+        let b = Integer::<Circuit, u8>::new(Mode::Private, console::Integer::one());
+        check_operation_halts(&a, &b, Integer::rem_euclid);
+    }
+}
@@ -0,0 +1,82 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> Integer<E, I> {
+    /// Returns `self` with the order of its bits reversed.
+    ///
+    /// The bit order is reversed by rewiring the bits of `self`, and therefore does not incur any constraints.
+    pub fn reverse_bits(&self) -> Self {
+        let bits_le = self.bits_le.iter().rev().cloned().collect();
+        Self { bits_le, phantom: Default::default() }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 128;
+
+    fn check_reverse_bits<I: IntegerType>(reverse_bits: fn(I) -> I) {
+        let mut rng = TestRng::default();
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            let values = (0..ITERATIONS).map(|_| Uniform::rand(&mut rng)).chain([
+                console::Integer::zero(),
+                console::Integer::one(),
+                console::Integer::MAX,
+                console::Integer::MIN,
+            ]);
+
+            for (i, value) in values.enumerate() {
+                let a = Integer::<Circuit, I>::new(mode, value);
+                let expected = console::Integer::new(reverse_bits(*value));
+
+                Circuit::scope(format!("ReverseBits: {mode} {i}"), || {
+                    let candidate = a.reverse_bits();
+                    assert_eq!(expected, candidate.eject_value());
+                    assert_eq!(mode, candidate.eject_mode());
+                    assert_scope!(0, 0, 0, 0);
+
+                    // Reversing the bits twice returns the original value.
+                    assert_eq!(value, candidate.reverse_bits().eject_value());
+                });
+                Circuit::reset();
+            }
+        }
+    }
+
+    #[test]
+    fn test_reverse_bits_signed() {
+        check_reverse_bits::<i8>(i8::reverse_bits);
+        check_reverse_bits::<i16>(i16::reverse_bits);
+        check_reverse_bits::<i32>(i32::reverse_bits);
+        check_reverse_bits::<i64>(i64::reverse_bits);
+        check_reverse_bits::<i128>(i128::reverse_bits);
+    }
+
+    #[test]
+    fn test_reverse_bits_unsigned() {
+        check_reverse_bits::<u8>(u8::reverse_bits);
+        check_reverse_bits::<u16>(u16::reverse_bits);
+        check_reverse_bits::<u32>(u32::reverse_bits);
+        check_reverse_bits::<u64>(u64::reverse_bits);
+        check_reverse_bits::<u128>(u128::reverse_bits);
+    }
+}
@@ -0,0 +1,217 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType, M: Magnitude> SelectIndexed<Integer<E, M>> for Integer<E, I> {
+    /// Returns `table[index]`, halting if the index is out of range.
+    ///
+    /// The selection is computed as the sum of the table entries, where each entry is masked
+    /// by a ternary on whether the index equals its position. As at most one entry is selected,
+    /// the sum is computed as a bitwise OR of the masked entries.
+    fn select_indexed(index: &Integer<E, M>, table: &[Self]) -> Self {
+        // Ensure the table is non-empty.
+        if table.is_empty() {
+            E::halt("The table must contain at least one element")
+        }
+
+        // If the index is a constant, directly return the selected entry.
+        if index.is_constant() {
+            let position = index.eject_value().to_usize().unwrap_or_else(|| E::halt("Failed to convert the index"));
+            return match table.get(position) {
+                Some(entry) => entry.clone(),
+                None => E::halt(format!("Index {position} is out of range for a table of {} elements", table.len())),
+            };
+        }
+
+        let zero = Integer::zero();
+        let mut output = zero.clone();
+        let mut is_in_range = Boolean::constant(false);
+        // Note: Entries beyond `M::MAX` cannot be indexed, and are skipped.
+        for (position, entry) in table.iter().enumerate().map_while(|(i, entry)| M::from(i).map(|i| (i, entry))) {
+            let is_selected = index.is_equal(&Integer::constant(console::Integer::new(position)));
+            output = &output | &Integer::ternary(&is_selected, entry, &zero);
+            is_in_range = &is_in_range | &is_selected;
+        }
+
+        // Ensure the index selected an entry of the table.
+        E::assert(is_in_range);
+
+        output
+    }
+}
+
+impl<E: Environment, I: IntegerType, M: Magnitude> Metrics<dyn SelectIndexed<Integer<E, M>>> for Integer<E, I> {
+    type Case = (Mode, Vec<Mode>);
+
+    fn count(case: &Self::Case) -> Count {
+        let (index_mode, table_modes) = case;
+
+        // A constant index directly selects the entry.
+        if index_mode.is_constant() {
+            return Count::zero();
+        }
+
+        // Compute the cost of masking each entry and accumulating it into the output.
+        let num_entries = std::cmp::min(table_modes.len() as u128, M::MAX.to_u128().unwrap_or_default() + 1) as usize;
+        let count = table_modes.iter().take(num_entries).enumerate().fold(Count::zero(), |cumulative, (i, mode)| {
+            // Compute the cost of the constant position and of its equality with the index.
+            let equal_count = Count::is(M::BITS, 0, 0, 0)
+                + count!(Integer<E, M>, Equal<Integer<E, M>, Output = Boolean<E>>, &(*index_mode, Mode::Constant));
+            let (mask_count, accumulate_count) = match (i, mode.is_constant()) {
+                // The first entry is accumulated into the constant zero for free.
+                (0, true) => (Count::zero(), Count::zero()),
+                (0, false) => (Count::is(0, 0, I::BITS, I::BITS), Count::zero()),
+                // Note: Masking a constant entry only introduces constraints for its set bits, and hence an upper bound is used.
+                (_, true) => (Count::zero(), Count::less_than(0, 0, I::BITS, I::BITS)),
+                (_, false) => (Count::is(0, 0, I::BITS, I::BITS), Count::is(0, 0, I::BITS, I::BITS)),
+            };
+            // The range check accumulates each equality into a boolean, after the first entry.
+            let range_count = match i {
+                0 => Count::zero(),
+                _ => Count::is(0, 0, 1, 1),
+            };
+            cumulative + equal_count + mask_count + accumulate_count + range_count
+        });
+
+        // Add the cost of the zero constant and of asserting the index is in range.
+        count + Count::is(I::BITS, 0, 0, 1)
+    }
+}
+
+impl<E: Environment, I: IntegerType, M: Magnitude> OutputMode<dyn SelectIndexed<Integer<E, M>>> for Integer<E, I> {
+    type Case = (CircuitType<Integer<E, M>>, Vec<Mode>);
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        let (index, table_modes) = case;
+        match index {
+            CircuitType::Constant(constant) => {
+                let position =
+                    constant.eject_value().to_usize().unwrap_or_else(|| E::halt("Failed to convert the index"));
+                match table_modes.get(position) {
+                    Some(mode) => *mode,
+                    None => E::halt("The index is out of range for the table"),
+                }
+            }
+            _ => Mode::Private,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    use core::panic::RefUnwindSafe;
+
+    const TABLE_SIZES: [usize; 3] = [1, 5, 8];
+
+    fn check_select_indexed<I: IntegerType, M: Magnitude>(mode_index: Mode, mode_table: Mode, num_entries: usize) {
+        let mut rng = TestRng::default();
+
+        // Sample a random table.
+        let table = (0..num_entries).map(|_| Uniform::rand(&mut rng)).collect::<Vec<console::Integer<_, I>>>();
+        let circuit_table =
+            table.iter().map(|value| Integer::<Circuit, I>::new(mode_table, *value)).collect::<Vec<_>>();
+
+        for (position, expected) in table.iter().enumerate() {
+            let index = Integer::<Circuit, M>::new(mode_index, console::Integer::new(M::from(position).unwrap()));
+
+            Circuit::scope(format!("SelectIndexed {mode_index} {mode_table} {position}"), || {
+                let candidate = Integer::select_indexed(&index, &circuit_table);
+                assert_eq!(*expected, candidate.eject_value());
+
+                let table_modes = vec![mode_table; num_entries];
+                assert_count!(
+                    Integer<Circuit, I>,
+                    SelectIndexed<Integer<Circuit, M>>,
+                    &(mode_index, table_modes.clone())
+                );
+                assert_output_mode!(
+                    Integer<Circuit, I>,
+                    SelectIndexed<Integer<Circuit, M>>,
+                    &(CircuitType::from(&index), table_modes),
+                    candidate
+                );
+            });
+            Circuit::reset();
+        }
+    }
+
+    fn check_select_indexed_out_of_range<I: IntegerType + RefUnwindSafe, M: Magnitude + RefUnwindSafe>(
+        mode_index: Mode,
+        mode_table: Mode,
+        num_entries: usize,
+    ) {
+        let mut rng = TestRng::default();
+
+        let table = (0..num_entries)
+            .map(|_| Integer::<Circuit, I>::new(mode_table, Uniform::rand(&mut rng)))
+            .collect::<Vec<_>>();
+        let index = Integer::<Circuit, M>::new(mode_index, console::Integer::new(M::from(num_entries).unwrap()));
+
+        match mode_index {
+            Mode::Constant => {
+                let result = std::panic::catch_unwind(|| Integer::select_indexed(&index, &table));
+                assert!(result.is_err());
+            }
+            _ => Circuit::scope("SelectIndexed out of range", || {
+                let _candidate = Integer::select_indexed(&index, &table);
+                assert!(!Circuit::is_satisfied_in_scope());
+            }),
+        }
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType + RefUnwindSafe, M: Magnitude + RefUnwindSafe>() {
+        for mode_index in [Mode::Constant, Mode::Public, Mode::Private] {
+            for mode_table in [Mode::Constant, Mode::Public, Mode::Private] {
+                for num_entries in TABLE_SIZES {
+                    check_select_indexed::<I, M>(mode_index, mode_table, num_entries);
+                    check_select_indexed_out_of_range::<I, M>(mode_index, mode_table, num_entries);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_u8_select_indexed_u8() {
+        run_test::<u8, u8>();
+    }
+
+    #[test]
+    fn test_i32_select_indexed_u16() {
+        run_test::<i32, u16>();
+    }
+
+    #[test]
+    fn test_u64_select_indexed_u32() {
+        run_test::<u64, u32>();
+    }
+
+    #[test]
+    fn test_i128_select_indexed_u8() {
+        run_test::<i128, u8>();
+    }
+
+    #[test]
+    fn test_select_indexed_fails_on_empty_table() {
+        let index = Integer::<Circuit, u8>::new(Mode::Private, console::Integer::new(0));
+        let result = std::panic::catch_unwind(|| Integer::<Circuit, u32>::select_indexed(&index, &[]));
+        assert!(result.is_err());
+    }
+}
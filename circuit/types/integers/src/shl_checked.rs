@@ -256,6 +256,61 @@ mod tests {
         }
     }
 
+    fn check_shl_operator<I: IntegerType, M: Magnitude>(
+        first: console::Integer<<Circuit as Environment>::Network, I>,
+        second: console::Integer<<Circuit as Environment>::Network, M>,
+        mode_a: Mode,
+        mode_b: Mode,
+    ) {
+        let a = Integer::<Circuit, I>::new(mode_a, first);
+        let b = Integer::<Circuit, M>::new(mode_b, second);
+
+        // Compute the expected value and cost using the method call.
+        let (expected, expected_count) = Circuit::scope(format!("ShlChecked: {mode_a} << {mode_b}"), || {
+            let candidate = a.shl_checked(&b);
+            (candidate.eject_value(), Circuit::count_in_scope())
+        });
+
+        // Ensure each form of the operator synthesizes the same circuit as the method call.
+        let check = |form: &str, operator: &dyn Fn() -> Integer<Circuit, I>| {
+            Circuit::scope(format!("Shl: {form} ({mode_a}, {mode_b})"), || {
+                let candidate = operator();
+                assert_eq!(expected, candidate.eject_value());
+                assert_eq!(expected_count, Circuit::count_in_scope());
+            });
+        };
+        check("a << b", &|| a.clone() << b.clone());
+        check("a << &b", &|| a.clone() << &b);
+        check("&a << b", &|| &a << b.clone());
+        check("&a << &b", &|| &a << &b);
+        check("a <<= &b", &|| {
+            let mut candidate = a.clone();
+            candidate <<= &b;
+            candidate
+        });
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_shl_operator_matches_method() {
+        let mut rng = TestRng::default();
+
+        for mode_a in [Mode::Constant, Mode::Public, Mode::Private] {
+            for mode_b in [Mode::Constant, Mode::Public, Mode::Private] {
+                for _ in 0..ITERATIONS {
+                    // Sample a 16-bit value and a shift amount below 16, so that the operation does not overflow.
+                    let first = console::Integer::<_, u32>::new(u32::rand(&mut rng) >> 16);
+                    let second = console::Integer::<_, u8>::new(u8::rand(&mut rng) % 16);
+                    check_shl_operator::<u32, u8>(first, second, mode_a, mode_b);
+
+                    let first = console::Integer::<_, i32>::new(i32::rand(&mut rng) >> 16);
+                    check_shl_operator::<i32, u8>(first, second, mode_a, mode_b);
+                }
+            }
+        }
+    }
+
     test_integer_binary!(run_test, i8, u8, shl);
     test_integer_binary!(run_test, i8, u16, shl);
     test_integer_binary!(run_test, i8, u32, shl);
@@ -0,0 +1,79 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> Integer<E, I> {
+    /// Returns `self << n`, for a constant shift amount `n`.
+    ///
+    /// The shift is performed by rewiring the bits of `self`, filling the lower bits with constant zeros,
+    /// and therefore does not incur any constraints. Unlike `shl_checked` and `shl_wrapped`,
+    /// a shift amount greater than or equal to `I::BITS` is permitted, and returns zero.
+    pub fn shl_const(&self, n: usize) -> Self {
+        // Clamp the shift amount to the number of bits.
+        let n = core::cmp::min(n, I::BITS as usize);
+
+        let mut bits_le = Vec::with_capacity(I::BITS as usize);
+        bits_le.extend(core::iter::repeat_n(Boolean::constant(false), n));
+        bits_le.extend_from_slice(&self.bits_le[..I::BITS as usize - n]);
+
+        Self { bits_le, phantom: Default::default() }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 32;
+
+    fn check_shl_const<I: IntegerType>(mode: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let value = Uniform::rand(&mut rng);
+            let a = Integer::<Circuit, I>::new(mode, value);
+
+            for n in [0, 1, I::BITS as usize / 2, I::BITS as usize - 1, I::BITS as usize, I::BITS as usize + 1, 1024] {
+                // Overshifting produces zero.
+                let expected = match n < I::BITS as usize {
+                    true => console::Integer::new(value.wrapping_shl(n as u32)),
+                    false => console::Integer::zero(),
+                };
+
+                Circuit::scope(format!("ShlConst: {mode} << {n} {i}"), || {
+                    let candidate = a.shl_const(n);
+                    assert_eq!(expected, candidate.eject_value());
+                    assert_scope!(0, 0, 0, 0);
+                });
+                Circuit::reset();
+            }
+        }
+    }
+
+    test_integer_unary!(check_shl_const, i8, shl_const);
+    test_integer_unary!(check_shl_const, i16, shl_const);
+    test_integer_unary!(check_shl_const, i32, shl_const);
+    test_integer_unary!(check_shl_const, i64, shl_const);
+    test_integer_unary!(check_shl_const, i128, shl_const);
+
+    test_integer_unary!(check_shl_const, u8, shl_const);
+    test_integer_unary!(check_shl_const, u16, shl_const);
+    test_integer_unary!(check_shl_const, u32, shl_const);
+    test_integer_unary!(check_shl_const, u64, shl_const);
+    test_integer_unary!(check_shl_const, u128, shl_const);
+}
@@ -177,6 +177,29 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_shl_wrapped_amount_reduction() {
+        // Shift amounts at and beyond the bit width are reduced modulo `I::BITS`, as in `wrapping_shl`.
+        for mode_a in [Mode::Constant, Mode::Public, Mode::Private] {
+            for mode_b in [Mode::Constant, Mode::Public, Mode::Private] {
+                let first = console::Integer::<_, u32>::new(0x8000_0001u32);
+                for amount in [0u8, 31, 32, 33, 64, u8::MAX] {
+                    let name = format!("Shl: {mode_a} << {mode_b} by {amount}");
+                    check_shl::<u32, u8>(&name, first, console::Integer::new(amount), mode_a, mode_b);
+                }
+                for amount in [32u32, 64, 65, u32::MAX] {
+                    let name = format!("Shl: {mode_a} << {mode_b} by {amount}");
+                    check_shl::<u32, u32>(&name, first, console::Integer::new(amount), mode_a, mode_b);
+                }
+                let first = console::Integer::<_, i32>::new(-3i32);
+                for amount in [32u8, 64, 95] {
+                    let name = format!("Shl: {mode_a} << {mode_b} by {amount}");
+                    check_shl::<i32, u8>(&name, first, console::Integer::new(amount), mode_a, mode_b);
+                }
+            }
+        }
+    }
+
     test_integer_binary!(run_test, i8, u8, shl);
     test_integer_binary!(run_test, i8, u16, shl);
     test_integer_binary!(run_test, i8, u32, shl);
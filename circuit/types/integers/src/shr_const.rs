@@ -0,0 +1,86 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> Integer<E, I> {
+    /// Returns `self >> n`, for a constant shift amount `n`.
+    ///
+    /// The shift is performed by rewiring the bits of `self`, filling the upper bits with constant zeros
+    /// for unsigned integers, or with the sign bit for signed integers (an arithmetic shift),
+    /// and therefore does not incur any constraints. Unlike `shr_checked` and `shr_wrapped`,
+    /// a shift amount greater than or equal to `I::BITS` is permitted, and returns zero
+    /// for unsigned integers, or all sign bits (i.e. `0` or `-1`) for signed integers.
+    pub fn shr_const(&self, n: usize) -> Self {
+        // Clamp the shift amount to the number of bits.
+        let n = core::cmp::min(n, I::BITS as usize);
+
+        let mut bits_le = Vec::with_capacity(I::BITS as usize);
+        bits_le.extend_from_slice(&self.bits_le[n..]);
+        match I::is_signed() {
+            // Sign-extend `self` by `n`.
+            true => bits_le.extend(core::iter::repeat_n(self.msb().clone(), n)),
+            // Zero-extend `self` by `n`.
+            false => bits_le.extend(core::iter::repeat_n(Boolean::constant(false), n)),
+        }
+
+        Self { bits_le, phantom: Default::default() }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 32;
+
+    fn check_shr_const<I: IntegerType>(mode: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let value = Uniform::rand(&mut rng);
+            let a = Integer::<Circuit, I>::new(mode, value);
+
+            for n in [0, 1, I::BITS as usize / 2, I::BITS as usize - 1, I::BITS as usize, I::BITS as usize + 1, 1024] {
+                // Overshifting produces zero, or all sign bits for signed integers.
+                let expected = match n < I::BITS as usize {
+                    true => console::Integer::new(value.wrapping_shr(n as u32)),
+                    false => console::Integer::new(value.wrapping_shr(I::BITS as u32 - 1).wrapping_shr(1)),
+                };
+
+                Circuit::scope(format!("ShrConst: {mode} >> {n} {i}"), || {
+                    let candidate = a.shr_const(n);
+                    assert_eq!(expected, candidate.eject_value());
+                    assert_scope!(0, 0, 0, 0);
+                });
+                Circuit::reset();
+            }
+        }
+    }
+
+    test_integer_unary!(check_shr_const, i8, shr_const);
+    test_integer_unary!(check_shr_const, i16, shr_const);
+    test_integer_unary!(check_shr_const, i32, shr_const);
+    test_integer_unary!(check_shr_const, i64, shr_const);
+    test_integer_unary!(check_shr_const, i128, shr_const);
+
+    test_integer_unary!(check_shr_const, u8, shr_const);
+    test_integer_unary!(check_shr_const, u16, shr_const);
+    test_integer_unary!(check_shr_const, u32, shr_const);
+    test_integer_unary!(check_shr_const, u64, shr_const);
+    test_integer_unary!(check_shr_const, u128, shr_const);
+}
@@ -238,6 +238,44 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_shr_wrapped_amount_reduction() {
+        // Shift amounts at and beyond the bit width are reduced modulo `I::BITS`, as in `wrapping_shr`.
+        for mode_a in [Mode::Constant, Mode::Public, Mode::Private] {
+            for mode_b in [Mode::Constant, Mode::Public, Mode::Private] {
+                let first = console::Integer::<_, u32>::new(0x8000_0001u32);
+                for amount in [0u8, 31, 32, 33, 64, u8::MAX] {
+                    let name = format!("Shr: {mode_a} >> {mode_b} by {amount}");
+                    check_shr::<u32, u8>(&name, first, console::Integer::new(amount), mode_a, mode_b);
+                }
+                for amount in [32u32, 64, 65, u32::MAX] {
+                    let name = format!("Shr: {mode_a} >> {mode_b} by {amount}");
+                    check_shr::<u32, u32>(&name, first, console::Integer::new(amount), mode_a, mode_b);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_shr_wrapped_signed_is_arithmetic() {
+        for mode_a in [Mode::Constant, Mode::Public, Mode::Private] {
+            for mode_b in [Mode::Constant, Mode::Public, Mode::Private] {
+                for (value, amount, expected) in
+                    [(-8i32, 1u8, -4i32), (-1, 31, -1), (i32::MIN, 31, -1), (-7, 33, -4), (i32::MIN, 32, i32::MIN)]
+                {
+                    let a = Integer::<Circuit, i32>::new(mode_a, console::Integer::new(value));
+                    let b = Integer::<Circuit, u8>::new(mode_b, console::Integer::new(amount));
+                    Circuit::scope(format!("Shr: {mode_a} >> {mode_b} ({value} >> {amount})"), || {
+                        let candidate = a.shr_wrapped(&b);
+                        assert_eq!(expected, *candidate.eject_value());
+                        assert!(Circuit::is_satisfied_in_scope(), "(is_satisfied_in_scope)");
+                    });
+                    Circuit::reset();
+                }
+            }
+        }
+    }
+
     test_integer_binary!(run_test, i8, u8, shr);
     test_integer_binary!(run_test, i8, u16, shr);
     test_integer_binary!(run_test, i8, u32, shr);
@@ -0,0 +1,189 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> IsNegative for Integer<E, I> {
+    type Boolean = Boolean<E>;
+
+    /// Returns `true` if `self` is less than zero.
+    /// For signed integers, this is the MSB, and for unsigned integers, this is always `false`.
+    fn is_negative(&self) -> Self::Boolean {
+        match I::is_signed() {
+            true => self.msb().clone(),
+            false => Boolean::constant(false),
+        }
+    }
+}
+
+impl<E: Environment, I: IntegerType> IsNonNegative for Integer<E, I> {
+    type Boolean = Boolean<E>;
+
+    /// Returns `true` if `self` is greater than or equal to zero.
+    /// For signed integers, this is the negated MSB, and for unsigned integers, this is always `true`.
+    fn is_non_negative(&self) -> Self::Boolean {
+        !self.is_negative()
+    }
+}
+
+impl<E: Environment, I: IntegerType> IsPositive for Integer<E, I> {
+    type Boolean = Boolean<E>;
+
+    /// Returns `true` if `self` is greater than zero, i.e. is nonzero and not negative.
+    fn is_positive(&self) -> Self::Boolean {
+        // Determine if `self` is nonzero, by checking the integer as a field element.
+        // Note: This is safe as the field is larger than the maximum integer type supported.
+        let is_nonzero = self.to_field().is_not_equal(&Field::zero());
+        match I::is_signed() {
+            true => is_nonzero & self.is_non_negative(),
+            false => is_nonzero,
+        }
+    }
+}
+
+impl<E: Environment, I: IntegerType> Metrics<dyn IsNegative<Boolean = Boolean<E>>> for Integer<E, I> {
+    type Case = Mode;
+
+    fn count(_case: &Self::Case) -> Count {
+        Count::is(0, 0, 0, 0)
+    }
+}
+
+impl<E: Environment, I: IntegerType> OutputMode<dyn IsNegative<Boolean = Boolean<E>>> for Integer<E, I> {
+    type Case = Mode;
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        match I::is_signed() {
+            true => *case,
+            false => Mode::Constant,
+        }
+    }
+}
+
+impl<E: Environment, I: IntegerType> Metrics<dyn IsNonNegative<Boolean = Boolean<E>>> for Integer<E, I> {
+    type Case = Mode;
+
+    fn count(_case: &Self::Case) -> Count {
+        Count::is(0, 0, 0, 0)
+    }
+}
+
+impl<E: Environment, I: IntegerType> OutputMode<dyn IsNonNegative<Boolean = Boolean<E>>> for Integer<E, I> {
+    type Case = Mode;
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        match I::is_signed() && !case.is_constant() {
+            true => Mode::Private,
+            false => Mode::Constant,
+        }
+    }
+}
+
+impl<E: Environment, I: IntegerType> Metrics<dyn IsPositive<Boolean = Boolean<E>>> for Integer<E, I> {
+    type Case = Mode;
+
+    fn count(case: &Self::Case) -> Count {
+        match (case.is_constant(), I::is_signed()) {
+            (true, _) => Count::is(1, 0, 0, 0),
+            (false, true) => Count::is(0, 0, 3, 4),
+            (false, false) => Count::is(0, 0, 2, 3),
+        }
+    }
+}
+
+impl<E: Environment, I: IntegerType> OutputMode<dyn IsPositive<Boolean = Boolean<E>>> for Integer<E, I> {
+    type Case = Mode;
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        match case.is_constant() {
+            true => Mode::Constant,
+            false => Mode::Private,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_integer_unary;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 100;
+
+    fn check_sign<I: IntegerType>(
+        name: &str,
+        value: console::Integer<<Circuit as Environment>::Network, I>,
+        mode: Mode,
+    ) {
+        let a = Integer::<Circuit, I>::new(mode, value);
+        let is_negative = *value < I::zero();
+        let is_positive = *value > I::zero();
+
+        Circuit::scope(name, || {
+            let candidate = a.is_negative();
+            assert_eq!(is_negative, candidate.eject_value());
+            assert_count!(IsNegative<Boolean>() => Integer<I>, &mode);
+            assert_output_mode!(IsNegative<Boolean>() => Integer<I>, &mode, candidate);
+        });
+        Circuit::reset();
+
+        Circuit::scope(name, || {
+            let candidate = a.is_non_negative();
+            assert_eq!(!is_negative, candidate.eject_value());
+            assert_count!(IsNonNegative<Boolean>() => Integer<I>, &mode);
+            assert_output_mode!(IsNonNegative<Boolean>() => Integer<I>, &mode, candidate);
+        });
+        Circuit::reset();
+
+        Circuit::scope(name, || {
+            let candidate = a.is_positive();
+            assert_eq!(is_positive, candidate.eject_value());
+            assert_count!(IsPositive<Boolean>() => Integer<I>, &mode);
+            assert_output_mode!(IsPositive<Boolean>() => Integer<I>, &mode, candidate);
+        });
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType>(mode: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let value = Uniform::rand(&mut rng);
+            check_sign::<I>(&format!("Sign: {mode} {i}"), value, mode);
+        }
+
+        // Check the zero, unit, and boundary values.
+        check_sign::<I>("Zero", console::Integer::zero(), mode);
+        check_sign::<I>("One", console::Integer::one(), mode);
+        check_sign::<I>("MIN", console::Integer::MIN, mode);
+        check_sign::<I>("MAX", console::Integer::MAX, mode);
+        if I::is_signed() {
+            check_sign::<I>("-1", -console::Integer::one(), mode);
+        }
+    }
+
+    test_integer_unary!(run_test, i8, sign);
+    test_integer_unary!(run_test, i16, sign);
+    test_integer_unary!(run_test, i32, sign);
+    test_integer_unary!(run_test, i64, sign);
+    test_integer_unary!(run_test, i128, sign);
+
+    test_integer_unary!(run_test, u8, sign);
+    test_integer_unary!(run_test, u16, sign);
+    test_integer_unary!(run_test, u32, sign);
+    test_integer_unary!(run_test, u64, sign);
+    test_integer_unary!(run_test, u128, sign);
+}
@@ -0,0 +1,182 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType, const N: usize> Sort<N> for Integer<E, I> {
+    type Output = Self;
+
+    /// Returns the given values, sorted in ascending order.
+    ///
+    /// This method implements a bitonic sorting network, where each comparator is a `conditional_swap`,
+    /// so the circuit is the same regardless of the values. The number of values `N` must be a power of two,
+    /// and this method fails to compile for any other `N`.
+    fn sort(values: &[Self; N]) -> [Self::Output; N] {
+        // Note: This fails to compile if `N` is not a power of two.
+        let mut sorted = Vec::with_capacity(BitonicNetwork::<N>::NUM_VALUES);
+        sorted.extend_from_slice(values);
+
+        for (i, j, is_ascending) in BitonicNetwork::<N>::comparators() {
+            // Swap the values if they are out of order, with respect to the direction of the comparator.
+            let is_out_of_order = match is_ascending {
+                true => sorted[i].is_greater_than(&sorted[j]),
+                false => sorted[i].is_less_than(&sorted[j]),
+            };
+            (sorted[i], sorted[j]) = Self::conditional_swap(&is_out_of_order, &sorted[i], &sorted[j]);
+        }
+
+        core::array::from_fn(|i| sorted[i].clone())
+    }
+}
+
+/// The comparators of a bitonic sorting network over `N` values.
+struct BitonicNetwork<const N: usize>;
+
+impl<const N: usize> BitonicNetwork<N> {
+    /// The number of values, checked at compile time to be a power of two.
+    const NUM_VALUES: usize = {
+        assert!(N.is_power_of_two(), "The number of values to sort must be a power of two");
+        N
+    };
+
+    /// Returns the comparators `(i, j, is_ascending)` of the network, in order.
+    /// Each comparator orders the values at indices `i < j` in ascending or descending order.
+    fn comparators() -> impl Iterator<Item = (usize, usize, bool)> {
+        // Iterate over the sizes of the bitonic sequences being merged: 2, 4, ..., N.
+        (1..=N.trailing_zeros()).map(|log_size| 1 << log_size).flat_map(|size: usize| {
+            // Iterate over the comparator distances: size / 2, size / 4, ..., 1.
+            (0..size.trailing_zeros()).rev().map(|log_distance| 1 << log_distance).flat_map(move |distance: usize| {
+                (0..N).filter(move |i| i & distance == 0).map(move |i| (i, i | distance, i & size == 0))
+            })
+        })
+    }
+
+    /// Returns the number of comparators in the network, which is `(N / 2) * log2(N) * (log2(N) + 1) / 2`.
+    fn num_comparators() -> u64 {
+        let log_n = N.trailing_zeros() as u64;
+        (N as u64 / 2) * log_n * (log_n + 1) / 2
+    }
+}
+
+impl<E: Environment, I: IntegerType, const N: usize> Metrics<dyn Sort<N, Output = Integer<E, I>>> for Integer<E, I> {
+    type Case = Mode;
+
+    fn count(case: &Self::Case) -> Count {
+        // Note: After the first comparator, every value is private, unless all values are constant.
+        let compare_count = count!(Self, Compare<Self, Output = Boolean<E>>, &(*case, *case));
+        let condition_mode = output_mode!(Self, Compare<Self, Output = Boolean<E>>, &(*case, *case));
+        let swap_count =
+            count!(Self, ConditionalSwap<Boolean = Boolean<E>, Output = Self>, &(condition_mode, *case, *case));
+        (compare_count + swap_count) * BitonicNetwork::<N>::num_comparators()
+    }
+}
+
+impl<E: Environment, I: IntegerType, const N: usize> OutputMode<dyn Sort<N, Output = Integer<E, I>>> for Integer<E, I> {
+    type Case = Mode;
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        match case {
+            Mode::Constant => Mode::Constant,
+            _ => Mode::Private,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 16;
+
+    fn check_sort<I: IntegerType, const N: usize>(
+        name: &str,
+        values: [console::Integer<<Circuit as Environment>::Network, I>; N],
+        mode: Mode,
+    ) {
+        let mut expected = values;
+        expected.sort();
+
+        let candidates = values.map(|value| Integer::<Circuit, I>::new(mode, value));
+        Circuit::scope(name, || {
+            let candidate = Integer::sort(&candidates);
+            assert_eq!(expected, candidate.clone().map(|value| value.eject_value()));
+            assert_count!(Integer<Circuit, I>, Sort<N, Output = Integer<Circuit, I>>, &mode);
+            candidate.iter().for_each(
+                |value| assert_output_mode!(Integer<Circuit, I>, Sort<N, Output = Integer<Circuit, I>>, &mode, value),
+            );
+        });
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType, const N: usize>(mode: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let values: [console::Integer<_, I>; N] = core::array::from_fn(|_| Uniform::rand(&mut rng));
+            check_sort::<I, N>(&format!("Sort {N}: {mode} random {i}"), values, mode);
+
+            // Check an already-sorted input.
+            let mut sorted = values;
+            sorted.sort();
+            check_sort::<I, N>(&format!("Sort {N}: {mode} sorted {i}"), sorted, mode);
+
+            // Check a reverse-sorted input.
+            let mut reversed = sorted;
+            reversed.reverse();
+            check_sort::<I, N>(&format!("Sort {N}: {mode} reversed {i}"), reversed, mode);
+
+            // Check an input with duplicates.
+            let duplicates = core::array::from_fn(|j| values[j % 2]);
+            check_sort::<I, N>(&format!("Sort {N}: {mode} duplicates {i}"), duplicates, mode);
+        }
+
+        // Check an input with the extreme values.
+        let extremes = core::array::from_fn(|j| match j % 2 == 0 {
+            true => console::Integer::MAX,
+            false => console::Integer::MIN,
+        });
+        check_sort::<I, N>(&format!("Sort {N}: {mode} extremes"), extremes, mode);
+    }
+
+    fn run_test_4<I: IntegerType>(mode: Mode) {
+        run_test::<I, 4>(mode);
+    }
+
+    fn run_test_8<I: IntegerType>(mode: Mode) {
+        run_test::<I, 8>(mode);
+    }
+
+    test_integer_unary!(run_test_4, i8, sort_4);
+    test_integer_unary!(run_test_4, i64, sort_4);
+    test_integer_unary!(run_test_4, u8, sort_4);
+    test_integer_unary!(run_test_4, u64, sort_4);
+
+    test_integer_unary!(run_test_8, i8, sort_8);
+    test_integer_unary!(run_test_8, i64, sort_8);
+    test_integer_unary!(run_test_8, u8, sort_8);
+    test_integer_unary!(run_test_8, u64, sort_8);
+
+    #[test]
+    fn test_num_comparators() {
+        assert_eq!(BitonicNetwork::<1>::comparators().count() as u64, BitonicNetwork::<1>::num_comparators());
+        assert_eq!(BitonicNetwork::<2>::comparators().count() as u64, BitonicNetwork::<2>::num_comparators());
+        assert_eq!(BitonicNetwork::<4>::comparators().count() as u64, BitonicNetwork::<4>::num_comparators());
+        assert_eq!(BitonicNetwork::<8>::comparators().count() as u64, BitonicNetwork::<8>::num_comparators());
+        assert_eq!(BitonicNetwork::<16>::comparators().count() as u64, BitonicNetwork::<16>::num_comparators());
+        assert_eq!(24, BitonicNetwork::<8>::num_comparators());
+    }
+}
@@ -0,0 +1,97 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> Integer<E, I> {
+    /// Returns `self` with the order of its bytes reversed.
+    ///
+    /// The byte order is reversed by rewiring the bits of `self`, and therefore does not incur any constraints.
+    /// This method is only defined for integer types whose width is a multiple of 8,
+    /// and fails to compile for any other width.
+    pub fn swap_bytes(&self) -> Self {
+        // Note: This fails to compile if the integer type is not byte-aligned.
+        let num_bytes = ByteWidth::<I>::NUM_BYTES;
+
+        let bits_le = (0..num_bytes).rev().flat_map(|i| self.bits_le[i * 8..(i + 1) * 8].iter().cloned()).collect();
+        Self { bits_le, phantom: Default::default() }
+    }
+}
+
+/// The number of bytes in an integer type, checked at compile time to be a whole number.
+struct ByteWidth<I: IntegerType>(PhantomData<I>);
+
+impl<I: IntegerType> ByteWidth<I> {
+    const NUM_BYTES: usize = {
+        assert!(I::BITS % 8 == 0, "The integer type must be a multiple of 8 bits");
+        I::BITS as usize / 8
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 128;
+
+    fn check_swap_bytes<I: IntegerType>(swap_bytes: fn(I) -> I) {
+        let mut rng = TestRng::default();
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            let values = (0..ITERATIONS).map(|_| Uniform::rand(&mut rng)).chain([
+                console::Integer::zero(),
+                console::Integer::one(),
+                console::Integer::MAX,
+                console::Integer::MIN,
+            ]);
+
+            for (i, value) in values.enumerate() {
+                let a = Integer::<Circuit, I>::new(mode, value);
+                let expected = console::Integer::new(swap_bytes(*value));
+
+                Circuit::scope(format!("SwapBytes: {mode} {i}"), || {
+                    let candidate = a.swap_bytes();
+                    assert_eq!(expected, candidate.eject_value());
+                    assert_eq!(mode, candidate.eject_mode());
+                    assert_scope!(0, 0, 0, 0);
+
+                    // Swapping the bytes twice returns the original value.
+                    assert_eq!(value, candidate.swap_bytes().eject_value());
+                });
+                Circuit::reset();
+            }
+        }
+    }
+
+    #[test]
+    fn test_swap_bytes_signed() {
+        check_swap_bytes::<i8>(i8::swap_bytes);
+        check_swap_bytes::<i16>(i16::swap_bytes);
+        check_swap_bytes::<i32>(i32::swap_bytes);
+        check_swap_bytes::<i64>(i64::swap_bytes);
+        check_swap_bytes::<i128>(i128::swap_bytes);
+    }
+
+    #[test]
+    fn test_swap_bytes_unsigned() {
+        check_swap_bytes::<u8>(u8::swap_bytes);
+        check_swap_bytes::<u16>(u16::swap_bytes);
+        check_swap_bytes::<u32>(u32::swap_bytes);
+        check_swap_bytes::<u64>(u64::swap_bytes);
+        check_swap_bytes::<u128>(u128::swap_bytes);
+    }
+}
@@ -53,6 +53,7 @@ impl<E: Environment, I: IntegerType> Metrics<dyn Ternary<Boolean = Boolean<E>, O
     type Case = (Mode, Mode, Mode);
 
     fn count(case: &Self::Case) -> Count {
+        // Note: A variable condition incurs one boolean ternary per bit, unless both branches are constant.
         match case {
             (Mode::Constant, _, _)
             | (Mode::Public, Mode::Constant, Mode::Constant)
@@ -69,6 +70,7 @@ impl<E: Environment, I: IntegerType> OutputMode<dyn Ternary<Boolean = Boolean<E>
 
     fn output_mode(case: &Self::Case) -> Mode {
         let (condition, mode_a, mode_b) = case;
+        // Note: A constant condition selects one of the branches, and hence its mode.
         match condition.mode().is_constant() {
             true => match condition {
                 CircuitType::Constant(constant) => match constant.eject_value() {
@@ -104,7 +106,7 @@ mod tests {
                 let candidate = Integer::ternary(&condition, &a, &b);
                 assert_eq!(expected, candidate.eject_value());
                 assert_count!(Ternary(Boolean, Integer<I>, Integer<I>) => Integer<I>, &(mode_condition, mode_a, mode_b));
-                // assert_output_mode!(Ternary(Boolean, Integer<I>, Integer<I>) => Integer<I>, &(CircuitType::from(&condition), mode_a, mode_b), candidate);
+                assert_output_mode!(Ternary(Boolean, Integer<I>, Integer<I>) => Integer<I>, &(CircuitType::from(&condition), mode_a, mode_b), candidate);
             });
             Circuit::reset();
         }
@@ -0,0 +1,139 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> Canonical for Scalar<E> {
+    type Boolean = Boolean<E>;
+
+    /// Returns `true` if `self` is less than the scalar field modulus.
+    ///
+    /// A variable scalar is only constrained to fit in `E::ScalarField::size_in_bits()` bits,
+    /// so a malicious witness may hold a value in `[MODULUS, 2^size_in_bits)`. This check
+    /// compares the little-endian bit decomposition of `self` against the bits of `MODULUS - 1`.
+    fn is_canonical(&self) -> Self::Boolean {
+        // Retrieve the modulus & subtract by 1 as we'll check `self` is less than or *equal* to this value.
+        // (For advanced users) ScalarField::MODULUS - 1 is equivalent to -1 in the field.
+        let modulus_minus_one = -E::ScalarField::one();
+
+        // Compute `bits_le <= ScalarField::MODULUS - 1`, which is equivalent to `bits_le < ScalarField::MODULUS`.
+        is_less_than_or_equal_constant(&self.to_bits_le(), &modulus_minus_one.to_bits_le())
+    }
+
+    /// Asserts that `self` is less than the scalar field modulus.
+    fn assert_canonical(&self) {
+        E::assert(self.is_canonical());
+    }
+}
+
+impl<E: Environment> Metrics<dyn Canonical<Boolean = Boolean<E>>> for Scalar<E> {
+    type Case = Mode;
+
+    fn count(case: &Self::Case) -> Count {
+        // Note: This includes the cost of `to_bits_le`, which is not incurred if the bits are already cached.
+        match case.is_constant() {
+            true => Count::is(251, 0, 0, 0),
+            false => Count::is(0, 0, 501, 502),
+        }
+    }
+}
+
+impl<E: Environment> OutputMode<dyn Canonical<Boolean = Boolean<E>>> for Scalar<E> {
+    type Case = Mode;
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        match case.is_constant() {
+            true => Mode::Constant,
+            false => Mode::Private,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 100;
+
+    /// Returns a scalar holding `ScalarField::MODULUS + offset`, which is not in canonical form.
+    fn non_canonical(mode: Mode, offset: u64) -> Scalar<Circuit> {
+        let modulus = console::Field::<<Circuit as Environment>::Network>::from_bits_le(
+            &<Circuit as Environment>::ScalarField::modulus().to_bits_le(),
+        )
+        .unwrap();
+        Scalar { field: Field::new(mode, modulus + console::Field::from_u64(offset)), bits_le: OnceCell::new() }
+    }
+
+    fn check_is_canonical(mode: Mode) {
+        let mut rng = TestRng::default();
+
+        // Sample random elements, along with the largest canonical element.
+        let mut values = (0..ITERATIONS).map(|_| Uniform::rand(&mut rng)).collect::<Vec<console::Scalar<_>>>();
+        values.extend([console::Scalar::zero(), console::Scalar::one(), -console::Scalar::one()]);
+
+        for (i, value) in values.into_iter().enumerate() {
+            let candidate = Scalar::<Circuit>::new(mode, value);
+
+            Circuit::scope(format!("{mode} {i}"), || {
+                let is_canonical = candidate.is_canonical();
+                assert!(is_canonical.eject_value());
+                assert_count!(Scalar<Circuit>, Canonical<Boolean = Boolean<Circuit>>, &mode);
+                assert_output_mode!(Scalar<Circuit>, Canonical<Boolean = Boolean<Circuit>>, &mode, is_canonical);
+            });
+
+            candidate.assert_canonical();
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+    }
+
+    fn check_is_not_canonical(mode: Mode) {
+        for offset in [0, 1, 1000] {
+            let candidate = non_canonical(mode, offset);
+            assert!(!candidate.is_canonical().eject_value());
+
+            candidate.assert_canonical();
+            assert!(!Circuit::is_satisfied());
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_is_canonical_constant() {
+        check_is_canonical(Mode::Constant);
+    }
+
+    #[test]
+    fn test_is_canonical_public() {
+        check_is_canonical(Mode::Public);
+    }
+
+    #[test]
+    fn test_is_canonical_private() {
+        check_is_canonical(Mode::Private);
+    }
+
+    #[test]
+    fn test_is_not_canonical_public() {
+        check_is_not_canonical(Mode::Public);
+    }
+
+    #[test]
+    fn test_is_not_canonical_private() {
+        check_is_not_canonical(Mode::Private);
+    }
+}
@@ -0,0 +1,112 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> Scalar<E> {
+    /// Initializes a new scalar field element from a base field element, reduced modulo the scalar field order.
+    ///
+    /// The reduction is computed by binary long division: for each multiple `2^j * MODULUS` of the scalar field
+    /// modulus (from largest to smallest), the multiple is subtracted if it is less than or equal to the value.
+    /// Each step costs one comparison against a constant and one ternary, so for the scalar and base fields of
+    /// this curve (which differ by 2 bits), the reduction of a variable costs 3 steps, or 1,518 constraints.
+    pub fn from_field_reduced(value: &Field<E>) -> Self {
+        // Note: We are reconstituting the scalar field into a base field.
+        // This is safe as the scalar field modulus is less than the base field modulus.
+        debug_assert!(E::ScalarField::modulus() < E::BaseField::modulus());
+
+        // Compute the multiples `2^j * MODULUS` that are less than the base field modulus, from smallest to largest.
+        // Note: As every base field element is less than `2^(j + 1) * MODULUS` for the largest such `j`,
+        // subtracting each multiple that fits (from largest to smallest) leaves a value less than `MODULUS`.
+        let mut multiples = Vec::new();
+        let mut multiple_bits_le = E::ScalarField::modulus().to_bits_le();
+        while let Ok(multiple) = console::FromBits::from_bits_le(&multiple_bits_le) {
+            multiples.push(Field::constant(multiple));
+            multiple_bits_le.insert(0, false);
+        }
+
+        // Subtract each multiple of the modulus that fits, from largest to smallest.
+        let reduced = multiples.iter().rev().fold(value.clone(), |remainder, multiple| {
+            Field::ternary(&remainder.is_less_than(multiple), &remainder, &(&remainder - multiple))
+        });
+
+        Scalar { field: reduced, bits_le: OnceCell::new() }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 100;
+
+    /// Returns the native reduction of the given base field element modulo the scalar field order.
+    fn reduce(
+        value: console::Field<<Circuit as Environment>::Network>,
+    ) -> console::Scalar<<Circuit as Environment>::Network> {
+        let bytes = console::ToBytes::to_bytes_le(&value).unwrap();
+        console::Scalar::new(<Circuit as Environment>::ScalarField::from_bytes_le_mod_order(&bytes))
+    }
+
+    fn check_from_field_reduced(
+        mode: Mode,
+        num_constants: u64,
+        num_public: u64,
+        num_private: u64,
+        num_constraints: u64,
+    ) {
+        let mut rng = TestRng::default();
+
+        // Sample random elements, along with the edge cases around multiples of the scalar field modulus.
+        let mut values = (0..ITERATIONS).map(|_| Uniform::rand(&mut rng)).collect::<Vec<console::Field<_>>>();
+        for multiple in 1..5u64 {
+            let multiple =
+                console::Field::from_bits_le(&(<Circuit as Environment>::ScalarField::modulus().to_bits_le())).unwrap()
+                    * console::Field::from_u64(multiple);
+            values.extend([multiple - console::Field::one(), multiple, multiple + console::Field::one()]);
+        }
+        values.extend([console::Field::zero(), -console::Field::one()]);
+
+        for (i, value) in values.into_iter().enumerate() {
+            let candidate = Field::<Circuit>::new(mode, value);
+
+            Circuit::scope(format!("{mode} {i}"), || {
+                let candidate = Scalar::from_field_reduced(&candidate);
+                assert_eq!(reduce(value), candidate.eject_value());
+                // Ensure the result is canonical, by checking its base field representation.
+                assert_eq!(console::ToField::to_field(&reduce(value)).unwrap(), candidate.to_field().eject_value());
+                assert_scope!(num_constants, num_public, num_private, num_constraints);
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_from_field_reduced_constant() {
+        check_from_field_reduced(Mode::Constant, 3, 0, 0, 0);
+    }
+
+    #[test]
+    fn test_from_field_reduced_public() {
+        check_from_field_reduced(Mode::Public, 762, 0, 1515, 1518);
+    }
+
+    #[test]
+    fn test_from_field_reduced_private() {
+        check_from_field_reduced(Mode::Private, 762, 0, 1515, 1518);
+    }
+}
@@ -17,6 +17,7 @@
 use super::*;
 
 pub mod from_bits;
+pub mod from_field_reduced;
 pub mod one;
 pub mod to_bits;
 pub mod to_field;
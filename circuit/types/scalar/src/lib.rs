@@ -21,6 +21,7 @@
 mod helpers;
 
 pub mod add;
+pub mod canonical;
 pub mod compare;
 pub mod equal;
 pub mod ternary;
@@ -31,7 +32,7 @@ use console::{TestRng, Uniform};
 use snarkvm_circuit_environment::{assert_count, assert_output_mode, assert_scope, count, output_mode};
 
 use snarkvm_circuit_environment::prelude::*;
-use snarkvm_circuit_types_boolean::Boolean;
+use snarkvm_circuit_types_boolean::{is_less_than_or_equal_constant, Boolean};
 use snarkvm_circuit_types_field::Field;
 
 #[derive(Clone)]
@@ -0,0 +1,183 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use once_cell::sync::Lazy;
+use std::{
+    any::{Any, TypeId},
+    collections::HashMap,
+};
+
+/// The maximum number of Pedersen instances held by the process-wide cache.
+/// Note: Once the cache is full, further instances are computed on each call, instead of evicting
+/// existing entries, as the set of setup messages used by a process is small and fixed in practice.
+const MAX_CACHED_INSTANCES: usize = 64;
+
+/// The process-wide cache of Pedersen instances.
+static CACHE: Lazy<SetupCache> = Lazy::new(|| SetupCache::new(MAX_CACHED_INSTANCES));
+
+/// Returns the cached Pedersen instance for the given setup message, computing it with `setup` on a cache miss.
+/// As the bases are behind an `Arc`, the returned clone shares its bases with the cached instance.
+pub(super) fn get_or_setup<T: Any + Clone + Send + Sync>(message: &str, setup: impl FnOnce(&str) -> T) -> T {
+    CACHE.get_or_setup(message, setup)
+}
+
+/// A bounded map from the instantiation and the setup message, to the type-erased Pedersen instance.
+/// Note: The `TypeId` of `Pedersen<E, NUM_BITS>` distinguishes each environment and window size,
+/// so different instantiations with the same message never collide.
+struct SetupCache {
+    /// The cached instances, keyed by the instantiation and the setup message.
+    instances: RwLock<HashMap<(TypeId, String), Box<dyn Any + Send + Sync>>>,
+    /// The maximum number of cached instances.
+    capacity: usize,
+}
+
+impl SetupCache {
+    /// Initializes a new, empty cache holding at most `capacity` instances.
+    fn new(capacity: usize) -> Self {
+        Self { instances: Default::default(), capacity }
+    }
+
+    /// Returns the number of cached instances.
+    #[cfg(test)]
+    fn len(&self) -> usize {
+        self.instances.read().unwrap_or_else(|error| error.into_inner()).len()
+    }
+
+    /// Returns the cached instance for the given setup message, computing it with `setup` on a cache miss.
+    fn get_or_setup<T: Any + Clone + Send + Sync>(&self, message: &str, setup: impl FnOnce(&str) -> T) -> T {
+        let key = (TypeId::of::<T>(), message.to_string());
+
+        // Return the cached instance, if it exists.
+        // Note: A poisoned lock only indicates a panic in another thread, and the map itself remains valid.
+        if let Some(instance) = self.instances.read().unwrap_or_else(|error| error.into_inner()).get(&key) {
+            if let Some(instance) = instance.downcast_ref::<T>() {
+                return instance.clone();
+            }
+        }
+
+        // Otherwise, compute the instance outside of the lock.
+        let instance = setup(message);
+
+        // Cache the instance, if the cache is not full.
+        // Note: If another thread raced to compute the same instance, the first one to be inserted is kept.
+        let mut instances = self.instances.write().unwrap_or_else(|error| error.into_inner());
+        match instances.get(&key) {
+            Some(cached) => cached.downcast_ref::<T>().cloned().unwrap_or(instance),
+            None if instances.len() < self.capacity => {
+                instances.insert(key, Box::new(instance.clone()));
+                instance
+            }
+            None => instance,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Pedersen;
+    use snarkvm_console_types::environment::Console;
+
+    use core::cell::Cell;
+
+    type CurrentEnvironment = Console;
+
+    #[test]
+    fn test_setup_is_cached() {
+        let cache = SetupCache::new(MAX_CACHED_INSTANCES);
+        let num_setups = Cell::new(0);
+        let setup = |message: &str| {
+            num_setups.set(num_setups.get() + 1);
+            Pedersen::<CurrentEnvironment, 64>::setup_uncached(message)
+        };
+
+        let first = cache.get_or_setup("PedersenCacheTest0", setup);
+        assert_eq!(1, num_setups.get());
+
+        // Ensure the second call is served from the cache.
+        let second = cache.get_or_setup("PedersenCacheTest0", setup);
+        assert_eq!(1, num_setups.get());
+
+        // Ensure the bases are identical, and shared with the cached instance.
+        assert_eq!(first.base_window(), second.base_window());
+        assert_eq!(first.random_base_window(), second.random_base_window());
+        assert!(Arc::ptr_eq(first.base_window(), second.base_window()));
+        assert!(Arc::ptr_eq(first.random_base_window(), second.random_base_window()));
+
+        // Ensure the cached bases match freshly computed bases.
+        let uncached = Pedersen::<CurrentEnvironment, 64>::setup_uncached("PedersenCacheTest0");
+        assert_eq!(uncached.base_window(), first.base_window());
+        assert_eq!(uncached.random_base_window(), first.random_base_window());
+
+        // Ensure the process-wide cache serves the same bases.
+        let global = Pedersen::<CurrentEnvironment, 64>::setup("PedersenCacheTest0");
+        assert!(Arc::ptr_eq(
+            global.base_window(),
+            Pedersen::<CurrentEnvironment, 64>::setup("PedersenCacheTest0").base_window()
+        ));
+        assert_eq!(global.base_window(), first.base_window());
+    }
+
+    #[test]
+    fn test_setup_cache_does_not_collide() {
+        let cache = SetupCache::new(MAX_CACHED_INSTANCES);
+
+        // Ensure different messages and instantiations are cached separately.
+        let pedersen64 = cache.get_or_setup("PedersenCacheTest1", Pedersen::<CurrentEnvironment, 64>::setup_uncached);
+        let pedersen128 = cache.get_or_setup("PedersenCacheTest1", Pedersen::<CurrentEnvironment, 128>::setup_uncached);
+        let other = cache.get_or_setup("PedersenCacheTest2", Pedersen::<CurrentEnvironment, 64>::setup_uncached);
+        assert_eq!(3, cache.len());
+
+        assert_eq!(64, pedersen64.base_window().len());
+        assert_eq!(128, pedersen128.base_window().len());
+        assert_eq!(pedersen64.base_window()[..], pedersen128.base_window()[..64]);
+        assert_ne!(pedersen64.base_window(), other.base_window());
+    }
+
+    #[test]
+    fn test_setup_cache_is_bounded() {
+        const CAPACITY: usize = 4;
+
+        let cache = SetupCache::new(CAPACITY);
+        let num_setups = Cell::new(0);
+        let setup = |message: &str| {
+            num_setups.set(num_setups.get() + 1);
+            message.len()
+        };
+
+        // Fill the cache.
+        for i in 0..CAPACITY {
+            cache.get_or_setup(&format!("PedersenCacheTest{i}"), setup);
+        }
+        assert_eq!(CAPACITY, cache.len());
+        assert_eq!(CAPACITY, num_setups.get());
+
+        // Ensure a new message is computed on each call, and is not cached.
+        for _ in 0..2 {
+            assert_eq!(19, cache.get_or_setup("PedersenCacheTest10", setup));
+        }
+        assert_eq!(CAPACITY, cache.len());
+        assert_eq!(CAPACITY + 2, num_setups.get());
+
+        // Ensure the existing entries are still served from the cache.
+        for i in 0..CAPACITY {
+            cache.get_or_setup(&format!("PedersenCacheTest{i}"), setup);
+        }
+        assert_eq!(CAPACITY + 2, num_setups.get());
+    }
+}
@@ -23,15 +23,7 @@ impl<E: Environment, const NUM_BITS: u8> CommitUncompressed for Pedersen<E, NUM_
 
     /// Returns the Pedersen commitment of the given input and randomizer as a group element.
     fn commit_uncompressed(&self, input: &[Self::Input], randomizer: &Self::Randomizer) -> Result<Self::Output> {
-        let mut output = self.hash_uncompressed(input)?;
-
-        // Compute h^r.
-        randomizer.to_bits_le().iter().zip_eq(&*self.random_base_window).filter(|(bit, _)| **bit).for_each(
-            |(_, base)| {
-                output += base;
-            },
-        );
-
-        Ok(output)
+        // Compute h^r, and add it to the hash of the input.
+        Ok(self.hash_uncompressed(input)? + Self::sum_bases(&randomizer.to_bits_le(), &self.random_base_window))
     }
 }
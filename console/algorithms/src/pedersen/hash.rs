@@ -21,6 +21,9 @@ impl<E: Environment, const NUM_BITS: u8> Hash for Pedersen<E, NUM_BITS> {
     type Output = Field<E>;
 
     /// Returns the Pedersen hash of the given input as a field element.
+    ///
+    /// Note: The output is only the x-coordinate of the hash point, so the hash points `(x, y)` and `(x, -y)`
+    /// yield the same digest. Use `hash_to_point` for a digest that is bound to the full point.
     fn hash(&self, input: &[Self::Input]) -> Result<Self::Output> {
         // Compute the Pedersen hash as an affine group element, and return the x-coordinate.
         Ok(self.hash_uncompressed(input)?.to_x_coordinate())
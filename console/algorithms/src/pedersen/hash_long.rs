@@ -0,0 +1,59 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, const NUM_BITS: u8> Pedersen<E, NUM_BITS> {
+    /// Returns the Pedersen hash of an input of arbitrary length as a field element.
+    ///
+    /// The input is split into chunks of `NUM_BITS` bits, where the last chunk is implicitly zero-extended.
+    /// These are followed by the chunks of the 64-bit little-endian length of the input, so that inputs
+    /// which differ only in trailing zeros have distinct digests. The digests are chained as follows:
+    ///   - `digest_0 = hash_uncompressed(chunk_0)`
+    ///   - `digest_i = hash_uncompressed(chunk_i) + h^{r_i}`, where `r_i` is the lower
+    ///     `Scalar::size_in_bits()` bits of the x-coordinate of `digest_{i-1}`
+    ///
+    /// Note: The chaining value `r_i` truncates the x-coordinate of `digest_{i-1}` to its lower
+    /// `Scalar::size_in_bits()` bits, discarding its upper bits.
+    ///
+    /// The output is the x-coordinate of the final digest.
+    pub fn hash_long(&self, input: &[bool]) -> Result<Field<E>> {
+        // Encode the length of the input.
+        let length = (input.len() as u64).to_bits_le();
+
+        // Split the input and its length into chunks, which are never empty.
+        let mut chunks = input.chunks(NUM_BITS as usize).chain(length.chunks(NUM_BITS as usize));
+        let first = chunks.next().unwrap_or(&[]);
+
+        // Hash the first chunk, and fold each subsequent chunk into the running digest.
+        let digest = chunks.try_fold(self.hash_uncompressed(first)?, |digest, chunk| {
+            let mut output = self.hash_uncompressed(chunk)?;
+            // Compute h^r, where r is the bits of the previous digest.
+            digest
+                .to_x_coordinate()
+                .to_bits_le()
+                .iter()
+                .zip(&*self.random_base_window)
+                .filter(|(bit, _)| **bit)
+                .for_each(|(_, base)| {
+                    output += base;
+                });
+            Ok::<_, Error>(output)
+        })?;
+
+        Ok(digest.to_x_coordinate())
+    }
+}
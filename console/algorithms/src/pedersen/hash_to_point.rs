@@ -0,0 +1,67 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, const NUM_BITS: u8> Pedersen<E, NUM_BITS> {
+    /// Returns the Pedersen hash of the given input as a group element, bound to the full point.
+    ///
+    /// Unlike `hash`, which only returns the x-coordinate, the output distinguishes the points `(x, y)` and `(x, -y)`.
+    /// The hash point is offset by a fixed domain point, so the output is separated from `hash_uncompressed`.
+    pub fn hash_to_point(&self, input: &[bool]) -> Result<Group<E>> {
+        Ok(self.hash_uncompressed(input)? + Self::hash_to_point_domain())
+    }
+
+    /// Returns the domain point that offsets the output of `hash_to_point`.
+    pub fn hash_to_point_domain() -> Group<E> {
+        let (generator, _, _) = Blake2Xs::hash_to_curve::<E::Affine>("Aleo.Pedersen.HashToPoint");
+        Group::<E>::new(generator)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_types::environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_hash_to_point_is_domain_separated() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        let pedersen = Pedersen::<CurrentEnvironment, 64>::setup("PedersenHashToPointTest");
+        for _ in 0..ITERATIONS {
+            let input = (0..64).map(|_| bool::rand(&mut rng)).collect::<Vec<bool>>();
+
+            // Ensure the point-valued output differs from the uncompressed hash, and from its negation.
+            let candidate = pedersen.hash_to_point(&input)?;
+            let uncompressed = pedersen.hash_uncompressed(&input)?;
+            assert_ne!(uncompressed, candidate);
+            assert_ne!(-uncompressed, candidate);
+
+            // Ensure the output is offset by the domain point.
+            assert_eq!(uncompressed, candidate - Pedersen::<CurrentEnvironment, 64>::hash_to_point_domain());
+        }
+
+        // Ensure the empty input does not map to the identity, unlike `hash_uncompressed`.
+        assert!(pedersen.hash_uncompressed(&[])?.is_zero());
+        assert!(!pedersen.hash_to_point(&[])?.is_zero());
+        Ok(())
+    }
+}
@@ -31,13 +31,6 @@ impl<E: Environment, const NUM_BITS: u8> HashUncompressed for Pedersen<E, NUM_BI
         }
 
         // Compute sum of h_i^{m_i} for all i.
-        Ok(input
-            .iter()
-            .zip_eq(&*self.base_window)
-            .flat_map(|(bit, base)| match bit {
-                true => Some(*base),
-                false => None,
-            })
-            .sum())
+        Ok(Self::sum_bases(&input, &self.base_window))
     }
 }
@@ -0,0 +1,203 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// A Pedersen hash function whose bases are derived on first use, and cached for subsequent use.
+///
+/// The `i`-th base is `2^i` times the generator, so hashing an input of `k` bits only materializes
+/// the first `k` bases. The generators themselves are only derived once their window is first used.
+/// The digests are identical to those of an eagerly-initialized `Pedersen`.
+#[derive(Clone)]
+pub struct LazyPedersen<E: Environment, const NUM_BITS: u8> {
+    /// The setup message, from which the generators are derived.
+    message: Arc<str>,
+    /// The bases of the base window that have been materialized so far.
+    base_window: Arc<RwLock<Vec<Group<E>>>>,
+    /// The bases of the random base window that have been materialized so far.
+    random_base_window: Arc<RwLock<Vec<Group<E>>>>,
+}
+
+impl<E: Environment, const NUM_BITS: u8> LazyPedersen<E, NUM_BITS> {
+    /// Initializes a new instance of Pedersen with the given setup message, without materializing any bases.
+    pub fn setup(message: &str) -> Self {
+        Self { message: message.into(), base_window: Default::default(), random_base_window: Default::default() }
+    }
+
+    /// Returns the number of bases in the base window that have been materialized.
+    pub fn num_materialized_bases(&self) -> Result<usize> {
+        Ok(Self::read(&self.base_window)?.len())
+    }
+
+    /// Returns the number of bases in the random base window that have been materialized.
+    pub fn num_materialized_random_bases(&self) -> Result<usize> {
+        Ok(Self::read(&self.random_base_window)?.len())
+    }
+
+    /// Returns a read guard over the given window.
+    fn read(window: &RwLock<Vec<Group<E>>>) -> Result<RwLockReadGuard<'_, Vec<Group<E>>>> {
+        window.read().map_err(|_| anyhow!("The Pedersen base window lock is poisoned"))
+    }
+
+    /// Returns a read guard over the given window, after materializing at least `num_bases` bases.
+    fn materialize(
+        window: &RwLock<Vec<Group<E>>>,
+        generator: impl FnOnce() -> Group<E>,
+        num_bases: usize,
+    ) -> Result<RwLockReadGuard<'_, Vec<Group<E>>>> {
+        // Return the window directly, if the bases have already been materialized.
+        {
+            let window = Self::read(window)?;
+            if window.len() >= num_bases {
+                return Ok(window);
+            }
+        }
+
+        // Otherwise, extend the window.
+        {
+            let mut window = window.write().map_err(|_| anyhow!("The Pedersen base window lock is poisoned"))?;
+            Pedersen::<E, NUM_BITS>::extend_window(&mut window, generator, num_bases);
+        }
+        Self::read(window)
+    }
+}
+
+impl<E: Environment, const NUM_BITS: u8> HashUncompressed for LazyPedersen<E, NUM_BITS> {
+    type Input = bool;
+    type Output = Group<E>;
+
+    /// Returns the Pedersen hash of the given input as a group element.
+    fn hash_uncompressed(&self, input: &[Self::Input]) -> Result<Self::Output> {
+        // Ensure the input size is within the parameter size.
+        if input.len() > NUM_BITS as usize {
+            bail!("Invalid input size for Pedersen: expected <= {NUM_BITS}, found {}", input.len())
+        }
+
+        // Materialize the bases for the input. The padding bits are `false`, and do not require a base.
+        let bases = Self::materialize(
+            &self.base_window,
+            || Pedersen::<E, NUM_BITS>::base_generator(&self.message),
+            input.len(),
+        )?;
+
+        // Compute sum of h_i^{m_i} for all i.
+        Ok(Pedersen::<E, NUM_BITS>::sum_bases(input, &bases[..input.len()]))
+    }
+}
+
+impl<E: Environment, const NUM_BITS: u8> Hash for LazyPedersen<E, NUM_BITS> {
+    type Input = bool;
+    type Output = Field<E>;
+
+    /// Returns the Pedersen hash of the given input as a field element.
+    fn hash(&self, input: &[Self::Input]) -> Result<Self::Output> {
+        // Compute the Pedersen hash as an affine group element, and return the x-coordinate.
+        Ok(self.hash_uncompressed(input)?.to_x_coordinate())
+    }
+}
+
+impl<E: Environment, const NUM_BITS: u8> CommitUncompressed for LazyPedersen<E, NUM_BITS> {
+    type Input = bool;
+    type Output = Group<E>;
+    type Randomizer = Scalar<E>;
+
+    /// Returns the Pedersen commitment of the given input and randomizer as a group element.
+    fn commit_uncompressed(&self, input: &[Self::Input], randomizer: &Self::Randomizer) -> Result<Self::Output> {
+        let output = self.hash_uncompressed(input)?;
+
+        // Materialize the random bases.
+        let random_bases = Self::materialize(
+            &self.random_base_window,
+            || Pedersen::<E, NUM_BITS>::random_base_generator(&self.message),
+            Scalar::<E>::size_in_bits(),
+        )?;
+
+        // Compute h^r, and add it to the hash of the input.
+        Ok(output + Pedersen::<E, NUM_BITS>::sum_bases(&randomizer.to_bits_le(), &random_bases))
+    }
+}
+
+impl<E: Environment, const NUM_BITS: u8> Commit for LazyPedersen<E, NUM_BITS> {
+    type Input = bool;
+    type Output = Field<E>;
+    type Randomizer = Scalar<E>;
+
+    /// Returns the Pedersen commitment of the given input and randomizer as a field element.
+    fn commit(&self, input: &[Self::Input], randomizer: &Self::Randomizer) -> Result<Self::Output> {
+        Ok(self.commit_uncompressed(input, randomizer)?.to_x_coordinate())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_types::environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    const ITERATIONS: usize = 10;
+    const MESSAGE: &str = "PedersenTest";
+
+    #[test]
+    fn test_lazy_matches_eager() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        let eager = Pedersen128::<CurrentEnvironment>::setup(MESSAGE);
+        let lazy = Pedersen128::<CurrentEnvironment>::setup_lazy(MESSAGE);
+
+        for i in 0..ITERATIONS {
+            let input = (0..i * 12).map(|_| bool::rand(&mut rng)).collect::<Vec<_>>();
+            let randomizer = Uniform::rand(&mut rng);
+            assert_eq!(eager.hash(&input)?, lazy.hash(&input)?);
+            assert_eq!(eager.commit(&input, &randomizer)?, lazy.commit(&input, &randomizer)?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_lazy_materializes_touched_bases() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        let eager = Pedersen128::<CurrentEnvironment>::setup(MESSAGE);
+        let lazy = Pedersen128::<CurrentEnvironment>::setup_lazy(MESSAGE);
+        assert_eq!(0, lazy.num_materialized_bases()?);
+        assert_eq!(0, lazy.num_materialized_random_bases()?);
+
+        // Hash a short input, and ensure only its bases were materialized.
+        let input = (0..16).map(|_| bool::rand(&mut rng)).collect::<Vec<_>>();
+        assert_eq!(eager.hash(&input)?, lazy.hash(&input)?);
+        assert_eq!(16, lazy.num_materialized_bases()?);
+        assert_eq!(0, lazy.num_materialized_random_bases()?);
+
+        // Hash a shorter input, and ensure no further bases were materialized.
+        assert_eq!(eager.hash(&input[..8])?, lazy.hash(&input[..8])?);
+        assert_eq!(16, lazy.num_materialized_bases()?);
+
+        // Hash a longer input, and ensure the window is extended.
+        let input = (0..64).map(|_| bool::rand(&mut rng)).collect::<Vec<_>>();
+        assert_eq!(eager.hash(&input)?, lazy.hash(&input)?);
+        assert_eq!(64, lazy.num_materialized_bases()?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_lazy_input_size() -> Result<()> {
+        let lazy = Pedersen64::<CurrentEnvironment>::setup_lazy(MESSAGE);
+        assert!(lazy.hash(&[true; 65]).is_err());
+        assert_eq!(0, lazy.num_materialized_bases()?);
+        Ok(())
+    }
+}
@@ -14,15 +14,27 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+#[cfg(feature = "setup_cache")]
+mod cache;
 mod commit;
 mod commit_uncompressed;
 mod hash;
+mod hash_long;
+mod hash_to_point;
 mod hash_uncompressed;
+mod lazy;
+mod prf;
+mod validate;
+
+pub use lazy::LazyPedersen;
 
 use crate::Blake2Xs;
 use snarkvm_console_types::prelude::*;
 
-use std::{borrow::Cow, sync::Arc};
+use std::{
+    borrow::Cow,
+    sync::{Arc, RwLock, RwLockReadGuard},
+};
 
 /// Pedersen64 is an *additively-homomorphic* collision-resistant hash function that takes up to a 64-bit input.
 pub type Pedersen64<E> = Pedersen<E, 64>;
@@ -40,35 +52,85 @@ pub struct Pedersen<E: Environment, const NUM_BITS: u8> {
 }
 
 impl<E: Environment, const NUM_BITS: u8> Pedersen<E, NUM_BITS> {
+    /// Returns the number of windows, which is always one, as every input bit has its own base in a single window.
+    pub const fn num_windows() -> usize {
+        1
+    }
+
+    /// Returns the number of bases in each window.
+    pub const fn window_size() -> usize {
+        NUM_BITS as usize
+    }
+
+    /// Returns the maximum number of input bits.
+    pub const fn capacity_in_bits() -> usize {
+        Self::num_windows() * Self::window_size()
+    }
+
     /// Initializes a new instance of Pedersen with the given setup message.
+    ///
+    /// If the `setup_cache` feature is enabled, the bases are computed once per process for each
+    /// instantiation and message, and subsequent calls return a clone of the cached instance.
     pub fn setup(message: &str) -> Self {
-        // Construct an indexed message to attempt to sample a base.
-        let (generator, _, _) = Blake2Xs::hash_to_curve::<E::Affine>(&format!("Aleo.Pedersen.Base.{message}"));
-        // Construct the window with the base.
-        let mut base_window = vec![Group::<E>::zero(); NUM_BITS as usize];
+        #[cfg(feature = "setup_cache")]
         {
-            let mut base_power = Group::<E>::new(generator);
-            for base in base_window.iter_mut().take(NUM_BITS as usize) {
-                *base = base_power;
-                base_power = base_power.double();
-            }
-            assert_eq!(base_window.len(), NUM_BITS as usize);
+            cache::get_or_setup(message, Self::setup_uncached)
         }
+        #[cfg(not(feature = "setup_cache"))]
+        {
+            Self::setup_uncached(message)
+        }
+    }
+
+    /// Initializes a new instance of Pedersen with the given setup message, computing all bases.
+    fn setup_uncached(message: &str) -> Self {
+        // Construct the window with the base.
+        let mut base_window = Vec::with_capacity(NUM_BITS as usize);
+        Self::extend_window(&mut base_window, || Self::base_generator(message), NUM_BITS as usize);
 
-        // Compute the random base.
-        let (generator, _, _) = Blake2Xs::hash_to_curve::<E::Affine>(&format!("Aleo.Pedersen.RandomBase.{message}"));
         // Construct the window with the random base.
-        let mut random_base = Vec::with_capacity(Scalar::<E>::size_in_bits());
-        {
-            let mut base = Group::<E>::new(generator);
-            for _ in 0..Scalar::<E>::size_in_bits() {
-                random_base.push(base);
-                base = base.double();
-            }
-            assert_eq!(random_base.len(), Scalar::<E>::size_in_bits());
+        let mut random_base_window = Vec::with_capacity(Scalar::<E>::size_in_bits());
+        Self::extend_window(
+            &mut random_base_window,
+            || Self::random_base_generator(message),
+            Scalar::<E>::size_in_bits(),
+        );
+
+        Self { base_window: Arc::new(base_window), random_base_window: Arc::new(random_base_window) }
+    }
+
+    /// Extends the given window up to `num_bases` bases, where the `i`-th base is `2^i` times the generator.
+    /// The generator is only derived if the window is empty.
+    fn extend_window(window: &mut Vec<Group<E>>, generator: impl FnOnce() -> Group<E>, num_bases: usize) {
+        if window.is_empty() && num_bases > 0 {
+            window.push(generator());
+        }
+        while window.len() < num_bases {
+            let base = window[window.len() - 1].double();
+            window.push(base);
         }
+    }
+
+    /// Returns the sum of the bases whose corresponding bit is set.
+    fn sum_bases(bits: &[bool], bases: &[Group<E>]) -> Group<E> {
+        bits.iter().zip_eq(bases).filter(|(bit, _)| **bit).map(|(_, base)| *base).sum()
+    }
 
-        Self { base_window: Arc::new(base_window.to_vec()), random_base_window: Arc::new(random_base) }
+    /// Initializes a new instance of Pedersen with the given setup message, whose bases are derived on first use.
+    pub fn setup_lazy(message: &str) -> LazyPedersen<E, NUM_BITS> {
+        LazyPedersen::setup(message)
+    }
+
+    /// Returns the generator of the base window for the given setup message.
+    fn base_generator(message: &str) -> Group<E> {
+        let (generator, _, _) = Blake2Xs::hash_to_curve::<E::Affine>(&format!("Aleo.Pedersen.Base.{message}"));
+        Group::<E>::new(generator)
+    }
+
+    /// Returns the generator of the random base window for the given setup message.
+    fn random_base_generator(message: &str) -> Group<E> {
+        let (generator, _, _) = Blake2Xs::hash_to_curve::<E::Affine>(&format!("Aleo.Pedersen.RandomBase.{message}"));
+        Group::<E>::new(generator)
     }
 
     /// Returns the base window.
@@ -81,3 +143,61 @@ impl<E: Environment, const NUM_BITS: u8> Pedersen<E, NUM_BITS> {
         &self.random_base_window
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_types::environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    use std::path::PathBuf;
+
+    /// Returns the path to the `resources` folder for this module.
+    fn resources_path() -> PathBuf {
+        // Construct the path for the `resources` folder.
+        let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        path.push("src");
+        path.push("pedersen");
+        path.push("resources");
+
+        // Create the `resources` folder, if it does not exist.
+        if !path.exists() {
+            std::fs::create_dir_all(&path).unwrap_or_else(|_| panic!("Failed to create resources folder: {path:?}"));
+        }
+        // Output the path.
+        path
+    }
+
+    /// Loads the given `test_file` and asserts the given `candidate` matches the expected value.
+    /// If the file does not exist, it is created empty, and the assertion fails with instructions
+    /// to regenerate it by running the test with `UPDATE_EXPECT=1`, and to commit the result.
+    #[track_caller]
+    fn assert_snapshot<S: Into<String>>(test_file: S, candidate: &str) {
+        // Construct the path for the test file.
+        let mut path = resources_path();
+        path.push(test_file.into());
+        path.set_extension("snap");
+
+        // Create the test file, if it does not exist.
+        if !path.exists() {
+            std::fs::File::create(&path).unwrap_or_else(|_| panic!("Failed to create file: {path:?}"));
+        }
+
+        // Assert the test file is equal to the expected value.
+        expect_test::expect_file![path].assert_eq(candidate);
+    }
+
+    /// Returns the hex encoding of the given bases, with one base per line.
+    fn bases_to_hex(bases: &[Group<CurrentEnvironment>]) -> String {
+        bases.iter().map(|base| format!("{}\n", hex::encode(base.to_bytes_le().unwrap()))).collect()
+    }
+
+    /// Ensures the derivation of the bases does not change, as downstream proofs depend on them.
+    #[test]
+    fn test_setup_bases_are_stable() {
+        let pedersen = Pedersen128::<CurrentEnvironment>::setup("fixed");
+        assert_snapshot("pedersen128_fixed_base_window", &bases_to_hex(pedersen.base_window()));
+        assert_snapshot("pedersen128_fixed_random_base_window", &bases_to_hex(pedersen.random_base_window()));
+    }
+}
@@ -0,0 +1,35 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, const NUM_BITS: u8> PRF for Pedersen<E, NUM_BITS> {
+    type Input = bool;
+    type Output = Field<E>;
+    type Seed = Field<E>;
+
+    /// Returns the Pedersen PRF output for the given seed and input, as a field element.
+    /// The preimage `seed || input` is hashed with `hash_long`, so it may exceed `NUM_BITS`.
+    #[inline]
+    fn prf(&self, seed: &Self::Seed, input: &[Self::Input]) -> Result<Self::Output> {
+        // Construct the preimage: seed || input.
+        let mut preimage = seed.to_bits_le();
+        preimage.extend_from_slice(input);
+
+        // Hash the preimage to derive the PRF output.
+        self.hash_long(&preimage)
+    }
+}
@@ -0,0 +1,99 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use std::collections::HashSet;
+
+impl<E: Environment, const NUM_BITS: u8> Pedersen<E, NUM_BITS> {
+    /// Ensures the bases of this instance are not degenerate, which would break collision resistance.
+    /// Specifically, checks that no base is the identity, and that no two bases across both windows are equal.
+    /// Note: A base shared between the windows breaks binding, as setting message bit `i` is then
+    /// indistinguishable from adding `2^j` to the randomizer.
+    pub fn validate_bases(&self) -> Result<()> {
+        let mut bases = HashSet::with_capacity(self.base_window.len() + self.random_base_window.len());
+        Self::validate_window("base window", &self.base_window, &mut bases)?;
+        Self::validate_window("random base window", &self.random_base_window, &mut bases)
+    }
+
+    /// Ensures the given window contains no identity base, and no base that is already in `bases`.
+    fn validate_window(name: &str, window: &[Group<E>], bases: &mut HashSet<Group<E>>) -> Result<()> {
+        for (index, base) in window.iter().enumerate() {
+            // Ensure the base is not the identity.
+            ensure!(!base.is_zero(), "Pedersen {name} contains the identity at index {index}");
+            // Ensure the base is not a duplicate of an earlier base.
+            ensure!(bases.insert(*base), "Pedersen {name} contains a duplicate base at index {index}");
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_types::environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    const MESSAGE: &str = "PedersenTest";
+
+    #[test]
+    fn test_validate_bases() -> Result<()> {
+        Pedersen64::<CurrentEnvironment>::setup(MESSAGE).validate_bases()?;
+        Pedersen128::<CurrentEnvironment>::setup(MESSAGE).validate_bases()
+    }
+
+    #[test]
+    fn test_validate_bases_identity() {
+        let pedersen = Pedersen64::<CurrentEnvironment>::setup(MESSAGE);
+
+        // Replace a base with the identity.
+        let mut base_window = pedersen.base_window().to_vec();
+        base_window[7] = Group::zero();
+        let candidate = Pedersen64 { base_window: Arc::new(base_window), ..pedersen.clone() };
+        assert!(candidate.validate_bases().is_err());
+
+        // Replace a random base with the identity.
+        let mut random_base_window = pedersen.random_base_window().to_vec();
+        random_base_window[0] = Group::zero();
+        let candidate = Pedersen64 { random_base_window: Arc::new(random_base_window), ..pedersen };
+        assert!(candidate.validate_bases().is_err());
+    }
+
+    #[test]
+    fn test_validate_bases_duplicate() {
+        let pedersen = Pedersen64::<CurrentEnvironment>::setup(MESSAGE);
+
+        // Duplicate a base within the base window.
+        let mut base_window = pedersen.base_window().to_vec();
+        base_window[10] = base_window[3];
+        let candidate = Pedersen64 { base_window: Arc::new(base_window), ..pedersen.clone() };
+        assert!(candidate.validate_bases().is_err());
+
+        // Duplicate a base within the random base window.
+        let mut random_base_window = pedersen.random_base_window().to_vec();
+        let last = random_base_window.len() - 1;
+        random_base_window[last] = random_base_window[0];
+        let candidate = Pedersen64 { random_base_window: Arc::new(random_base_window), ..pedersen.clone() };
+        assert!(candidate.validate_bases().is_err());
+
+        // Duplicate a base across the two windows.
+        let mut random_base_window = pedersen.random_base_window().to_vec();
+        random_base_window[0] = pedersen.base_window()[0];
+        let candidate = Pedersen64 { random_base_window: Arc::new(random_base_window), ..pedersen };
+        assert!(candidate.validate_bases().is_err());
+    }
+}
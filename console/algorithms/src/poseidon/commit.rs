@@ -0,0 +1,40 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// The domain separator for Poseidon commitments, which keeps a commitment to `input`
+/// under `randomizer` distinct from the Poseidon hash of `randomizer || input`.
+const COMMIT_DOMAIN: &str = "PoseidonCommit";
+
+impl<E: Environment, const RATE: usize> Commit for Poseidon<E, RATE> {
+    type Input = Field<E>;
+    type Output = Field<E>;
+    type Randomizer = Field<E>;
+
+    /// Returns the Poseidon commitment of the given input and randomizer as a field element.
+    #[inline]
+    fn commit(&self, input: &[Self::Input], randomizer: &Self::Randomizer) -> Result<Self::Output> {
+        // Construct the preimage: COMMIT_DOMAIN || randomizer || input.
+        let mut preimage = Vec::with_capacity(2 + input.len());
+        preimage.push(Field::<E>::new_domain_separator(COMMIT_DOMAIN));
+        preimage.push(*randomizer);
+        preimage.extend_from_slice(input);
+
+        // Hash the preimage to derive the commitment.
+        self.hash(&preimage)
+    }
+}
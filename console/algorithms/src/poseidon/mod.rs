@@ -16,6 +16,7 @@
 
 mod helpers;
 
+mod commit;
 mod hash;
 mod hash_many;
 mod hash_to_group;